@@ -0,0 +1,35 @@
+use std::convert::TryInto;
+
+use bdaddr::BdAddr;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// The former parser, collecting groups into `Vec`.
+fn from_str_vec(s: &str) -> Option<BdAddr> {
+    let mut b = s
+        .splitn(6, ':')
+        .map(|v| u8::from_str_radix(v, 16))
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    b.reverse();
+    let b: [u8; 6] = b.try_into().ok()?;
+    Some(b.into())
+}
+
+fn bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_str");
+    for (name, s) in [
+        ("valid", "00:1a:7d:da:71:13"),
+        ("invalid", "00:1a:7d:da:71:1g"),
+    ] {
+        group.bench_function(format!("vec/{}", name), |b| {
+            b.iter(|| from_str_vec(black_box(s)))
+        });
+        group.bench_function(format!("bdaddr/{}", name), |b| {
+            b.iter(|| black_box(s).parse::<BdAddr>().ok())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,36 @@
+//! `cargo run --example clap --features clap -- --address 53:03:8c:bc:bd:82 --address-type random`
+
+use bdaddr::{Address, AddressType, BdAddr};
+use clap::{value_parser, Arg, Command};
+
+fn main() {
+    let m = Command::new("clap")
+        .arg(
+            Arg::new("address")
+                .long("address")
+                .required(true)
+                .value_parser(value_parser!(BdAddr)),
+        )
+        .arg(
+            Arg::new("address-type")
+                .long("address-type")
+                .default_value("public")
+                .value_parser(value_parser!(AddressType)),
+        )
+        .arg(
+            Arg::new("peer")
+                .long("peer")
+                .help("<address type>/<address>")
+                .value_parser(value_parser!(Address)),
+        )
+        .get_matches();
+
+    let addr = *m.get_one::<BdAddr>("address").unwrap();
+    let ty = m.get_one::<AddressType>("address-type").unwrap().clone();
+    let addr = addr.to_addr(ty);
+    println!("address: {:?}", addr);
+
+    if let Some(peer) = m.get_one::<Address>("peer") {
+        println!("peer: {:?}", peer);
+    }
+}
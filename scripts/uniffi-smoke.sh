@@ -0,0 +1,30 @@
+#!/bin/sh
+# Generate Kotlin / Swift bindings of `uniffi` feature and check the exported interface.
+set -eu
+
+cd "$(dirname "$0")/.."
+out="${1:-target/uniffi}"
+
+cargo rustc --lib --features uniffi --crate-type cdylib
+lib=$(ls target/debug/libbdaddr.so target/debug/libbdaddr.dylib target/debug/bdaddr.dll 2>/dev/null | head -n 1)
+cargo run --features uniffi-bindgen --bin uniffi-bindgen -- \
+    generate --library "$lib" --language kotlin --language swift --out-dir "$out"
+
+check() {
+    if ! grep -q "$2" "$1"; then
+        echo "missing $2 in $1" >&2
+        exit 1
+    fi
+}
+
+kt=$(find "$out" -name bdaddr.kt)
+for s in "fun \`parseBdAddr\`" "fun \`parseAddress\`" "fun \`addressKind\`" "fun \`rpaMatches\`" \
+    "data class Address" "enum class AddressType" "class BdAddrException"; do
+    check "$kt" "$s"
+done
+for s in "func parseBdAddr" "func parseAddress" "func addressKind" "func rpaMatches" \
+    "struct Address" "enum AddressType" "enum BdAddrError"; do
+    check "$out/bdaddr.swift" "$s"
+done
+
+echo "ok: $out"
@@ -1,31 +1,586 @@
-use std::convert::{TryFrom, TryInto};
-use std::fmt;
-use std::str::FromStr;
-
-#[cfg(feature = "matches")]
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+pub mod ad;
+#[cfg(feature = "advmac")]
+mod advmac;
+mod base32;
+#[cfg(feature = "bluenrg")]
+mod bluenrg;
+#[cfg(feature = "bluer")]
+mod bluer;
+#[cfg(feature = "bluest")]
+mod bluest;
+mod bluez;
+pub use bluez::BluezAddressError;
+#[cfg(feature = "bluez-async")]
+mod bluez_async;
+#[cfg(feature = "bson")]
+pub mod bson;
+#[cfg(feature = "bt-hci")]
+mod bt_hci;
+#[cfg(feature = "btleplug")]
+mod btleplug;
+#[cfg(feature = "btmgmt")]
+mod btmgmt;
+#[cfg(feature = "btstack")]
+mod btstack;
+#[cfg(feature = "capi")]
+pub mod capi;
+mod classic;
+pub use classic::{InvalidLap, Lap};
+#[cfg(feature = "clap")]
+mod clap;
+mod convert;
+pub use convert::{TryIntoAddress, TryIntoBdAddr};
+#[cfg(all(feature = "testing", feature = "rand"))]
+mod corpus;
+#[cfg(all(feature = "testing", feature = "rand"))]
+pub use corpus::{CorpusGenerator, CorpusMix};
+#[cfg(feature = "dbus")]
+mod dbus;
+#[cfg(feature = "dbus")]
+pub use self::dbus::DbusAddressError;
+// named not to shadow `diesel` crate used by derives
+#[cfg(feature = "diesel")]
+mod diesel_types;
+#[cfg(feature = "esp")]
+mod esp;
+#[cfg(feature = "esp")]
+pub use esp::ble_addr_t;
+#[cfg(feature = "eui48")]
+mod eui48;
+mod find;
+mod format_with;
+pub use find::find_addresses;
+pub use format_with::FormatWith;
+pub mod hci;
+#[cfg(feature = "io-bluetooth")]
+mod io_bluetooth;
+#[cfg(feature = "io-bluetooth")]
+pub use io_bluetooth::BluetoothDeviceAddress;
+mod lenient;
+#[cfg(all(feature = "libc", target_os = "linux"))]
+mod libc;
+#[cfg(all(feature = "libc", target_os = "linux"))]
+pub use self::libc::{bdaddr_t, sockaddr_l2, sockaddr_rc};
+#[cfg(feature = "log-kv")]
+mod log_kv;
+#[cfg(feature = "mac_address")]
+mod mac_address;
+#[cfg(feature = "macaddr")]
+mod macaddr;
+#[cfg(any(feature = "matches", feature = "matches-openssl"))]
 mod matches;
+#[cfg(any(feature = "matches", feature = "matches-openssl"))]
+pub use self::matches::AesBackendError;
+#[cfg(feature = "napi")]
+pub mod napi;
+pub mod ndef;
+#[cfg(feature = "nrf")]
+mod nrf;
+#[cfg(feature = "nrf")]
+pub use nrf::NrfAddressError;
+#[cfg(feature = "alloc")]
+mod oui;
+#[cfg(feature = "alloc")]
+pub use oui::OuiSet;
+#[cfg(feature = "postgres")]
+mod postgres_types;
+#[cfg(feature = "python")]
+#[clippy::msrv = "1.83"] // pyo3
+mod python;
+#[cfg(feature = "python")]
+pub use python::{py_module, PyAddress, PyBdAddr};
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "rubble")]
+mod rubble;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod serde;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "trouble")]
+mod trouble_host;
+// named not to shadow `uniffi` crate used by derives
+#[cfg(feature = "uniffi")]
+mod uniffi_types;
+#[cfg(feature = "ux")]
+mod ux;
+mod vendor_resolved;
+pub use vendor_resolved::VendorResolved;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "wasm")]
+pub use wasm::JsBdAddr;
+#[cfg(feature = "windows")]
+mod windows;
+#[cfg(feature = "windows")]
+pub use windows::UnknownWinrtAddressType;
+#[cfg(feature = "zbus")]
+mod zvariant;
+
+/// Reason of [`AddressParseError`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressParseErrorKind {
+    /// Group has no hex digits. (including empty string)
+    Empty,
+    /// Not a hex digit nor separator.
+    InvalidDigit,
+    /// More hex digits than two. (or than the form, e.g. [`BdAddr::parse_lenient`])
+    GroupTooLong,
+    /// Less hex digits than two. (or than the form, e.g. [`BdAddr::parse_lenient`])
+    GroupTooShort,
+    /// Separator differs from the first one in [`BdAddr::parse_lenient`].
+    MixedSeparators,
+    /// Not 6 groups.
+    WrongGroupCount {
+        /// Number of groups of the form. (`6` if colon separated)
+        expected: usize,
+        /// Number of groups found.
+        found: usize,
+    },
+    /// Malformed in other forms than colon separated. (e.g. base32, `type/address`)
+    Invalid,
+}
 
 /// Parse error for [`BdAddr::from_str`]
-#[derive(Debug, thiserror::Error)]
-#[error("failed to parse address")]
-pub struct AddressParseError;
+///
+/// ```
+/// use bdaddr::{AddressParseErrorKind, BdAddr};
+///
+/// let err = "55:44:33:2Z:11:00".parse::<BdAddr>().unwrap_err();
+/// assert_eq!(AddressParseErrorKind::InvalidDigit, err.kind());
+/// assert_eq!((3, 10), (err.group(), err.position()));
+/// assert_eq!("invalid hex digit in group 3 at offset 10", err.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressParseError {
+    kind: AddressParseErrorKind,
+    group: usize,
+    position: usize,
+}
+
+impl AddressParseError {
+    /// Error without location. (for the other forms than colon separated)
+    pub(crate) const INVALID: Self = Self::new(AddressParseErrorKind::Invalid, 0, 0);
+
+    pub(crate) const fn new(kind: AddressParseErrorKind, group: usize, position: usize) -> Self {
+        Self {
+            kind,
+            group,
+            position,
+        }
+    }
+
+    /// Reason of the failure.
+    pub fn kind(&self) -> AddressParseErrorKind {
+        self.kind
+    }
+
+    /// Index of the failed group, from zero. (`0` if [`AddressParseErrorKind::Invalid`])
+    pub fn group(&self) -> usize {
+        self.group
+    }
+
+    /// Byte offset of the failure in input. (`0` if [`AddressParseErrorKind::Invalid`])
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for AddressParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            kind,
+            group,
+            position,
+        } = self;
+        match kind {
+            AddressParseErrorKind::Empty => {
+                write!(f, "empty group {} at offset {}", group, position)
+            }
+            AddressParseErrorKind::InvalidDigit => {
+                write!(
+                    f,
+                    "invalid hex digit in group {} at offset {}",
+                    group, position
+                )
+            }
+            AddressParseErrorKind::GroupTooLong => {
+                write!(f, "group {} too long at offset {}", group, position)
+            }
+            AddressParseErrorKind::GroupTooShort => {
+                write!(f, "group {} too short at offset {}", group, position)
+            }
+            AddressParseErrorKind::MixedSeparators => {
+                write!(
+                    f,
+                    "mixed separator after group {} at offset {}",
+                    group, position
+                )
+            }
+            AddressParseErrorKind::WrongGroupCount { expected, found } => write!(
+                f,
+                "wrong group count (expected {}, found {}) at offset {}",
+                expected, found, position
+            ),
+            AddressParseErrorKind::Invalid => f.write_str("failed to parse address"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AddressParseError {}
 
 /// Invalid bits for this address type.
-#[derive(Debug, thiserror::Error)]
-#[error("Invalid bits for this address type. (expect: 0b{0:02b}, but 0b{1:02b})")]
-pub struct InvalidBitsForAddressType(u8, u8);
+#[derive(Debug)]
+pub struct InvalidBitsForAddressType {
+    type_name: &'static str,
+    address: BdAddr,
+    expected: u8,
+    actual: u8,
+}
+
+impl InvalidBitsForAddressType {
+    fn new(type_name: &'static str, expected: u8, address: [u8; 6]) -> Self {
+        Self {
+            type_name,
+            expected,
+            actual: (address[5] & 0xC0) >> 6,
+            address: address.into(),
+        }
+    }
+
+    /// Name of the target address type. (e.g. `StaticDeviceAddress`)
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Rejected address.
+    pub fn address(&self) -> &BdAddr {
+        &self.address
+    }
+
+    /// Expected most significant two bits for the target address type.
+    pub fn expected_bits(&self) -> u8 {
+        self.expected
+    }
+
+    /// Actual most significant two bits of the rejected address.
+    pub fn actual_bits(&self) -> u8 {
+        self.actual
+    }
+}
+
+impl fmt::Display for InvalidBitsForAddressType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid bits for {}. (address: {}, expect: 0b{:02b}, but 0b{:02b})",
+            self.type_name, self.address, self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidBitsForAddressType {}
+
+/// Unknown address type code.
+#[derive(Debug)]
+pub struct UnknownAddressType(u8);
+
+impl fmt::Display for UnknownAddressType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown address type. (0x{:02x})", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownAddressType {}
+
+/// Parse error for enum variant name. (e.g. [`AddressType::from_str`])
+///
+/// The rejected input is kept only with `alloc` feature.
+#[derive(Debug)]
+pub struct VariantParseError {
+    /// Name of the enum in the message. (e.g. `address type`)
+    name: &'static str,
+
+    #[cfg(feature = "alloc")]
+    input: String,
+}
+
+impl VariantParseError {
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    fn new(name: &'static str, s: &str) -> Self {
+        Self {
+            name,
+            #[cfg(feature = "alloc")]
+            input: s.to_string(),
+        }
+    }
+
+    /// Unknown [`AddressType`] string.
+    fn address_type(s: &str) -> Self {
+        Self::new("address type", s)
+    }
+}
+
+impl fmt::Display for VariantParseError {
+    #[cfg(feature = "alloc")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown {}. ({:?})", self.name, self.input)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown {}.", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VariantParseError {}
+
+/// Error for [`Address::from_str`]
+#[derive(Debug)]
+pub enum AddressFromStrError {
+    /// Not formatted as `<address type>/<address>`.
+    Format,
+
+    /// Unknown address type.
+    UnknownAddressType(VariantParseError),
+
+    /// Failed to parse address.
+    AddressParseError(AddressParseError),
+}
+
+impl fmt::Display for AddressFromStrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Format => f.write_str("expect <address type>/<address>."),
+            Self::UnknownAddressType(e) => e.fmt(f),
+            Self::AddressParseError(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AddressFromStrError {}
+
+impl From<VariantParseError> for AddressFromStrError {
+    fn from(e: VariantParseError) -> Self {
+        Self::UnknownAddressType(e)
+    }
+}
+
+impl From<AddressParseError> for AddressFromStrError {
+    fn from(e: AddressParseError) -> Self {
+        Self::AddressParseError(e)
+    }
+}
+
+/// Random part is not 46 bits, or all zeros or all ones.
+#[derive(Debug)]
+pub struct InvalidRandomPart(u64);
+
+impl fmt::Display for InvalidRandomPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid random part. (expect: 46 bits neither all zeros nor all ones, but 0x{:x})",
+            self.0
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidRandomPart {}
+
+/// Buffer is shorter than the written form.
+#[derive(Debug)]
+pub struct BufferTooSmall {
+    expected: usize,
+    actual: usize,
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Buffer too small. (expect: {}, but {})",
+            self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+/// Slice length is not 6.
+#[derive(Debug)]
+pub struct InvalidAddressLength(usize);
+
+impl fmt::Display for InvalidAddressLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid address length. (expect: 6, but {})", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidAddressLength {}
+
+/// Error for `TryFrom<&[u8]>` of the tag checked addresses.
+#[derive(Debug)]
+pub enum AddressFromSliceError {
+    /// Slice length is not 6.
+    InvalidLength(InvalidAddressLength),
+
+    /// Invalid tag bits.
+    InvalidBits(InvalidBitsForAddressType),
+}
+
+impl fmt::Display for AddressFromSliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(e) => e.fmt(f),
+            Self::InvalidBits(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AddressFromSliceError {}
+
+impl From<InvalidAddressLength> for AddressFromSliceError {
+    fn from(e: InvalidAddressLength) -> Self {
+        Self::InvalidLength(e)
+    }
+}
+
+impl From<InvalidBitsForAddressType> for AddressFromSliceError {
+    fn from(e: InvalidBitsForAddressType) -> Self {
+        Self::InvalidBits(e)
+    }
+}
+
+/// Address type not supported by the destination.
+#[derive(Debug)]
+pub struct UnsupportedAddressType(AddressType);
+
+impl fmt::Display for UnsupportedAddressType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unsupported address type. ({:?})", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnsupportedAddressType {}
 
 /// Bluetooth Device Address without Address type.
-#[derive(Clone, PartialEq, Eq, Hash)]
+///
+/// This is guaranteed to have the same layout as `[u8; 6]` in little-endian (wire) order.
+///
+/// # Byte order
+///
+/// The octets are held in wire order, the least significant octet first as HCI and most stacks do, and
+/// the textual form is in display order, the most significant octet first.
+/// (`[0x00, 0x11, 0x22, 0x33, 0x44, 0x55]` is `55:44:33:22:11:00`)
+/// Conversions reverse the octets for types holding display order (e.g. MAC address types), and copy them
+/// as is for types holding wire order.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text, sql_type = diesel::sql_types::Binary)
+)]
+#[repr(transparent)]
 pub struct BdAddr([u8; 6]);
 
 impl BdAddr {
+    /// Construct from bytes in little-endian (wire) order.
+    pub const fn new(b: [u8; 6]) -> Self {
+        Self(b)
+    }
+
+    /// Construct from bytes in little-endian order, that is the HCI / over-the-air (wire) layout.
+    ///
+    /// Same as [`BdAddr::new`] and `From<[u8; 6]>`.
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = BdAddr::from_le_bytes([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+    /// assert_eq!("55:44:33:22:11:00", addr.to_string());
+    /// ```
+    pub const fn from_le_bytes(b: [u8; 6]) -> Self {
+        Self(b)
+    }
+
+    /// Construct from bytes in big-endian order, that is the human-readable (display) order.
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = BdAddr::from_be_bytes([0x55, 0x44, 0x33, 0x22, 0x11, 0x00]);
+    /// assert_eq!("55:44:33:22:11:00", addr.to_string());
+    /// ```
+    pub const fn from_be_bytes(b: [u8; 6]) -> Self {
+        Self([b[5], b[4], b[3], b[2], b[1], b[0]])
+    }
+
+    /// Bytes in little-endian order, that is the HCI / over-the-air (wire) layout.
+    ///
+    /// Same as `<[u8; 6]>::from`.
+    pub const fn to_le_bytes(self) -> [u8; 6] {
+        self.0
+    }
+
+    /// Bytes in big-endian order, that is the human-readable (display) order.
+    pub const fn to_be_bytes(self) -> [u8; 6] {
+        let b = self.0;
+        [b[5], b[4], b[3], b[2], b[1], b[0]]
+    }
+
+    /// Write bytes in little-endian (wire) order into the head of buffer.
+    ///
+    /// Error if `buf` is shorter than 6.
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = BdAddr::from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+    /// let mut packet = [0xff; 8];
+    /// addr.write_to(&mut packet[1..]).unwrap();
+    /// assert_eq!([0xff, 0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00, 0xff], packet);
+    /// ```
+    pub fn write_to(&self, buf: &mut [u8]) -> Result<(), BufferTooSmall> {
+        let actual = buf.len();
+        let head = buf.get_mut(..6).ok_or(BufferTooSmall {
+            expected: 6,
+            actual,
+        })?;
+        head.copy_from_slice(&self.0);
+        Ok(())
+    }
+
     /// Convert as BR/EDR Address.
+    ///
+    /// This tags unconditionally, use [`Address::as_bredr`] to re-tag LE Public Device Address only.
     pub fn to_br_edr_addr(self) -> Address {
         Address::BrEdr(self)
     }
 
     /// Convert as LE Public Device Address.
+    ///
+    /// This tags unconditionally, use [`Address::as_le_public`] to re-tag BR/EDR Address only.
     pub fn to_le_public_addr(self) -> Address {
         Address::LePublic(PublicDeviceAddress(self))
     }
@@ -34,27 +589,187 @@ impl BdAddr {
     pub fn to_le_random_addr(self) -> Address {
         Address::LeRandom(RandomDeviceAddress::new(self))
     }
+
+    /// Convert as the address type.
+    ///
+    /// LE Random Device Address is classified by most significant two bits.
+    /// Use [`Address::from_kind_parts`] to validate kind.
+    pub fn to_addr(self, address_type: AddressType) -> Address {
+        match address_type {
+            AddressType::BrEdr => self.to_br_edr_addr(),
+            AddressType::LePublic => self.to_le_public_addr(),
+            AddressType::LeRandom => self.to_le_random_addr(),
+        }
+    }
+
+    /// Read from raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of 6 bytes. No alignment is required.
+    pub unsafe fn from_ptr(ptr: *const u8) -> Self {
+        Self((ptr as *const [u8; 6]).read_unaligned())
+    }
+
+    /// Get raw pointer to the 6 bytes.
+    pub fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+
+    fn value(&self) -> i64 {
+        let b = self.0;
+        i64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], 0, 0])
+    }
+
+    /// Numeric difference from `other`, as display order value. (`self - other`)
+    ///
+    /// e.g. `00:11:22:33:45:00` is `1` from `00:11:22:33:44:ff`.
+    pub fn offset_from(&self, other: &BdAddr) -> i64 {
+        self.value() - other.value()
+    }
+
+    /// Test numeric difference from `other` is up to `delta`.
+    pub fn is_within(&self, other: &BdAddr, delta: u8) -> bool {
+        self.offset_from(other).abs() <= delta as i64
+    }
+
+    /// Get Organizationally Unique Identifier. (most significant 3 octets, in display order)
+    ///
+    /// e.g. `[0x00, 0x1a, 0x7d]` of `00:1a:7d:da:71:13`. These are the last 3 octets in wire order, reversed.
+    pub fn oui(&self) -> [u8; 3] {
+        [self.0[5], self.0[4], self.0[3]]
+    }
+
+    /// Get NIC specific part. (least significant 3 octets, in display order)
+    ///
+    /// e.g. `[0xda, 0x71, 0x13]` of `00:1a:7d:da:71:13`. These are the first 3 octets in wire order, reversed.
+    pub fn lower_half(&self) -> [u8; 3] {
+        [self.0[2], self.0[1], self.0[0]]
+    }
+
+    /// Test U/L bit (`0b10`) of the first octet in display order is set. (e.g. `02:00:00:00:00:00`)
+    ///
+    /// The first octet in display order is the **last** stored octet in wire order, as `<[u8; 6]>::from(addr)[5]`.
+    /// Only meaningful for public addresses, these bits of LE Random Device Address are random part or tag bits.
+    pub fn is_locally_administered(&self) -> bool {
+        self.0[5] & 0b10 != 0
+    }
+
+    /// Test I/G bit (`0b01`) of the first octet in display order is set. (e.g. `01:00:5e:00:00:00`)
+    ///
+    /// The first octet in display order is the **last** stored octet in wire order, as `<[u8; 6]>::from(addr)[5]`.
+    /// Only meaningful for public addresses, these bits of LE Random Device Address are random part or tag bits.
+    pub fn is_group_address(&self) -> bool {
+        self.0[5] & 0b01 != 0
+    }
+
+    /// Reinterpret reference of bytes as reference of BdAddr.
+    pub fn from_ref(v: &[u8; 6]) -> &Self {
+        // SAFETY: BdAddr is repr(transparent) over [u8; 6]
+        unsafe { &*(v as *const [u8; 6] as *const Self) }
+    }
 }
 
+/// Bytes in little-endian (wire) order, same as [`BdAddr::from_le_bytes`].
 impl From<[u8; 6]> for BdAddr {
     fn from(v: [u8; 6]) -> Self {
         Self(v)
     }
 }
 
+/// Bytes in little-endian (wire) order, same as [`BdAddr::to_le_bytes`].
 impl From<BdAddr> for [u8; 6] {
     fn from(v: BdAddr) -> Self {
         v.0
     }
 }
 
+impl AsRef<BdAddr> for BdAddr {
+    fn as_ref(&self) -> &BdAddr {
+        self
+    }
+}
+
+impl BdAddr {
+    /// Colon separated hex digits in display order.
+    fn hex(&self, digits: &[u8; 16]) -> [u8; 17] {
+        let mut buf = [b':'; 17];
+        for (i, v) in self.0.iter().rev().enumerate() {
+            buf[i * 3] = digits[(v >> 4) as usize];
+            buf[i * 3 + 1] = digits[(v & 0xf) as usize];
+        }
+        buf
+    }
+}
+
+impl BdAddr {
+    /// Length of the textual form. (`xx:xx:xx:xx:xx:xx`)
+    pub const STR_LEN: usize = 17;
+
+    /// Write the textual form (same as [`fmt::Display`]) into buffer, and return it as str.
+    ///
+    /// This does not allocate, for `no_std` without `alloc`.
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = BdAddr::from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+    /// let mut buf = [0; BdAddr::STR_LEN];
+    /// assert_eq!("00:1a:7d:da:71:13", addr.write_str_to(&mut buf));
+    /// ```
+    pub fn write_str_to<'a>(&self, buf: &'a mut [u8; 17]) -> &'a str {
+        *buf = self.hex(b"0123456789abcdef");
+        match core::str::from_utf8(buf) {
+            Ok(s) => s,
+            Err(..) => unreachable!(),
+        }
+    }
+
+    /// Write the textual form into the head of buffer, and return it as str.
+    ///
+    /// Error if `buf` is shorter than [`BdAddr::STR_LEN`].
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = BdAddr::from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+    /// let mut buf = [0; 64];
+    /// assert_eq!("00:1a:7d:da:71:13", addr.write_str_to_slice(&mut buf).unwrap());
+    /// assert!(addr.write_str_to_slice(&mut [0; 16]).is_err());
+    /// ```
+    pub fn write_str_to_slice<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, BufferTooSmall> {
+        let err = BufferTooSmall {
+            expected: Self::STR_LEN,
+            actual: buf.len(),
+        };
+        let head = buf
+            .get_mut(..Self::STR_LEN)
+            .and_then(|b| <&mut [u8; 17]>::try_from(b).ok())
+            .ok_or(err)?;
+        Ok(self.write_str_to(head))
+    }
+}
+
+/// Lowercase, same as [`fmt::LowerHex`]. Width, fill and alignment are honored.
 impl fmt::Display for BdAddr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
-            self.0[5], self.0[4], self.0[3], self.0[2], self.0[1], self.0[0]
-        )
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+/// `55:44:33:22:11:00`
+impl fmt::LowerHex for BdAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let buf = self.hex(b"0123456789abcdef");
+        f.pad(core::str::from_utf8(&buf).map_err(|_| fmt::Error)?)
+    }
+}
+
+/// `55:44:33:22:11:00` in uppercase. (e.g. `AA:BB:CC:DD:EE:FF`)
+impl fmt::UpperHex for BdAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let buf = self.hex(b"0123456789ABCDEF");
+        f.pad(core::str::from_utf8(&buf).map_err(|_| fmt::Error)?)
     }
 }
 
@@ -64,17 +779,110 @@ impl fmt::Debug for BdAddr {
     }
 }
 
+/// Ordered by display order value. (e.g. `00:00:00:00:00:ff` < `00:00:00:00:01:00`)
+impl Ord for BdAddr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl PartialOrd for BdAddr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Value of hex digit. (case-insensitive, unlike `from_str_radix` no sign is accepted)
+const fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Walk 6 groups of exactly two digits separated by `sep` from `start`, in display order.
+///
+/// Error positions are offsets in `s`.
+fn parse_groups(
+    s: &[u8],
+    start: usize,
+    sep: u8,
+    digit: impl Fn(u8) -> Option<u8>,
+) -> Result<BdAddr, AddressParseError> {
+    parse_groups_of(s, start, &[2; 6], sep, &[], digit)
+}
+
+/// Walk groups of exactly `widths` digits (12 in total) separated by `sep` from `start`, in display order.
+///
+/// Separators in `others` are [`AddressParseErrorKind::MixedSeparators`], and the other non-digits are
+/// [`AddressParseErrorKind::InvalidDigit`]. Error positions are offsets in `s`.
+fn parse_groups_of(
+    s: &[u8],
+    start: usize,
+    widths: &[usize],
+    sep: u8,
+    others: &[u8],
+    digit: impl Fn(u8) -> Option<u8>,
+) -> Result<BdAddr, AddressParseError> {
+    let groups = widths.len();
+    let mut pos = start;
+    let mut v = 0u64;
+    for (i, width) in widths.iter().enumerate() {
+        let err = |kind, pos| Err(AddressParseError::new(kind, i, pos));
+        let start = pos;
+        while let Some(d) = s.get(pos).and_then(|c| digit(*c)) {
+            if pos - start == *width {
+                return err(AddressParseErrorKind::GroupTooLong, pos);
+            }
+            v = v << 4 | d as u64;
+            pos += 1;
+        }
+        match (pos - start, s.get(pos)) {
+            (_, Some(c)) if *c != sep && !others.contains(c) => {
+                return err(AddressParseErrorKind::InvalidDigit, pos)
+            }
+            (0, _) => return err(AddressParseErrorKind::Empty, pos),
+            (n, _) if n < *width => return err(AddressParseErrorKind::GroupTooShort, pos),
+            _ => {}
+        }
+
+        match s.get(pos) {
+            Some(c) if *c != sep => return err(AddressParseErrorKind::MixedSeparators, pos),
+            Some(..) if i < groups - 1 => pos += 1,
+            None if i == groups - 1 => {}
+            None => {
+                return err(
+                    AddressParseErrorKind::WrongGroupCount {
+                        expected: groups,
+                        found: i + 1,
+                    },
+                    pos,
+                )
+            }
+            Some(..) => {
+                let found = groups + s[pos..].iter().filter(|c| **c == sep).count();
+                return err(
+                    AddressParseErrorKind::WrongGroupCount {
+                        expected: groups,
+                        found,
+                    },
+                    pos,
+                );
+            }
+        }
+    }
+    let b = v.to_le_bytes();
+    Ok(BdAddr([b[0], b[1], b[2], b[3], b[4], b[5]]))
+}
+
 impl FromStr for BdAddr {
     type Err = AddressParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s
-            .splitn(6, ':')
-            .map(|v| u8::from_str_radix(v, 16))
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|_| AddressParseError)?;
-        parts.reverse();
-        Ok(Self(parts.try_into().map_err(|_| AddressParseError)?))
+        // Walk once. Each group is exactly two hex digits, that is the inverse of `Display`.
+        parse_groups(s.as_bytes(), 0, b':', hex_digit)
     }
 }
 
@@ -86,10 +894,41 @@ impl TryFrom<&str> for BdAddr {
     }
 }
 
+/// Bytes in little-endian (wire) order. (e.g. address field of HCI event)
+impl TryFrom<&[u8]> for BdAddr {
+    type Error = InvalidAddressLength;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        <[u8; 6]>::try_from(v)
+            .map(Self)
+            .map_err(|_| InvalidAddressLength(v.len()))
+    }
+}
+
+/// Mask of random part of LE Random Device Address. (46 bits)
+const RANDOM_PART_MASK: u64 = (1 << 46) - 1;
+
+/// Random part with tag bits as bytes in wire order.
+fn from_random_part(bits: u64, tag: u8) -> Result<[u8; 6], InvalidRandomPart> {
+    // all ones is `RANDOM_PART_MASK`, beyond it is wider than 46 bits
+    if bits == 0 || bits >= RANDOM_PART_MASK {
+        return Err(InvalidRandomPart(bits));
+    }
+    let b = (bits | (tag as u64) << 46).to_le_bytes();
+    Ok([b[0], b[1], b[2], b[3], b[4], b[5]])
+}
+
 /// LE Public Device Address
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct PublicDeviceAddress(BdAddr);
 
+impl PublicDeviceAddress {
+    /// Construct from bytes in little-endian (wire) order.
+    pub const fn new(b: [u8; 6]) -> Self {
+        Self(BdAddr(b))
+    }
+}
+
 impl From<[u8; 6]> for PublicDeviceAddress {
     fn from(v: [u8; 6]) -> Self {
         Self(v.into())
@@ -102,6 +941,21 @@ impl From<PublicDeviceAddress> for Address {
     }
 }
 
+/// Bytes in little-endian (wire) order.
+impl TryFrom<&[u8]> for PublicDeviceAddress {
+    type Error = InvalidAddressLength;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        BdAddr::try_from(v).map(Self)
+    }
+}
+
+impl AsRef<BdAddr> for PublicDeviceAddress {
+    fn as_ref(&self) -> &BdAddr {
+        &self.0
+    }
+}
+
 impl fmt::Display for PublicDeviceAddress {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -109,11 +963,42 @@ impl fmt::Display for PublicDeviceAddress {
 }
 
 /// LE Non-Resolvable Private Address
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NonResolvablePrivateAddress(BdAddr);
 
 impl NonResolvablePrivateAddress {
     const TAG: u8 = 0b00;
+
+    /// Construct from bytes in little-endian (wire) order.
+    ///
+    /// Fails if the tag bits (most significant two bits) do not match.
+    pub fn new(b: [u8; 6]) -> Result<Self, InvalidBitsForAddressType> {
+        Self::try_from(b)
+    }
+
+    /// Construct from bytes in little-endian (wire) order without checking the tag bits.
+    ///
+    /// The caller must ensure the tag bits (most significant two bits) are `0b00`.
+    pub const fn new_unchecked(b: [u8; 6]) -> Self {
+        Self(BdAddr(b))
+    }
+
+    /// Construct from random part. (46 bits)
+    ///
+    /// The random part is the address as 48 bits integer in display order without the tag bits,
+    /// e.g. `0x1` is `00:00:00:00:00:01` and `0x2000_0000_0000` is `20:00:00:00:00:00`.
+    /// Fails if wider than 46 bits, or all zeros or all ones.
+    /// The caller must also ensure it is not equal to the public address.
+    ///
+    /// ref BLUETOOTH CORE SPECIFICATION | Vol 6, Part B | 1.3.2.2 Non-resolvable private address generation
+    pub fn from_random_part(bits: u64) -> Result<Self, InvalidRandomPart> {
+        Ok(Self(from_random_part(bits, Self::TAG)?.into()))
+    }
+
+    /// Get random part. (46 bits, see [`NonResolvablePrivateAddress::from_random_part`])
+    pub fn random_part(&self) -> u64 {
+        self.0.value() as u64 & RANDOM_PART_MASK
+    }
 }
 
 impl TryFrom<[u8; 6]> for NonResolvablePrivateAddress {
@@ -123,7 +1008,11 @@ impl TryFrom<[u8; 6]> for NonResolvablePrivateAddress {
         if (v[5] & 0xC0) >> 6 == Self::TAG {
             Ok(Self(v.into()))
         } else {
-            Err(InvalidBitsForAddressType(Self::TAG, (v[5] & 0xC0) >> 6))
+            Err(InvalidBitsForAddressType::new(
+                "NonResolvablePrivateAddress",
+                Self::TAG,
+                v,
+            ))
         }
     }
 }
@@ -134,6 +1023,12 @@ impl From<NonResolvablePrivateAddress> for RandomDeviceAddress {
     }
 }
 
+impl AsRef<BdAddr> for NonResolvablePrivateAddress {
+    fn as_ref(&self) -> &BdAddr {
+        &self.0
+    }
+}
+
 impl fmt::Display for NonResolvablePrivateAddress {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -141,11 +1036,43 @@ impl fmt::Display for NonResolvablePrivateAddress {
 }
 
 /// LE Resolvable Private Address
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ResolvablePrivateAddress(BdAddr);
 
 impl ResolvablePrivateAddress {
     const TAG: u8 = 0b01;
+
+    /// Construct from bytes in little-endian (wire) order.
+    ///
+    /// Fails if the tag bits (most significant two bits) do not match.
+    pub fn new(b: [u8; 6]) -> Result<Self, InvalidBitsForAddressType> {
+        Self::try_from(b)
+    }
+
+    /// Construct from bytes in little-endian (wire) order without checking the tag bits.
+    ///
+    /// The caller must ensure the tag bits (most significant two bits) are `0b01`.
+    pub const fn new_unchecked(b: [u8; 6]) -> Self {
+        Self(BdAddr(b))
+    }
+
+    /// Get `prand`. (24 bits, most significant 3 octets in display order, including the tag bits)
+    ///
+    /// e.g. `0x53_038c` of `53:03:8c:bc:bd:82`.
+    ///
+    /// ref BLUETOOTH CORE SPECIFICATION | Vol 6, Part B | 1.3.2.2 Resolvable private address generation
+    pub fn prand(&self) -> u32 {
+        let b = &self.0 .0;
+        u32::from_le_bytes([b[3], b[4], b[5], 0])
+    }
+
+    /// Get `hash`. (24 bits, least significant 3 octets in display order)
+    ///
+    /// e.g. `0xbc_bd82` of `53:03:8c:bc:bd:82`.
+    pub fn hash(&self) -> u32 {
+        let b = &self.0 .0;
+        u32::from_le_bytes([b[0], b[1], b[2], 0])
+    }
 }
 
 impl TryFrom<[u8; 6]> for ResolvablePrivateAddress {
@@ -155,7 +1082,11 @@ impl TryFrom<[u8; 6]> for ResolvablePrivateAddress {
         if (v[5] & 0xC0) >> 6 == Self::TAG {
             Ok(Self(v.into()))
         } else {
-            Err(InvalidBitsForAddressType(Self::TAG, (v[5] & 0xC0) >> 6))
+            Err(InvalidBitsForAddressType::new(
+                "ResolvablePrivateAddress",
+                Self::TAG,
+                v,
+            ))
         }
     }
 }
@@ -166,6 +1097,12 @@ impl From<ResolvablePrivateAddress> for RandomDeviceAddress {
     }
 }
 
+impl AsRef<BdAddr> for ResolvablePrivateAddress {
+    fn as_ref(&self) -> &BdAddr {
+        &self.0
+    }
+}
+
 impl fmt::Display for ResolvablePrivateAddress {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -173,11 +1110,41 @@ impl fmt::Display for ResolvablePrivateAddress {
 }
 
 /// LE Static Device Address
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct StaticDeviceAddress(BdAddr);
 
 impl StaticDeviceAddress {
     const TAG: u8 = 0b11;
+
+    /// Construct from bytes in little-endian (wire) order.
+    ///
+    /// Fails if the tag bits (most significant two bits) do not match.
+    pub fn new(b: [u8; 6]) -> Result<Self, InvalidBitsForAddressType> {
+        Self::try_from(b)
+    }
+
+    /// Construct from bytes in little-endian (wire) order without checking the tag bits.
+    ///
+    /// The caller must ensure the tag bits (most significant two bits) are `0b11`.
+    pub const fn new_unchecked(b: [u8; 6]) -> Self {
+        Self(BdAddr(b))
+    }
+
+    /// Construct from random part. (46 bits)
+    ///
+    /// The random part is the address as 48 bits integer in display order without the tag bits,
+    /// e.g. `0x1` is `c0:00:00:00:00:01` and `0x2000_0000_0000` is `e0:00:00:00:00:00`.
+    /// Fails if wider than 46 bits, or all zeros or all ones.
+    ///
+    /// ref BLUETOOTH CORE SPECIFICATION | Vol 6, Part B | 1.3.2.1 Static device address
+    pub fn from_random_part(bits: u64) -> Result<Self, InvalidRandomPart> {
+        Ok(Self(from_random_part(bits, Self::TAG)?.into()))
+    }
+
+    /// Get random part. (46 bits, see [`StaticDeviceAddress::from_random_part`])
+    pub fn random_part(&self) -> u64 {
+        self.0.value() as u64 & RANDOM_PART_MASK
+    }
 }
 
 impl TryFrom<[u8; 6]> for StaticDeviceAddress {
@@ -187,7 +1154,11 @@ impl TryFrom<[u8; 6]> for StaticDeviceAddress {
         if (v[5] & 0xC0) >> 6 == Self::TAG {
             Ok(Self(v.into()))
         } else {
-            Err(InvalidBitsForAddressType(Self::TAG, (v[5] & 0xC0) >> 6))
+            Err(InvalidBitsForAddressType::new(
+                "StaticDeviceAddress",
+                Self::TAG,
+                v,
+            ))
         }
     }
 }
@@ -198,6 +1169,12 @@ impl From<StaticDeviceAddress> for RandomDeviceAddress {
     }
 }
 
+impl AsRef<BdAddr> for StaticDeviceAddress {
+    fn as_ref(&self) -> &BdAddr {
+        &self.0
+    }
+}
+
 impl fmt::Display for StaticDeviceAddress {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -205,7 +1182,9 @@ impl fmt::Display for StaticDeviceAddress {
 }
 
 /// LE Random Device Address
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// Ordered by variant (`NonResolvable`, `Resolvable`, `Static`, `Unknown`), then by address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum RandomDeviceAddress {
     /// Non-Resolvable Private Address
     NonResolvable(NonResolvablePrivateAddress),
@@ -221,7 +1200,7 @@ pub enum RandomDeviceAddress {
 }
 
 impl RandomDeviceAddress {
-    fn new(addr: BdAddr) -> Self {
+    const fn new(addr: BdAddr) -> Self {
         match (addr.0[5] & 0xC0) >> 6 {
             NonResolvablePrivateAddress::TAG => {
                 Self::NonResolvable(NonResolvablePrivateAddress(addr))
@@ -231,6 +1210,55 @@ impl RandomDeviceAddress {
             _ => Self::Unknown(addr),
         }
     }
+
+    /// Get kind of this address.
+    pub fn kind(&self) -> RandomDeviceAddressKind {
+        match self {
+            Self::NonResolvable(..) => RandomDeviceAddressKind::NonResolvable,
+            Self::Resolvable(..) => RandomDeviceAddressKind::Resolvable,
+            Self::Static(..) => RandomDeviceAddressKind::Static,
+            Self::Unknown(..) => RandomDeviceAddressKind::Unknown,
+        }
+    }
+
+    /// Split into kind and inner BdAddr.
+    pub fn into_parts(self) -> (RandomDeviceAddressKind, BdAddr) {
+        let kind = self.kind();
+        let addr = match self {
+            Self::NonResolvable(NonResolvablePrivateAddress(addr)) => addr,
+            Self::Resolvable(ResolvablePrivateAddress(addr)) => addr,
+            Self::Static(StaticDeviceAddress(addr)) => addr,
+            Self::Unknown(addr) => addr,
+        };
+        (kind, addr)
+    }
+
+    /// Construct from kind and BdAddr.
+    ///
+    /// Fails if most significant two bits do not match the kind. (`0b10` for `Unknown`)
+    pub fn from_parts(
+        kind: RandomDeviceAddressKind,
+        addr: BdAddr,
+    ) -> Result<Self, InvalidBitsForAddressType> {
+        let b = addr.0;
+        match kind {
+            RandomDeviceAddressKind::NonResolvable => {
+                NonResolvablePrivateAddress::try_from(b).map(Self::NonResolvable)
+            }
+            RandomDeviceAddressKind::Resolvable => {
+                ResolvablePrivateAddress::try_from(b).map(Self::Resolvable)
+            }
+            RandomDeviceAddressKind::Static => StaticDeviceAddress::try_from(b).map(Self::Static),
+            RandomDeviceAddressKind::Unknown => match Self::new(addr) {
+                v @ Self::Unknown(..) => Ok(v),
+                _ => Err(InvalidBitsForAddressType::new(
+                    "RandomDeviceAddress",
+                    0b10,
+                    b,
+                )),
+            },
+        }
+    }
 }
 
 impl From<[u8; 6]> for RandomDeviceAddress {
@@ -245,6 +1273,26 @@ impl From<RandomDeviceAddress> for Address {
     }
 }
 
+/// Bytes in little-endian (wire) order, classified by the tag bits.
+impl TryFrom<&[u8]> for RandomDeviceAddress {
+    type Error = InvalidAddressLength;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        BdAddr::try_from(v).map(Self::new)
+    }
+}
+
+impl AsRef<BdAddr> for RandomDeviceAddress {
+    fn as_ref(&self) -> &BdAddr {
+        match self {
+            Self::NonResolvable(NonResolvablePrivateAddress(addr)) => addr,
+            Self::Resolvable(ResolvablePrivateAddress(addr)) => addr,
+            Self::Static(StaticDeviceAddress(addr)) => addr,
+            Self::Unknown(addr) => addr,
+        }
+    }
+}
+
 impl fmt::Display for RandomDeviceAddress {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -256,21 +1304,146 @@ impl fmt::Display for RandomDeviceAddress {
     }
 }
 
+/// Kind of [`RandomDeviceAddress`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum RandomDeviceAddressKind {
+    /// Non-Resolvable Private Address
+    NonResolvable,
+
+    /// Resolvable Private Address
+    Resolvable,
+
+    /// Static Device Address
+    Static,
+
+    /// Unknown
+    Unknown,
+}
+
+impl RandomDeviceAddressKind {
+    /// Iterate all variants.
+    ///
+    /// In declaration order. (`NonResolvable`, `Resolvable`, `Static` and `Unknown`)
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        [
+            Self::NonResolvable,
+            Self::Resolvable,
+            Self::Static,
+            Self::Unknown,
+        ]
+        .into_iter()
+    }
+}
+
+/// `non-resolvable`, `resolvable`, `static` or `unknown`.
+impl AsRef<str> for RandomDeviceAddressKind {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::NonResolvable => "non-resolvable",
+            Self::Resolvable => "resolvable",
+            Self::Static => "static",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for RandomDeviceAddressKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl FromStr for RandomDeviceAddressKind {
+    type Err = VariantParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|v| v.as_ref() == s)
+            .ok_or_else(|| VariantParseError::new("random device address kind", s))
+    }
+}
+
 /// Address type for [`Address`]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum AddressType {
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AddressType {
+    /// Classic BR/EDR Address
+    BrEdr,
+
+    /// LE Public Device Address
+    LePublic,
+
+    /// LE Random Device Address
+    LeRandom,
+}
+
+impl AddressType {
+    /// Iterate all variants.
+    ///
+    /// In declaration order. (`BrEdr`, `LePublic` and `LeRandom`)
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        [Self::BrEdr, Self::LePublic, Self::LeRandom].into_iter()
+    }
+
+    /// Same as [`AsRef<str>`], in `'static` lifetime.
+    pub(crate) const fn as_str(&self) -> &'static str {
+        match self {
+            Self::BrEdr => "bredr",
+            Self::LePublic => "public",
+            Self::LeRandom => "random",
+        }
+    }
+}
+
+/// `bredr`, `public` or `random`. (same as the address type prefix of [`Address`] in serde, and BlueZ)
+impl AsRef<str> for AddressType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AddressType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl FromStr for AddressType {
+    type Err = VariantParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|v| v.as_ref() == s)
+            .ok_or_else(|| VariantParseError::address_type(s))
+    }
+}
+
+/// [`AddressType`] with kind of LE Random Device Address, for [`Address::from_kind_parts`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AddressKind {
     /// Classic BR/EDR Address
     BrEdr,
 
     /// LE Public Device Address
     LePublic,
 
-    /// LE Random Device Address
-    LeRandom,
+    /// LE Random Device Address of the kind
+    LeRandom(RandomDeviceAddressKind),
+}
+
+impl From<AddressKind> for AddressType {
+    fn from(v: AddressKind) -> Self {
+        match v {
+            AddressKind::BrEdr => Self::BrEdr,
+            AddressKind::LePublic => Self::LePublic,
+            AddressKind::LeRandom(..) => Self::LeRandom,
+        }
+    }
 }
 
 /// Bluetooth Device Address
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Address {
     /// Classic BR/EDR Address
     BrEdr(BdAddr),
@@ -284,18 +1457,18 @@ pub enum Address {
 
 impl Address {
     /// Construct Classic BR/EDR Address from bytes.
-    pub fn bredr_from(b: [u8; 6]) -> Self {
-        Self::BrEdr(b.into())
+    pub const fn bredr_from(b: [u8; 6]) -> Self {
+        Self::BrEdr(BdAddr(b))
     }
 
     /// Construct LE Public Device Address from bytes.
-    pub fn le_public_from(b: [u8; 6]) -> Self {
-        Self::LePublic(b.into())
+    pub const fn le_public_from(b: [u8; 6]) -> Self {
+        Self::LePublic(PublicDeviceAddress(BdAddr(b)))
     }
 
     /// Construct LE Random Device Address from bytes.
-    pub fn le_random_from(b: [u8; 6]) -> Self {
-        Self::LeRandom(b.into())
+    pub const fn le_random_from(b: [u8; 6]) -> Self {
+        Self::LeRandom(RandomDeviceAddress::new(BdAddr(b)))
     }
 
     /// Construct Classic BR/EDR Address from str.
@@ -339,6 +1512,158 @@ impl Address {
             Self::LeRandom(..) => AddressType::LeRandom,
         }
     }
+
+    /// Heuristic for dual-mode device using BR/EDR and LE Public addresses adjacent to each other.
+    ///
+    /// True if both are BR/EDR or LE Public Device Address, and within `max_delta`.
+    /// Always false for LE Random Device Address.
+    pub fn maybe_same_dual_mode_device(&self, other: &Address, max_delta: u8) -> bool {
+        match (self, other) {
+            (
+                Self::BrEdr(a) | Self::LePublic(PublicDeviceAddress(a)),
+                Self::BrEdr(b) | Self::LePublic(PublicDeviceAddress(b)),
+            ) => a.is_within(b, max_delta),
+            _ => false,
+        }
+    }
+
+    /// Re-tag LE Public Device Address as BR/EDR Address of the same dual-mode device.
+    ///
+    /// BR/EDR Address is returned as is, and LE Random Device Address is `None`,
+    /// as only the public address is shared between the transports.
+    /// Use [`BdAddr::to_br_edr_addr`] to tag regardless of the source.
+    ///
+    /// ref BLUETOOTH CORE SPECIFICATION | Vol 3, Part C | 15.1 BLUETOOTH DEVICE ADDRESS
+    pub fn as_bredr(&self) -> Option<Address> {
+        match self {
+            Self::BrEdr(..) => Some(*self),
+            Self::LePublic(PublicDeviceAddress(addr)) => Some(Self::BrEdr(*addr)),
+            Self::LeRandom(..) => None,
+        }
+    }
+
+    /// Re-tag BR/EDR Address as LE Public Device Address of the same dual-mode device.
+    ///
+    /// LE Public Device Address is returned as is, and LE Random Device Address is `None`,
+    /// as only the public address is shared between the transports.
+    /// Use [`BdAddr::to_le_public_addr`] to tag regardless of the source.
+    ///
+    /// ref BLUETOOTH CORE SPECIFICATION | Vol 3, Part C | 15.1 BLUETOOTH DEVICE ADDRESS
+    pub fn as_le_public(&self) -> Option<Address> {
+        match self {
+            Self::BrEdr(addr) => Some(Self::LePublic(PublicDeviceAddress(*addr))),
+            Self::LePublic(..) => Some(*self),
+            Self::LeRandom(..) => None,
+        }
+    }
+
+    /// Split into address type and inner BdAddr.
+    ///
+    /// Use [`Address::into_kind_parts`] for kind of LE Random Device Address, and
+    /// [`Address::from_parts`] to join.
+    pub fn into_parts(self) -> (AddressType, BdAddr) {
+        (self.address_type(), self.into_bd_addr())
+    }
+
+    /// Construct from address type and BdAddr. (same as [`BdAddr::to_addr`])
+    ///
+    /// LE Random Device Address is classified by most significant two bits.
+    pub fn from_parts(address_type: AddressType, addr: BdAddr) -> Self {
+        addr.to_addr(address_type)
+    }
+
+    /// Split into kind and inner BdAddr.
+    ///
+    /// Use [`Address::from_kind_parts`] to join.
+    pub fn into_kind_parts(self) -> (AddressKind, BdAddr) {
+        let kind = match self {
+            Self::BrEdr(..) => AddressKind::BrEdr,
+            Self::LePublic(..) => AddressKind::LePublic,
+            Self::LeRandom(addr) => AddressKind::LeRandom(addr.kind()),
+        };
+        (kind, self.into_bd_addr())
+    }
+
+    /// Construct from kind and BdAddr.
+    ///
+    /// Fails if most significant two bits of LE Random Device Address do not match the kind.
+    /// (same as [`RandomDeviceAddress::from_parts`])
+    ///
+    /// ```
+    /// use bdaddr::{Address, AddressKind, BdAddr, RandomDeviceAddressKind};
+    ///
+    /// let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]);
+    /// let kind = AddressKind::LeRandom(RandomDeviceAddressKind::Static);
+    /// assert_eq!(Address::le_random_from(addr.into()), Address::from_kind_parts(kind, addr).unwrap());
+    /// let kind = AddressKind::LeRandom(RandomDeviceAddressKind::Resolvable);
+    /// assert!(Address::from_kind_parts(kind, addr).is_err());
+    /// ```
+    pub fn from_kind_parts(
+        kind: AddressKind,
+        addr: BdAddr,
+    ) -> Result<Self, InvalidBitsForAddressType> {
+        match kind {
+            AddressKind::BrEdr => Ok(addr.to_br_edr_addr()),
+            AddressKind::LePublic => Ok(addr.to_le_public_addr()),
+            AddressKind::LeRandom(kind) => {
+                RandomDeviceAddress::from_parts(kind, addr).map(Self::LeRandom)
+            }
+        }
+    }
+
+    /// Encode as address type code and 6 bytes in little-endian (wire) order.
+    ///
+    /// | Code   | Address                  |
+    /// |--------|--------------------------|
+    /// | `0x00` | Classic BR/EDR Address   |
+    /// | `0x01` | LE Public Device Address |
+    /// | `0x02` | LE Random Device Address |
+    ///
+    /// This is the binary form of serde, redis and base32. It is not the HCI form of
+    /// [`Address::to_hci_bytes`] (`0x00` Public, `0x01` Random), and the two cannot be told apart.
+    ///
+    /// ```
+    /// use bdaddr::Address;
+    ///
+    /// let addr = Address::le_public_from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+    /// let b = addr.to_tagged_bytes();
+    /// assert_eq!([0x01, 0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00], b);
+    /// assert_eq!(addr, Address::from_tagged_bytes(b).unwrap());
+    /// ```
+    pub fn to_tagged_bytes(&self) -> [u8; 7] {
+        let ty = match self {
+            Self::BrEdr(..) => 0x00,
+            Self::LePublic(..) => 0x01,
+            Self::LeRandom(..) => 0x02,
+        };
+        let [b0, b1, b2, b3, b4, b5] = self.into_bd_addr().0;
+        [ty, b0, b1, b2, b3, b4, b5]
+    }
+
+    /// Decode [`Address::to_tagged_bytes`] form.
+    ///
+    /// LE Random Device Address is classified by the tag bits, and the reserved `0b10` is [`RandomDeviceAddress::Unknown`].
+    /// Other codes are [`UnknownAddressType`].
+    pub fn from_tagged_bytes(b: [u8; 7]) -> Result<Self, UnknownAddressType> {
+        let [ty, b0, b1, b2, b3, b4, b5] = b;
+        let addr = [b0, b1, b2, b3, b4, b5];
+        match ty {
+            0x00 => Ok(Self::bredr_from(addr)),
+            0x01 => Ok(Self::le_public_from(addr)),
+            0x02 => Ok(Self::le_random_from(addr)),
+            x => Err(UnknownAddressType(x)),
+        }
+    }
+}
+
+impl AsRef<BdAddr> for Address {
+    fn as_ref(&self) -> &BdAddr {
+        match self {
+            Self::BrEdr(addr) => addr,
+            Self::LePublic(addr) => addr.as_ref(),
+            Self::LeRandom(addr) => addr.as_ref(),
+        }
+    }
 }
 
 impl fmt::Display for Address {
@@ -351,10 +1676,109 @@ impl fmt::Display for Address {
     }
 }
 
+/// `<address type>/<address>`. (e.g. `public/00:1a:7d:da:71:13`)
+///
+/// Address type is `bredr`, `public` or `random` as [`AddressType::from_str`], and the kind of random address
+/// is derived from the address. (the reserved tag bits `0b10` are [`RandomDeviceAddress::Unknown`]) This is the string form of serde, clap, Redis and the bindings, not the
+/// inverse of [`Display`](fmt::Display) that omits address type. Error positions are offsets in the whole string.
+///
+/// ```
+/// use bdaddr::{Address, AddressFromStrError, AddressType};
+///
+/// let addr = "random/53:03:8c:bc:bd:82".parse::<Address>().unwrap();
+/// assert_eq!(AddressType::LeRandom, addr.address_type());
+/// assert_eq!("53:03:8c:bc:bd:82", addr.to_string());
+/// assert!(matches!(
+///     "53:03:8c:bc:bd:82".parse::<Address>(),
+///     Err(AddressFromStrError::Format)
+/// ));
+/// ```
+impl FromStr for Address {
+    type Err = AddressFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ty, _) = s.split_once('/').ok_or(AddressFromStrError::Format)?;
+        let start = ty.len() + 1;
+        let ty = ty.parse::<AddressType>()?;
+        Ok(parse_groups(s.as_bytes(), start, b':', hex_digit)?.to_addr(ty))
+    }
+}
+
+/// `TryFrom<&[u8]>` of the tag checked addresses, through `TryFrom<[u8; 6]>`.
+macro_rules! impl_try_from_slice {
+    ($($ty:ty),*) => {
+        $(
+            /// Bytes in little-endian (wire) order, with the tag bits checked.
+            impl TryFrom<&[u8]> for $ty {
+                type Error = AddressFromSliceError;
+
+                fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+                    let b = BdAddr::try_from(v)?.0;
+                    Ok(Self::try_from(b)?)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_slice!(
+    NonResolvablePrivateAddress,
+    ResolvablePrivateAddress,
+    StaticDeviceAddress
+);
+
+/// Forward [`fmt::LowerHex`] and [`fmt::UpperHex`] to inner [`BdAddr`].
+macro_rules! forward_hex_fmt {
+    ($($ty:ty),*) => {
+        $(
+            impl fmt::LowerHex for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::LowerHex::fmt(self.as_ref(), f)
+                }
+            }
+
+            impl fmt::UpperHex for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::UpperHex::fmt(self.as_ref(), f)
+                }
+            }
+        )*
+    };
+}
+
+forward_hex_fmt!(
+    PublicDeviceAddress,
+    NonResolvablePrivateAddress,
+    ResolvablePrivateAddress,
+    StaticDeviceAddress,
+    RandomDeviceAddress,
+    Address
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const _: [(); 6] = [(); core::mem::size_of::<BdAddr>()];
+    const _: [(); 1] = [(); core::mem::align_of::<BdAddr>()];
+
+    #[test]
+    fn test_ffi() {
+        extern "C" fn copy(src: *const u8, dst: *mut u8) {
+            unsafe { core::ptr::copy_nonoverlapping(src, dst, 6) }
+        }
+
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        let mut out = [0; 6];
+        copy(addr.as_ptr(), out.as_mut_ptr());
+        assert_eq!([0x00, 0x11, 0x22, 0x33, 0x44, 0x55], out);
+        assert_eq!(&addr, BdAddr::from_ref(&out));
+        assert_eq!(addr, unsafe { BdAddr::from_ptr(out.as_ptr()) });
+
+        let buf = [0xff, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55];
+        assert_eq!(addr, unsafe { BdAddr::from_ptr(buf[1..].as_ptr()) });
+    }
+
     #[test]
     fn test_display() {
         let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
@@ -362,12 +1786,291 @@ mod tests {
         assert_eq!([0x00, 0x11, 0x22, 0x33, 0x44, 0x55], <[u8; 6]>::from(addr));
     }
 
+    #[test]
+    fn test_address_from_str() {
+        for (s, addr) in [
+            (
+                "bredr/00:1a:7d:da:71:13",
+                Address::bredr_from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]),
+            ),
+            (
+                "public/00:1a:7d:da:71:13",
+                Address::le_public_from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]),
+            ),
+            (
+                "random/53:03:8C:BC:BD:82",
+                Address::le_random_from([0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53]),
+            ),
+            (
+                "random/B5:44:33:22:11:00",
+                Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xb5]),
+            ),
+        ] {
+            assert_eq!(addr, s.parse().unwrap(), "{:?}", s);
+        }
+        assert!(matches!(
+            "random/B5:44:33:22:11:00".parse(),
+            Ok(Address::LeRandom(RandomDeviceAddress::Unknown(..)))
+        ));
+
+        for (s, message) in [
+            ("", "expect <address type>/<address>."),
+            ("00:1a:7d:da:71:13", "expect <address type>/<address>."),
+            ("le/00:1a:7d:da:71:13", "Unknown address type. (\"le\")"),
+            ("/00:1a:7d:da:71:13", "Unknown address type. (\"\")"),
+            ("le/garbage", "Unknown address type. (\"le\")"),
+            ("public/", "empty group 0 at offset 7"),
+            (
+                "public/00:1a:7d:da:71",
+                "wrong group count (expected 6, found 5) at offset 21",
+            ),
+            (
+                "random/00:1a:7d:da:71:13/x",
+                "invalid hex digit in group 5 at offset 24",
+            ),
+        ] {
+            let err = s.parse::<Address>().unwrap_err();
+            assert_eq!(message, err.to_string(), "{:?}", s);
+        }
+        assert!(matches!(
+            "public/00:1a:7d:da:71:1".parse::<Address>(),
+            Err(AddressFromStrError::AddressParseError(e)) if e.position() == 23
+        ));
+    }
+
+    #[test]
+    fn test_write_str_to() {
+        for b in [
+            [0x00; 6],
+            [0xff; 6],
+            [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00],
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0x01],
+            [0x01, 0x00, 0x00, 0x00, 0x00, 0x00],
+            [0x0f, 0xa0, 0xdd, 0xcc, 0x0b, 0x0a],
+        ] {
+            let addr = BdAddr::from(b);
+            let mut buf = [0xff; 17];
+            assert_eq!(addr.to_string(), addr.write_str_to(&mut buf));
+
+            let mut buf = [0xff; 20];
+            assert_eq!(addr.to_string(), addr.write_str_to_slice(&mut buf).unwrap());
+            assert_eq!([0xff; 3], buf[17..]);
+            assert_eq!(
+                addr.to_string(),
+                addr.write_str_to_slice(&mut [0; 17]).unwrap()
+            );
+        }
+
+        let addr = BdAddr::from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+        for n in [0, 1, 16] {
+            let err = addr.write_str_to_slice(&mut [0; 32][..n]).unwrap_err();
+            assert_eq!(
+                format!("Buffer too small. (expect: 17, but {})", n),
+                err.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_hex_fmt() {
+        let addr = BdAddr::from([0x0f, 0xa0, 0xdd, 0xcc, 0xbb, 0xaa]);
+        assert_eq!("aa:bb:cc:dd:a0:0f", format!("{}", addr));
+        assert_eq!("aa:bb:cc:dd:a0:0f", format!("{:x}", addr));
+        assert_eq!("AA:BB:CC:DD:A0:0F", format!("{:X}", addr));
+        assert_eq!("   aa:bb:cc:dd:a0:0f", format!("{:>20}", addr));
+        assert_eq!("aa:bb:cc:dd:a0:0f---", format!("{:-<20}", addr));
+        assert_eq!(" AA:BB:CC:DD:A0:0F  ", format!("{:^20X}", addr));
+        assert_eq!("aa:bb:cc:dd:a0:0f", format!("{:>10}", addr));
+
+        let b = [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5];
+        for addr in [
+            Address::bredr_from(b),
+            Address::le_public_from(b),
+            Address::le_random_from(b),
+        ] {
+            assert_eq!("f5:44:33:22:11:00", format!("{}", addr));
+            assert_eq!("F5:44:33:22:11:00", format!("{:X}", addr));
+            assert_eq!("f5:44:33:22:11:00  ", format!("{:19x}", addr));
+            assert_eq!("  f5:44:33:22:11:00", format!("{:>19}", addr));
+        }
+        let addr = StaticDeviceAddress::new(b).unwrap();
+        assert_eq!("F5:44:33:22:11:00", format!("{:X}", addr));
+        assert_eq!(
+            "F5:44:33:22:11:00",
+            format!("{:X}", RandomDeviceAddress::Static(addr))
+        );
+    }
+
     #[test]
     fn test_parse() {
         let addr = "55:44:33:22:11:00".parse().unwrap();
         assert_eq!(BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]), addr);
     }
 
+    #[test]
+    fn test_parse_syntax() {
+        // exactly two hex digits for each group
+        for (s, b) in [
+            ("55:44:33:22:11:00", [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            ("AA:bb:Cc:dD:00:0f", [0x0f, 0x00, 0xdd, 0xcc, 0xbb, 0xaa]),
+        ] {
+            assert_eq!(BdAddr::from(b), s.parse().unwrap(), "{:?}", s);
+        }
+        for s in [
+            "55:44:33:22:11",
+            "55:44:33:22:11:00:",
+            "55:44:33:22::00",
+            "100:44:33:22:11:00",
+            "55:44:33:22:11:100",
+            "5:4:3:2:1:0",
+            "AA:bb:Cc:dD:0:f",
+            "+55:44:33:22:11:00",
+            "55:+44:33:22:11:00",
+            "+1:+02:003:0004:00000005:ff",
+            "0055:44:33:22:11:00",
+            "55:44:33:22:11:0000",
+            "+:44:33:22:11:00",
+            "++5:44:33:22:11:00",
+            "-55:44:33:22:11:00",
+            " 55:44:33:22:11:00",
+            "55 :44:33:22:11:00",
+            "55:44:33:22:11:00 ",
+            "55:44:33:22:11:00\n",
+            "55-44-33-22-11-00",
+        ] {
+            assert!(s.parse::<BdAddr>().is_err(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_parse_roundtrip() {
+        for b in [
+            [0x00; 6],
+            [0xff; 6],
+            [0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+            [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00],
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06],
+            [0xf0, 0x0f, 0xa0, 0x0a, 0x10, 0x01],
+        ] {
+            let addr = BdAddr::from(b);
+            assert_eq!(addr, addr.to_string().parse().unwrap());
+        }
+        for s in [
+            "00:00:00:00:00:00",
+            "55:44:33:22:11:00",
+            "00:1a:7d:da:71:13",
+            "01:10:0a:a0:0f:f0",
+        ] {
+            assert_eq!(s, s.parse::<BdAddr>().unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        use AddressParseErrorKind::*;
+
+        for (s, kind, group, position, message) in [
+            ("", Empty, 0, 0, "empty group 0 at offset 0"),
+            (
+                "55:44:33:22::00",
+                Empty,
+                4,
+                12,
+                "empty group 4 at offset 12",
+            ),
+            (
+                "55:44:33:2Z:11:00",
+                InvalidDigit,
+                3,
+                10,
+                "invalid hex digit in group 3 at offset 10",
+            ),
+            (
+                "55-44-33-22-11-00",
+                InvalidDigit,
+                0,
+                2,
+                "invalid hex digit in group 0 at offset 2",
+            ),
+            (
+                "55:44:33:22:11:00 ",
+                InvalidDigit,
+                5,
+                17,
+                "invalid hex digit in group 5 at offset 17",
+            ),
+            (
+                "55:100:33:22:11:00",
+                GroupTooLong,
+                1,
+                5,
+                "group 1 too long at offset 5",
+            ),
+            (
+                "0055:44:33:22:11:00",
+                GroupTooLong,
+                0,
+                2,
+                "group 0 too long at offset 2",
+            ),
+            (
+                "5:4:3:2:1:0",
+                GroupTooShort,
+                0,
+                1,
+                "group 0 too short at offset 1",
+            ),
+            (
+                "55:44:33:22:11:0",
+                GroupTooShort,
+                5,
+                16,
+                "group 5 too short at offset 16",
+            ),
+            (
+                "+55:44:33:22:11:00",
+                InvalidDigit,
+                0,
+                0,
+                "invalid hex digit in group 0 at offset 0",
+            ),
+            (
+                "55:44:33:22:11",
+                WrongGroupCount {
+                    expected: 6,
+                    found: 5,
+                },
+                4,
+                14,
+                "wrong group count (expected 6, found 5) at offset 14",
+            ),
+            (
+                "55:44:33:22:11:00:ff:",
+                WrongGroupCount {
+                    expected: 6,
+                    found: 8,
+                },
+                5,
+                17,
+                "wrong group count (expected 6, found 8) at offset 17",
+            ),
+        ] {
+            let err = s.parse::<BdAddr>().unwrap_err();
+            assert_eq!(kind, err.kind(), "{:?}", s);
+            assert_eq!(group, err.group(), "{:?}", s);
+            assert_eq!(position, err.position(), "{:?}", s);
+            assert_eq!(message, err.to_string(), "{:?}", s);
+
+            for err2 in [
+                Address::bredr_from_str(s).unwrap_err(),
+                Address::le_public_from_str(s).unwrap_err(),
+                Address::le_random_from_str(s).unwrap_err(),
+            ] {
+                assert_eq!(err, err2, "{:?}", s);
+            }
+        }
+    }
+
     #[test]
     fn test_bredr_parse() {
         let addr = Address::bredr_from_str("55:44:33:22:11:00").unwrap();
@@ -375,8 +2078,14 @@ mod tests {
         assert_eq!("BrEdr(55:44:33:22:11:00)", &format!("{:?}", addr));
 
         let err = Address::bredr_from_str("ZZ:ZZ:ZZ:ZZ:ZZ:ZZ").unwrap_err();
-        assert_eq!("AddressParseError", &format!("{:?}", err));
-        assert_eq!("failed to parse address", &format!("{:}", err));
+        assert_eq!(
+            "AddressParseError { kind: InvalidDigit, group: 0, position: 0 }",
+            &format!("{:?}", err)
+        );
+        assert_eq!(
+            "invalid hex digit in group 0 at offset 0",
+            &format!("{:}", err)
+        );
     }
 
     #[test]
@@ -389,8 +2098,14 @@ mod tests {
         );
 
         let err = Address::le_public_from_str("ZZ:ZZ:ZZ:ZZ:ZZ:ZZ").unwrap_err();
-        assert_eq!("AddressParseError", &format!("{:?}", err));
-        assert_eq!("failed to parse address", &format!("{:}", err));
+        assert_eq!(
+            "AddressParseError { kind: InvalidDigit, group: 0, position: 0 }",
+            &format!("{:?}", err)
+        );
+        assert_eq!(
+            "invalid hex digit in group 0 at offset 0",
+            &format!("{:}", err)
+        );
     }
 
     #[test]
@@ -403,8 +2118,14 @@ mod tests {
         );
 
         let err = Address::le_random_from_str("ZZ:ZZ:ZZ:ZZ:ZZ:ZZ").unwrap_err();
-        assert_eq!("AddressParseError", &format!("{:?}", err));
-        assert_eq!("failed to parse address", &format!("{:}", err));
+        assert_eq!(
+            "AddressParseError { kind: InvalidDigit, group: 0, position: 0 }",
+            &format!("{:?}", err)
+        );
+        assert_eq!(
+            "invalid hex digit in group 0 at offset 0",
+            &format!("{:}", err)
+        );
     }
 
     #[test]
@@ -496,9 +2217,15 @@ mod tests {
 
         let result = NonResolvablePrivateAddress::try_from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55])
             .unwrap_err();
-        assert_eq!("InvalidBitsForAddressType(0, 1)", &format!("{:?}", result));
+        assert_eq!("NonResolvablePrivateAddress", result.type_name());
+        assert_eq!(
+            &BdAddr([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            result.address()
+        );
+        assert_eq!(0b00, result.expected_bits());
+        assert_eq!(0b01, result.actual_bits());
         assert_eq!(
-            "Invalid bits for this address type. (expect: 0b00, but 0b01)",
+            "Invalid bits for NonResolvablePrivateAddress. (address: 55:44:33:22:11:00, expect: 0b00, but 0b01)",
             &format!("{:}", result)
         );
     }
@@ -514,9 +2241,11 @@ mod tests {
 
         let result =
             ResolvablePrivateAddress::try_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xB5]).unwrap_err();
-        assert_eq!("InvalidBitsForAddressType(1, 2)", &format!("{:?}", result));
+        assert_eq!("ResolvablePrivateAddress", result.type_name());
+        assert_eq!(0b01, result.expected_bits());
+        assert_eq!(0b10, result.actual_bits());
         assert_eq!(
-            "Invalid bits for this address type. (expect: 0b01, but 0b10)",
+            "Invalid bits for ResolvablePrivateAddress. (address: b5:44:33:22:11:00, expect: 0b01, but 0b10)",
             &format!("{:}", result)
         );
     }
@@ -531,9 +2260,11 @@ mod tests {
 
         let result =
             StaticDeviceAddress::try_from([0x00, 0x11, 0x22, 0x33, 0x44, 0x05]).unwrap_err();
-        assert_eq!("InvalidBitsForAddressType(3, 0)", &format!("{:?}", result));
+        assert_eq!("StaticDeviceAddress", result.type_name());
+        assert_eq!(0b11, result.expected_bits());
+        assert_eq!(0b00, result.actual_bits());
         assert_eq!(
-            "Invalid bits for this address type. (expect: 0b11, but 0b00)",
+            "Invalid bits for StaticDeviceAddress. (address: 05:44:33:22:11:00, expect: 0b11, but 0b00)",
             &format!("{:}", result)
         );
     }
@@ -638,4 +2369,606 @@ mod tests {
         assert_eq!("LeRandom", format!("{:?}", ty));
         assert_eq!(AddressType::LeRandom, ty);
     }
+
+    #[test]
+    fn test_address_type_variants() {
+        assert_eq!(
+            vec![
+                AddressType::BrEdr,
+                AddressType::LePublic,
+                AddressType::LeRandom
+            ],
+            AddressType::iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["bredr", "public", "random"],
+            AddressType::iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+        );
+        for ty in AddressType::iter() {
+            assert_eq!(ty, ty.as_ref().parse().unwrap());
+        }
+        let err = "BrEdr".parse::<AddressType>().unwrap_err();
+        assert_eq!("Unknown address type. (\"BrEdr\")", err.to_string());
+    }
+
+    #[test]
+    fn test_random_device_address_kind_variants() {
+        assert_eq!(
+            vec![
+                RandomDeviceAddressKind::NonResolvable,
+                RandomDeviceAddressKind::Resolvable,
+                RandomDeviceAddressKind::Static,
+                RandomDeviceAddressKind::Unknown,
+            ],
+            RandomDeviceAddressKind::iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["non-resolvable", "resolvable", "static", "unknown"],
+            RandomDeviceAddressKind::iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+        );
+        for kind in RandomDeviceAddressKind::iter() {
+            assert_eq!(kind, kind.as_ref().parse().unwrap());
+        }
+        let err = "non_resolvable"
+            .parse::<RandomDeviceAddressKind>()
+            .unwrap_err();
+        assert_eq!(
+            "Unknown random device address kind. (\"non_resolvable\")",
+            err.to_string()
+        );
+
+        let kind = RandomDeviceAddress::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x75]).kind();
+        assert_eq!(RandomDeviceAddressKind::Resolvable, kind);
+    }
+
+    #[test]
+    fn test_tagged_bytes() {
+        let b = [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5];
+        for (ty, addr) in [
+            (0x00, Address::bredr_from(b)),
+            (0x01, Address::le_public_from(b)),
+            (0x02, Address::le_random_from(b)),
+        ] {
+            let tagged = addr.to_tagged_bytes();
+            assert_eq!([ty, 0x00, 0x11, 0x22, 0x33, 0x44, 0xf5], tagged);
+            assert_eq!(addr, Address::from_tagged_bytes(tagged).unwrap());
+        }
+        assert!(matches!(
+            Address::from_tagged_bytes([0x02, 0x00, 0x11, 0x22, 0x33, 0x44, 0xb5]),
+            Ok(Address::LeRandom(RandomDeviceAddress::Unknown(..)))
+        ));
+        let err = Address::from_tagged_bytes([0x03, 0, 0, 0, 0, 0, 0]).unwrap_err();
+        assert_eq!("Unknown address type. (0x03)", err.to_string());
+    }
+
+    #[test]
+    fn test_address_parts() {
+        for addr in [
+            Address::bredr_from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            Address::le_public_from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0x35]),
+            Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0x75]),
+            Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xF5]),
+            Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xB5]),
+        ] {
+            let (ty, bd_addr) = addr.into_parts();
+            assert_eq!(addr.address_type(), ty);
+            assert_eq!(addr.into_bd_addr(), bd_addr);
+            assert_eq!(addr, bd_addr.to_addr(ty.clone()));
+            assert_eq!(addr, Address::from_parts(ty, bd_addr));
+
+            let (kind, bd_addr) = addr.into_kind_parts();
+            assert_eq!(addr.address_type(), AddressType::from(kind.clone()));
+            assert_eq!(addr, Address::from_kind_parts(kind, bd_addr).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_random_device_address_parts() {
+        for b in [
+            [0x00, 0x11, 0x22, 0x33, 0x44, 0x35],
+            [0x00, 0x11, 0x22, 0x33, 0x44, 0x75],
+            [0x00, 0x11, 0x22, 0x33, 0x44, 0xF5],
+            [0x00, 0x11, 0x22, 0x33, 0x44, 0xB5],
+        ] {
+            let addr = RandomDeviceAddress::from(b);
+            let (kind, bd_addr) = addr.into_parts();
+            assert_eq!(addr.kind(), kind);
+            assert_eq!(BdAddr(b), bd_addr);
+            assert_eq!(
+                addr,
+                RandomDeviceAddress::from_parts(kind, bd_addr).unwrap()
+            );
+        }
+
+        // lie about the kind
+        let bd_addr = BdAddr([0x00, 0x11, 0x22, 0x33, 0x44, 0xF5]);
+        let addr = bd_addr.to_addr(AddressType::LeRandom);
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Static(..))
+        ));
+        let kind = AddressKind::LeRandom(RandomDeviceAddressKind::Resolvable);
+        let err = Address::from_kind_parts(kind, bd_addr).unwrap_err();
+        assert_eq!("ResolvablePrivateAddress", err.type_name());
+        assert_eq!(0b11, err.actual_bits());
+        let kind = AddressKind::LeRandom(RandomDeviceAddressKind::Static);
+        assert_eq!(addr, Address::from_kind_parts(kind, bd_addr).unwrap());
+        let err = RandomDeviceAddress::from_parts(RandomDeviceAddressKind::Resolvable, bd_addr)
+            .unwrap_err();
+        assert_eq!("ResolvablePrivateAddress", err.type_name());
+        assert_eq!(0b11, err.actual_bits());
+        let err =
+            RandomDeviceAddress::from_parts(RandomDeviceAddressKind::Unknown, bd_addr).unwrap_err();
+        assert_eq!("RandomDeviceAddress", err.type_name());
+        assert_eq!(0b10, err.expected_bits());
+    }
+
+    #[test]
+    fn test_bytes_order() {
+        let addr = BdAddr::from_be_bytes([0x55, 0x44, 0x33, 0x22, 0x11, 0x00]);
+        assert_eq!("55:44:33:22:11:00", addr.to_string());
+        assert_eq!([0x55, 0x44, 0x33, 0x22, 0x11, 0x00], addr.to_be_bytes());
+        assert_eq!([0x00, 0x11, 0x22, 0x33, 0x44, 0x55], addr.to_le_bytes());
+        assert_eq!(
+            addr,
+            BdAddr::from_le_bytes([0x00, 0x11, 0x22, 0x33, 0x44, 0x55])
+        );
+        assert_eq!(addr, BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]));
+        assert_eq!(addr.to_le_bytes(), <[u8; 6]>::from(addr));
+
+        for b in [
+            [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00],
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0x01],
+            [0xff, 0xfe, 0xfd, 0xfc, 0xfb, 0xfa],
+        ] {
+            assert_eq!(b, BdAddr::from_le_bytes(b).to_le_bytes());
+            assert_eq!(b, BdAddr::from_be_bytes(b).to_be_bytes());
+            let mut r = b;
+            r.reverse();
+            assert_eq!(BdAddr::from_le_bytes(b), BdAddr::from_be_bytes(r));
+        }
+    }
+
+    #[test]
+    fn test_slice() {
+        let b = [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00, 0xff];
+        let addr = BdAddr::try_from(&b[..6]).unwrap();
+        assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+        for n in [0, 5, 7] {
+            let err = BdAddr::try_from(&b[..n]).unwrap_err();
+            assert_eq!(
+                format!("Invalid address length. (expect: 6, but {})", n),
+                err.to_string()
+            );
+        }
+
+        let mut buf = [0xee; 6];
+        addr.write_to(&mut buf).unwrap();
+        assert_eq!(b[..6], buf);
+        let mut buf = [0xee; 8];
+        addr.write_to(&mut buf).unwrap();
+        assert_eq!([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00, 0xee, 0xee], buf);
+        for n in [0, 5] {
+            let mut buf = [0xee; 8];
+            let err = addr.write_to(&mut buf[..n]).unwrap_err();
+            assert_eq!(
+                format!("Buffer too small. (expect: 6, but {})", n),
+                err.to_string()
+            );
+            assert_eq!([0xee; 8], buf);
+        }
+    }
+
+    #[test]
+    fn test_slice_typed() {
+        let b = [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5];
+        assert_eq!(
+            PublicDeviceAddress::new(b),
+            PublicDeviceAddress::try_from(&b[..]).unwrap()
+        );
+        assert!(PublicDeviceAddress::try_from(&b[..5]).is_err());
+        assert_eq!(
+            StaticDeviceAddress::new(b).unwrap(),
+            StaticDeviceAddress::try_from(&b[..]).unwrap()
+        );
+        assert!(matches!(
+            RandomDeviceAddress::try_from(&b[..]).unwrap(),
+            RandomDeviceAddress::Static(..)
+        ));
+        assert!(RandomDeviceAddress::try_from(&[0; 7][..]).is_err());
+
+        let b = [0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53];
+        assert_eq!(
+            ResolvablePrivateAddress::new(b).unwrap(),
+            ResolvablePrivateAddress::try_from(&b[..]).unwrap()
+        );
+        let err = StaticDeviceAddress::try_from(&b[..]).unwrap_err();
+        assert!(matches!(err, AddressFromSliceError::InvalidBits(..)));
+        assert_eq!(
+            "Invalid bits for StaticDeviceAddress. (address: 53:03:8c:bc:bd:82, expect: 0b11, but 0b01)",
+            err.to_string()
+        );
+        let err = NonResolvablePrivateAddress::try_from(&b[..4]).unwrap_err();
+        assert!(matches!(err, AddressFromSliceError::InvalidLength(..)));
+        assert_eq!(
+            "Invalid address length. (expect: 6, but 4)",
+            err.to_string()
+        );
+        let err =
+            ResolvablePrivateAddress::try_from(&[0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53, 0x00][..])
+                .unwrap_err();
+        assert!(matches!(err, AddressFromSliceError::InvalidLength(..)));
+    }
+
+    #[test]
+    fn test_oui() {
+        for (s, oui, lower) in [
+            ("00:1c:b3:33:22:11", [0x00, 0x1c, 0xb3], [0x33, 0x22, 0x11]), // Apple
+            ("b8:27:eb:12:34:56", [0xb8, 0x27, 0xeb], [0x12, 0x34, 0x56]), // Raspberry Pi Foundation
+            ("24:0a:c4:00:00:01", [0x24, 0x0a, 0xc4], [0x00, 0x00, 0x01]), // Espressif
+            ("a4:c1:38:ff:ee:dd", [0xa4, 0xc1, 0x38], [0xff, 0xee, 0xdd]), // Telink Semiconductor
+        ] {
+            let addr = s.parse::<BdAddr>().unwrap();
+            assert_eq!(oui, addr.oui(), "{}", s);
+            assert_eq!(lower, addr.lower_half(), "{}", s);
+            assert!(!addr.is_locally_administered(), "{}", s);
+            assert!(!addr.is_group_address(), "{}", s);
+        }
+
+        // the bits are in the last stored octet
+        let addr = BdAddr::from([0x03, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert!(!addr.is_locally_administered());
+        assert!(!addr.is_group_address());
+        for (b5, local, group) in [
+            (0x02, true, false),
+            (0x01, false, true),
+            (0x03, true, true),
+            (0xfc, false, false),
+        ] {
+            let addr = BdAddr::from([0x00, 0x00, 0x00, 0x00, 0x00, b5]);
+            assert_eq!(local, addr.is_locally_administered(), "{}", addr);
+            assert_eq!(group, addr.is_group_address(), "{}", addr);
+        }
+        assert!("01:00:5e:00:00:fb"
+            .parse::<BdAddr>()
+            .unwrap()
+            .is_group_address());
+    }
+
+    #[test]
+    fn test_offset_from() {
+        let a = BdAddr::try_from("00:11:22:33:44:ff").unwrap();
+        let b = BdAddr::try_from("00:11:22:33:45:00").unwrap();
+        assert_eq!(1, b.offset_from(&a));
+        assert_eq!(-1, a.offset_from(&b));
+        assert_eq!(0, a.offset_from(&a));
+        assert!(a.is_within(&b, 1));
+        assert!(b.is_within(&a, 1));
+        assert!(!a.is_within(&b, 0));
+
+        let a = BdAddr::try_from("00:11:22:ff:ff:ff").unwrap();
+        let b = BdAddr::try_from("00:11:23:00:00:01").unwrap();
+        assert_eq!(2, b.offset_from(&a));
+        assert!(!b.is_within(&a, 1));
+        assert!(b.is_within(&a, 2));
+
+        let a = BdAddr::try_from("00:00:00:00:00:00").unwrap();
+        let b = BdAddr::try_from("ff:ff:ff:ff:ff:ff").unwrap();
+        assert_eq!(0xffff_ffff_ffff, b.offset_from(&a));
+        assert_eq!(-0xffff_ffff_ffff, a.offset_from(&b));
+        assert!(!a.is_within(&b, 255));
+    }
+
+    #[test]
+    fn test_maybe_same_dual_mode_device() {
+        let bredr = Address::bredr_from_str("00:1a:7d:da:71:ff").unwrap();
+        let le = Address::le_public_from_str("00:1a:7d:da:72:00").unwrap();
+        assert!(bredr.maybe_same_dual_mode_device(&le, 1));
+        assert!(le.maybe_same_dual_mode_device(&bredr, 1));
+        assert!(!bredr.maybe_same_dual_mode_device(&le, 0));
+        assert!(bredr.maybe_same_dual_mode_device(&bredr, 0));
+
+        let random = Address::le_random_from_str("00:1a:7d:da:72:00").unwrap();
+        assert!(!bredr.maybe_same_dual_mode_device(&random, 1));
+        assert!(!random.maybe_same_dual_mode_device(&random, 1));
+    }
+
+    #[test]
+    fn test_from_random_part() {
+        for (bits, s, n) in [
+            (0x1, "c0:00:00:00:00:01", "00:00:00:00:00:01"),
+            (0x2000_0000_0000, "e0:00:00:00:00:00", "20:00:00:00:00:00"),
+            (0x3fff_ffff_fffe, "ff:ff:ff:ff:ff:fe", "3f:ff:ff:ff:ff:fe"),
+            (0x0544_3322_1100, "c5:44:33:22:11:00", "05:44:33:22:11:00"),
+        ] {
+            let addr = StaticDeviceAddress::from_random_part(bits).unwrap();
+            assert_eq!(s, addr.to_string());
+            assert_eq!(bits, addr.random_part());
+            let addr = NonResolvablePrivateAddress::from_random_part(bits).unwrap();
+            assert_eq!(n, addr.to_string());
+            assert_eq!(bits, addr.random_part());
+            assert!(matches!(
+                RandomDeviceAddress::from(<[u8; 6]>::from(addr.0)),
+                RandomDeviceAddress::NonResolvable(..)
+            ));
+        }
+
+        for bits in [0, (1 << 46) - 1, 1 << 46, (1 << 46) | 1, u64::MAX] {
+            assert!(StaticDeviceAddress::from_random_part(bits).is_err());
+            assert!(NonResolvablePrivateAddress::from_random_part(bits).is_err());
+        }
+        let err = StaticDeviceAddress::from_random_part(1 << 46).unwrap_err();
+        assert_eq!(
+            "Invalid random part. (expect: 46 bits neither all zeros nor all ones, but 0x400000000000)",
+            err.to_string()
+        );
+
+        let addr = StaticDeviceAddress::try_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]).unwrap();
+        assert_eq!(0x3544_3322_1100, addr.random_part());
+    }
+
+    #[test]
+    fn test_random_part() {
+        // tag bits are masked off, the top bit of random part is next to them
+        for (b, bits) in [
+            ([0x00, 0x00, 0x00, 0x00, 0x00, 0xe0], 0x2000_0000_0000),
+            ([0x00, 0x00, 0x00, 0x00, 0x00, 0xdf], 0x1f00_0000_0000),
+            ([0x01, 0x00, 0x00, 0x00, 0x00, 0xc0], 0x0000_0000_0001),
+            ([0x00, 0x01, 0x00, 0x00, 0x00, 0xc0], 0x0000_0000_0100),
+            ([0xfe, 0xff, 0xff, 0xff, 0xff, 0xff], 0x3fff_ffff_fffe),
+        ] {
+            let addr = StaticDeviceAddress::try_from(b).unwrap();
+            assert_eq!(bits, addr.random_part(), "{}", addr);
+        }
+        for (b, bits) in [
+            ([0x00, 0x00, 0x00, 0x00, 0x00, 0x20], 0x2000_0000_0000),
+            ([0x00, 0x00, 0x00, 0x00, 0x00, 0x1f], 0x1f00_0000_0000),
+            ([0x00, 0x00, 0x00, 0x00, 0x80, 0x00], 0x0080_0000_0000),
+        ] {
+            let addr = NonResolvablePrivateAddress::try_from(b).unwrap();
+            assert_eq!(bits, addr.random_part(), "{}", addr);
+        }
+
+        let addr =
+            ResolvablePrivateAddress::try_from([0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53]).unwrap();
+        assert_eq!(0x53_038c, addr.prand());
+        assert_eq!(0xbc_bd82, addr.hash());
+        assert_eq!(ResolvablePrivateAddress::TAG as u32, addr.prand() >> 22);
+    }
+
+    #[test]
+    fn test_ord() {
+        use std::collections::BTreeSet;
+
+        let mut v = [
+            "00:00:00:00:01:00",
+            "00:00:00:00:00:ff",
+            "01:00:00:00:00:00",
+        ]
+        .iter()
+        .map(|s| s.parse::<BdAddr>().unwrap())
+        .collect::<Vec<_>>();
+        v.sort();
+        assert_eq!(
+            vec![
+                "00:00:00:00:00:ff",
+                "00:00:00:00:01:00",
+                "01:00:00:00:00:00"
+            ],
+            v.iter().map(|v| v.to_string()).collect::<Vec<_>>()
+        );
+
+        let mut v = [
+            PublicDeviceAddress::from([0x00, 0x01, 0, 0, 0, 0]),
+            PublicDeviceAddress::from([0xff, 0x00, 0, 0, 0, 0]),
+        ];
+        v.sort();
+        assert_eq!("00:00:00:00:00:ff", v[0].to_string());
+
+        let set = [0xff, 0xc0, 0xc1, 0xc0]
+            .iter()
+            .map(|b| StaticDeviceAddress::try_from([0x00, 0x00, 0x00, 0x00, 0x00, *b]).unwrap())
+            .collect::<BTreeSet<_>>();
+        assert_eq!(
+            vec![
+                "c0:00:00:00:00:00",
+                "c1:00:00:00:00:00",
+                "ff:00:00:00:00:00"
+            ],
+            set.iter().map(|v| v.to_string()).collect::<Vec<_>>()
+        );
+
+        let mut v = [
+            NonResolvablePrivateAddress::from_random_part(0x100).unwrap(),
+            NonResolvablePrivateAddress::from_random_part(0xff).unwrap(),
+        ];
+        v.sort();
+        assert_eq!(0xff, v[0].random_part());
+
+        let mut v = [
+            ResolvablePrivateAddress::try_from([0x00, 0x00, 0x00, 0x00, 0x00, 0x41]).unwrap(),
+            ResolvablePrivateAddress::try_from([0xff, 0x00, 0x00, 0x00, 0x00, 0x40]).unwrap(),
+        ];
+        v.sort();
+        assert_eq!("40:00:00:00:00:ff", v[0].to_string());
+
+        // variant order, then by address
+        let mut v = [
+            [0x01, 0x00, 0x00, 0x00, 0x00, 0xc0],
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0x80],
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0xc0],
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0x40],
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0x3f],
+        ]
+        .iter()
+        .map(|b| RandomDeviceAddress::from(*b))
+        .collect::<Vec<_>>();
+        v.sort();
+        assert_eq!(
+            vec![
+                "3f:00:00:00:00:00",
+                "40:00:00:00:00:00",
+                "c0:00:00:00:00:00",
+                "c0:00:00:00:00:01",
+                "80:00:00:00:00:00",
+            ],
+            v.iter().map(|v| v.to_string()).collect::<Vec<_>>()
+        );
+
+        // consistent with inner `BdAddr` within the same variant
+        let b = [
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0xc0],
+            [0xff, 0x00, 0x00, 0x00, 0x00, 0xc0],
+            [0x00, 0x01, 0x00, 0x00, 0x00, 0xc0],
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0xc1],
+            [0x01, 0x00, 0x00, 0x00, 0x00, 0xff],
+        ];
+        for x in b {
+            for y in b {
+                let expect = BdAddr::from(x).cmp(&BdAddr::from(y));
+                let (sx, sy) = (
+                    StaticDeviceAddress::try_from(x).unwrap(),
+                    StaticDeviceAddress::try_from(y).unwrap(),
+                );
+                assert_eq!(expect, sx.cmp(&sy));
+                assert_eq!(BdAddr::from(x) < BdAddr::from(y), sx < sy);
+                assert_eq!(
+                    expect,
+                    RandomDeviceAddress::from(x).cmp(&RandomDeviceAddress::from(y))
+                );
+                assert_eq!(
+                    expect,
+                    PublicDeviceAddress::from(x).cmp(&PublicDeviceAddress::from(y))
+                );
+            }
+        }
+    }
+
+    const BD_ADDR: BdAddr = BdAddr::new([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+
+    static TABLE: [Address; 3] = [
+        Address::bredr_from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]),
+        Address::le_public_from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]),
+        Address::le_random_from([0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53]),
+    ];
+
+    static STATICS: [StaticDeviceAddress; 2] = [
+        StaticDeviceAddress::new_unchecked([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]),
+        StaticDeviceAddress::new_unchecked([0x00, 0x11, 0x22, 0x33, 0x44, 0xc0]),
+    ];
+
+    #[test]
+    fn test_const() {
+        assert_eq!("00:1a:7d:da:71:13", BD_ADDR.to_string());
+        let copied = TABLE;
+        assert_eq!(BD_ADDR.to_br_edr_addr(), copied[0]);
+        assert_eq!(BD_ADDR.to_le_public_addr(), copied[1]);
+        assert!(matches!(
+            copied[2],
+            Address::LeRandom(RandomDeviceAddress::Resolvable(..))
+        ));
+        assert_eq!(TABLE, copied);
+
+        assert_eq!(
+            STATICS[0],
+            StaticDeviceAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]).unwrap()
+        );
+        assert_eq!("c0:44:33:22:11:00", STATICS[1].to_string());
+        assert_eq!(
+            PublicDeviceAddress::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            PublicDeviceAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55])
+        );
+        assert!(NonResolvablePrivateAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x35]).is_ok());
+        let err = ResolvablePrivateAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]).unwrap_err();
+        assert_eq!(
+            "Invalid bits for ResolvablePrivateAddress. (address: f5:44:33:22:11:00, expect: 0b01, but 0b11)",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_retag() {
+        let b = [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00];
+        let bredr = Address::bredr_from(b);
+        let le = Address::le_public_from(b);
+        assert_eq!(Some(le), bredr.as_le_public());
+        assert_eq!(Some(bredr), le.as_bredr());
+        assert_eq!(Some(bredr), bredr.as_bredr());
+        assert_eq!(Some(le), le.as_le_public());
+
+        for s in [
+            "35:44:33:22:11:00",
+            "53:03:8c:bc:bd:82",
+            "f5:44:33:22:11:00",
+            "b5:44:33:22:11:00",
+        ] {
+            let random = Address::le_random_from_str(s).unwrap();
+            assert_eq!(None, random.as_bredr(), "{}", s);
+            assert_eq!(None, random.as_le_public(), "{}", s);
+        }
+
+        // escape hatch tags regardless of the source
+        let rpa = BdAddr::from([0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53]);
+        assert_eq!(AddressType::BrEdr, rpa.to_br_edr_addr().address_type());
+        assert_eq!(
+            AddressType::LePublic,
+            rpa.to_le_public_addr().address_type()
+        );
+    }
+
+    #[test]
+    fn test_parse_adversarial() {
+        use AddressParseErrorKind::*;
+
+        let long = "00:".repeat(10_000);
+        let too_many = |found| WrongGroupCount { expected: 6, found };
+        for (s, kind, group, position) in [
+            ("", Empty, 0, 0),
+            (":", Empty, 0, 0),
+            (":::::", Empty, 0, 0),
+            ("00:11:22:33:44:", Empty, 5, 15),
+            ("00:11:22:33:44:55:", too_many(7), 5, 17),
+            ("00:11:22:33:44:55:66", too_many(7), 5, 17),
+            ("-0:11:22:33:44:55", InvalidDigit, 0, 0),
+            ("0x:11:22:33:44:55", InvalidDigit, 0, 1),
+            ("00:11:22:33:44:\u{ff15}\u{ff15}", InvalidDigit, 5, 15),
+            ("00:11:22:33:44:5\u{e9}", InvalidDigit, 5, 16),
+            ("\u{0}", InvalidDigit, 0, 0),
+            (long.as_str(), too_many(10_001), 5, 17),
+        ] {
+            for err in [
+                s.parse::<BdAddr>().unwrap_err(),
+                Address::le_random_from_str(s).unwrap_err(),
+            ] {
+                assert_eq!(kind, err.kind(), "{:?}", s);
+                assert_eq!(group, err.group(), "{:?}", s);
+                assert_eq!(position, err.position(), "{:?}", s);
+            }
+            assert!(s.parse::<AddressType>().is_err(), "{:?}", s);
+            assert!(s.parse::<RandomDeviceAddressKind>().is_err(), "{:?}", s);
+        }
+
+        for (b, expected) in [
+            ([0x00; 6], RandomDeviceAddressKind::NonResolvable),
+            ([0xFF; 6], RandomDeviceAddressKind::Static),
+        ] {
+            let addr = BdAddr::from(b);
+            assert_eq!(expected, RandomDeviceAddress::new(addr).kind());
+            assert_eq!(0, addr.offset_from(&addr));
+            assert!(addr.is_within(&addr, 0));
+            for kind in RandomDeviceAddressKind::iter() {
+                let result = RandomDeviceAddress::from_parts(kind.clone(), addr);
+                assert_eq!(kind == expected, result.is_ok(), "{:?}", kind);
+            }
+        }
+        let (min, max) = (BdAddr::from([0x00; 6]), BdAddr::from([0xFF; 6]));
+        assert_eq!(0xFFFF_FFFF_FFFF, max.offset_from(&min));
+        assert_eq!(-0xFFFF_FFFF_FFFF, min.offset_from(&max));
+        assert!(!min.is_within(&max, 0xFF));
+    }
 }
@@ -0,0 +1,230 @@
+//! Advertising Data structures bearing addresses.
+//!
+//! ```
+//! use bdaddr::{ad, Address};
+//!
+//! let targets = [
+//!     Address::le_public_from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]),
+//!     Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]),
+//! ];
+//! let data = ad::encode_target_addresses(&targets).unwrap();
+//! assert_eq!(&[0x07, ad::PUBLIC_TARGET_ADDRESS], &data[..2]);
+//! assert_eq!(&[0x07, ad::RANDOM_TARGET_ADDRESS], &data[8..10]);
+//! ```
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+
+#[cfg(feature = "alloc")]
+use super::{Address, AddressType, UnsupportedAddressType};
+
+/// AD type of Public Target Address.
+pub const PUBLIC_TARGET_ADDRESS: u8 = 0x17;
+
+/// AD type of Random Target Address.
+pub const RANDOM_TARGET_ADDRESS: u8 = 0x18;
+
+/// Addresses in one AD structure. (`Length` up to 255)
+#[cfg(feature = "alloc")]
+const MAX_ADDRESSES: usize = 42;
+
+/// Error for [`parse_target_address_ad`].
+#[derive(Debug)]
+pub enum ParseError {
+    /// Not a Public / Random Target Address.
+    UnexpectedAdType(u8),
+
+    /// Length is not a multiple of 6.
+    InvalidLength(usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedAdType(ty) => write!(f, "Unexpected AD type. (0x{:02x})", ty),
+            Self::InvalidLength(len) => write!(
+                f,
+                "Invalid data length. (expect: multiple of 6, but {})",
+                len
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Encode Public Target Address and Random Target Address AD structures. (`Length`, `AD Type` and data)
+///
+/// Public addresses come first, followed by random addresses, each in the given order.
+/// Empty kinds are omitted, and more than 42 addresses of a kind are split into multiple structures.
+/// Rejects BR/EDR Address.
+///
+/// ref Core Specification Supplement | Part A | 1.13 PUBLIC TARGET ADDRESS
+///
+/// ref Core Specification Supplement | Part A | 1.14 RANDOM TARGET ADDRESS
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode_target_addresses(targets: &[Address]) -> Result<Vec<u8>, UnsupportedAddressType> {
+    let mut public = vec![];
+    let mut random = vec![];
+    for target in targets {
+        let b = <[u8; 6]>::from(target.into_bd_addr());
+        match target.address_type() {
+            AddressType::LePublic => public.push(b),
+            AddressType::LeRandom => random.push(b),
+            ty @ AddressType::BrEdr => return Err(UnsupportedAddressType(ty)),
+        }
+    }
+
+    let mut data = vec![];
+    for (ad_type, addrs) in [
+        (PUBLIC_TARGET_ADDRESS, public),
+        (RANDOM_TARGET_ADDRESS, random),
+    ] {
+        for chunk in addrs.chunks(MAX_ADDRESSES) {
+            data.push((1 + chunk.len() * 6) as u8);
+            data.push(ad_type);
+            for b in chunk {
+                data.extend_from_slice(b);
+            }
+        }
+    }
+    Ok(data)
+}
+
+/// Parse data of Public Target Address or Random Target Address AD structure. (without `Length` and `AD Type`)
+///
+/// Random addresses are classified by most significant two bits.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn parse_target_address_ad(ad_type: u8, data: &[u8]) -> Result<Vec<Address>, ParseError> {
+    let from: fn([u8; 6]) -> Address = match ad_type {
+        PUBLIC_TARGET_ADDRESS => Address::le_public_from,
+        RANDOM_TARGET_ADDRESS => Address::le_random_from,
+        x => return Err(ParseError::UnexpectedAdType(x)),
+    };
+    if data.len() % 6 != 0 {
+        return Err(ParseError::InvalidLength(data.len()));
+    }
+    data.chunks(6)
+        .map(|b| {
+            <[u8; 6]>::try_from(b)
+                .map(from)
+                .map_err(|_| ParseError::InvalidLength(data.len()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::addr::RandomDeviceAddress;
+
+    #[test]
+    fn test_encode_target_addresses() {
+        let targets = [
+            Address::le_random_from_str("F5:44:33:22:11:00").unwrap(),
+            Address::le_public_from_str("00:1A:7D:DA:71:13").unwrap(),
+            Address::le_random_from_str("53:03:8C:BC:BD:82").unwrap(),
+            Address::le_public_from_str("00:11:22:33:44:55").unwrap(),
+        ];
+        assert_eq!(
+            vec![
+                0x0d, 0x17, //
+                0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00, //
+                0x55, 0x44, 0x33, 0x22, 0x11, 0x00, //
+                0x0d, 0x18, //
+                0x00, 0x11, 0x22, 0x33, 0x44, 0xf5, //
+                0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53, //
+            ],
+            encode_target_addresses(&targets).unwrap()
+        );
+
+        let targets = [Address::le_random_from_str("F5:44:33:22:11:00").unwrap()];
+        assert_eq!(
+            vec![0x07, 0x18, 0x00, 0x11, 0x22, 0x33, 0x44, 0xf5],
+            encode_target_addresses(&targets).unwrap()
+        );
+
+        let targets = vec![Address::le_public_from([0; 6]); 43];
+        let data = encode_target_addresses(&targets).unwrap();
+        assert_eq!(2 + 42 * 6 + 2 + 6, data.len());
+        assert_eq!([0xfd, 0x17], data[..2]);
+        assert_eq!([0x07, 0x17], data[254..256]);
+
+        assert!(encode_target_addresses(&[]).unwrap().is_empty());
+
+        let targets = [Address::bredr_from_str("00:1A:7D:DA:71:13").unwrap()];
+        let err = encode_target_addresses(&targets).unwrap_err();
+        assert_eq!("Unsupported address type. (BrEdr)", &err.to_string());
+    }
+
+    #[test]
+    fn test_parse_target_address_ad() {
+        let data = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0xf5, //
+            0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53, //
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x35, //
+        ];
+        let addrs = parse_target_address_ad(RANDOM_TARGET_ADDRESS, &data).unwrap();
+        assert!(matches!(
+            &addrs[..],
+            [
+                Address::LeRandom(RandomDeviceAddress::Static(..)),
+                Address::LeRandom(RandomDeviceAddress::Resolvable(..)),
+                Address::LeRandom(RandomDeviceAddress::NonResolvable(..)),
+            ]
+        ));
+        assert_eq!(
+            Address::le_random_from_str("53:03:8C:BC:BD:82").unwrap(),
+            addrs[1]
+        );
+
+        let addrs = parse_target_address_ad(PUBLIC_TARGET_ADDRESS, &data[..12]).unwrap();
+        assert_eq!(
+            vec![
+                Address::le_public_from_str("F5:44:33:22:11:00").unwrap(),
+                Address::le_public_from_str("53:03:8C:BC:BD:82").unwrap(),
+            ],
+            addrs
+        );
+
+        let err = parse_target_address_ad(PUBLIC_TARGET_ADDRESS, &data[..14]).unwrap_err();
+        assert_eq!(
+            "Invalid data length. (expect: multiple of 6, but 14)",
+            &err.to_string()
+        );
+
+        let err = parse_target_address_ad(0x09, &data).unwrap_err();
+        assert_eq!("Unexpected AD type. (0x09)", &err.to_string());
+    }
+
+    #[test]
+    fn test_parse_target_address_ad_adversarial() {
+        let data = [0xFF; 64];
+        for ad_type in 0..=0xFF {
+            for len in 0..=data.len() {
+                let result = parse_target_address_ad(ad_type, &data[..len]);
+                match (ad_type, result) {
+                    (PUBLIC_TARGET_ADDRESS | RANDOM_TARGET_ADDRESS, Ok(addrs)) => {
+                        assert_eq!(0, len % 6);
+                        assert_eq!(len / 6, addrs.len());
+                    }
+                    (PUBLIC_TARGET_ADDRESS | RANDOM_TARGET_ADDRESS, Err(err)) => {
+                        assert_ne!(0, len % 6);
+                        assert!(matches!(err, ParseError::InvalidLength(n) if n == len));
+                    }
+                    (_, result) => assert!(matches!(
+                        result,
+                        Err(ParseError::UnexpectedAdType(ty)) if ty == ad_type
+                    )),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! `advmac::MacAddr6` holds the octets in display order. (see [byte order](BdAddr#byte-order) of [`BdAddr`])
+//!
+//! `no_std` builds are limited to targets where `c_char` is `i8` (e.g. `x86_64-unknown-none`),
+//! as advmac 1.x has conflicting `TryFrom` impls where it is `u8` (e.g. `thumbv7em-none-eabihf`).
+
+use advmac::MacAddr6;
+
+use super::BdAddr;
+
+const fn reverse(b: [u8; 6]) -> [u8; 6] {
+    [b[5], b[4], b[3], b[2], b[1], b[0]]
+}
+
+impl BdAddr {
+    /// Construct from `advmac::MacAddr6`.
+    pub const fn from_advmac(v: MacAddr6) -> Self {
+        Self(reverse(v.to_array()))
+    }
+
+    /// Convert to `advmac::MacAddr6`.
+    pub const fn to_advmac(&self) -> MacAddr6 {
+        MacAddr6::new(reverse(self.0))
+    }
+}
+
+impl From<BdAddr> for MacAddr6 {
+    fn from(v: BdAddr) -> Self {
+        v.to_advmac()
+    }
+}
+
+impl From<MacAddr6> for BdAddr {
+    fn from(v: MacAddr6) -> Self {
+        Self::from_advmac(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advmac::MacAddrFormat;
+
+    #[test]
+    fn test_display() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]);
+        let mac = MacAddr6::from(addr);
+        assert_eq!([0xf5, 0x44, 0x33, 0x22, 0x11, 0x00], mac.to_array());
+        let mut s = String::new();
+        mac.format_write(&mut s, MacAddrFormat::ColonNotation)
+            .unwrap();
+        assert_eq!(addr.to_string(), s.to_lowercase());
+        assert_eq!(addr, BdAddr::from(mac));
+    }
+
+    #[test]
+    fn test_const() {
+        const MAC: MacAddr6 = MacAddr6::new([0xf5, 0x44, 0x33, 0x22, 0x11, 0x00]);
+        const ADDR: BdAddr = BdAddr::from_advmac(MAC);
+        const ROUNDTRIP: MacAddr6 = ADDR.to_advmac();
+        assert_eq!("f5:44:33:22:11:00", ADDR.to_string());
+        assert_eq!(MAC, ROUNDTRIP);
+    }
+}
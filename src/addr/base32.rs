@@ -0,0 +1,177 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use super::{Address, AddressParseError, BdAddr};
+
+#[cfg(feature = "alloc")]
+const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+#[cfg(feature = "alloc")]
+fn encode(b: &[u8]) -> String {
+    let mut s = String::with_capacity((b.len() * 8 + 4) / 5);
+    let mut buf = 0u16;
+    let mut bits = 0;
+    for v in b {
+        buf = (buf << 8) | *v as u16;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            s.push(ALPHABET[((buf >> bits) & 0x1F) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        s.push(ALPHABET[((buf << (5 - bits)) & 0x1F) as usize] as char);
+    }
+    s
+}
+
+fn decode<const N: usize>(s: &str) -> Result<[u8; N], AddressParseError> {
+    if s.len() != (N * 8 + 4) / 5 {
+        return Err(AddressParseError::INVALID);
+    }
+    let mut out = [0; N];
+    let mut buf = 0u16;
+    let mut bits = 0;
+    let mut n = 0;
+    for c in s.bytes() {
+        let v = match c {
+            b'a'..=b'z' => c - b'a',
+            b'A'..=b'Z' => c - b'A',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return Err(AddressParseError::INVALID),
+        };
+        buf = (buf << 5) | v as u16;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            *out.get_mut(n).ok_or(AddressParseError::INVALID)? = (buf >> bits) as u8;
+            n += 1;
+        }
+    }
+    if buf & ((1 << bits) - 1) != 0 {
+        return Err(AddressParseError::INVALID);
+    }
+    Ok(out)
+}
+
+impl BdAddr {
+    /// Encode as unpadded base32. (10 characters)
+    ///
+    /// The format is frozen, as it may be stored or linked.
+    ///
+    /// - Alphabet is RFC 4648 in lowercase. (`abcdefghijklmnopqrstuvwxyz234567`)
+    /// - Bytes are in display (big-endian) order, so addresses of the same OUI share the leading characters.
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+    /// assert_eq!("aanh3wtrcm", addr.to_base32());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_base32(&self) -> String {
+        let mut b = self.0;
+        b.reverse();
+        encode(&b)
+    }
+
+    /// Decode from unpadded base32. (case-insensitive)
+    ///
+    /// Rejects non-zero unused trailing bits.
+    pub fn from_base32(s: &str) -> Result<Self, AddressParseError> {
+        let mut b = decode::<6>(s)?;
+        b.reverse();
+        Ok(Self(b))
+    }
+}
+
+impl Address {
+    /// Encode as unpadded base32 with address type. (12 characters)
+    ///
+    /// Address type code of [`Address::to_tagged_bytes`] + 6 bytes, in the same format
+    /// as [`BdAddr::to_base32`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_base32(&self) -> String {
+        let [ty, a0, a1, a2, a3, a4, a5] = self.to_tagged_bytes();
+        encode(&[ty, a5, a4, a3, a2, a1, a0])
+    }
+
+    /// Decode from unpadded base32 with address type. (case-insensitive)
+    pub fn from_base32(s: &str) -> Result<Self, AddressParseError> {
+        let [ty, a5, a4, a3, a2, a1, a0] = decode::<7>(s)?;
+        Self::from_tagged_bytes([ty, a0, a1, a2, a3, a4, a5])
+            .map_err(|_| AddressParseError::INVALID)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AddressParseErrorKind;
+
+    #[test]
+    fn test_bd_addr() {
+        for (addr, s) in [
+            ("00:1a:7d:da:71:13", "aanh3wtrcm"),
+            ("00:00:00:00:00:00", "aaaaaaaaaa"),
+            ("ff:ff:ff:ff:ff:ff", "7777777774"),
+            ("53:03:8c:bc:bd:82", "kmbyzpf5qi"),
+        ] {
+            let addr = addr.parse::<BdAddr>().unwrap();
+            assert_eq!(s, addr.to_base32());
+            assert_eq!(addr, BdAddr::from_base32(s).unwrap());
+            assert_eq!(addr, BdAddr::from_base32(&s.to_uppercase()).unwrap());
+        }
+
+        assert!(BdAddr::from_base32("aanh3wtrc").is_err());
+        assert!(BdAddr::from_base32("aanh3wtrcma").is_err());
+        assert!(BdAddr::from_base32("aanh3wtrc1").is_err());
+        assert!(BdAddr::from_base32("aanh3wtrcn").is_err());
+    }
+
+    #[test]
+    fn test_address() {
+        for (addr, s) in [
+            (
+                Address::bredr_from_str("00:1a:7d:da:71:13").unwrap(),
+                "aaabu7o2oejq",
+            ),
+            (
+                Address::le_public_from_str("00:1a:7d:da:71:13").unwrap(),
+                "aeabu7o2oejq",
+            ),
+            (
+                Address::le_random_from_str("53:03:8c:bc:bd:82").unwrap(),
+                "ajjqhdf4xwba",
+            ),
+        ] {
+            assert_eq!(s, addr.to_base32());
+            assert_eq!(addr, Address::from_base32(s).unwrap());
+        }
+
+        assert!(Address::from_base32("amabu7o2oejq").is_err());
+        assert!(Address::from_base32("aanh3wtrcm").is_err());
+    }
+
+    #[test]
+    fn test_adversarial() {
+        for s in [
+            "",
+            "a",
+            "aanh3wtrc",
+            "aanh3wtrcmm",
+            "aanh3wtrc1",
+            "aanh3wtrc\u{e9}",
+            "\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}",
+            "7777777777",
+            "777777777777",
+        ] {
+            let err = BdAddr::from_base32(s).unwrap_err();
+            assert_eq!(AddressParseErrorKind::Invalid, err.kind(), "{:?}", s);
+            let err = Address::from_base32(s).unwrap_err();
+            assert_eq!(AddressParseErrorKind::Invalid, err.kind(), "{:?}", s);
+        }
+    }
+}
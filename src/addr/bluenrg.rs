@@ -0,0 +1,122 @@
+//! BlueNRG-MS (`bluenrg` crate) `BdAddr` holds the octets in the same (wire) order as [`BdAddr`],
+//! and `BdAddrType` / `OwnAddressType` carry the address type code (`0x00` public, `0x01` random).
+//!
+//! ```
+//! use bdaddr::Address;
+//! use bluenrg::gap::{AdvertisingFilterPolicy, AdvertisingType, DiscoverableParameters};
+//!
+//! let own = Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]);
+//! let (address_type, _bd_addr) = own.to_bluenrg().unwrap();
+//! let params = DiscoverableParameters {
+//!     advertising_type: AdvertisingType::ConnectableUndirected,
+//!     advertising_interval: None,
+//!     address_type,
+//!     filter_policy: AdvertisingFilterPolicy::AllowConnectionAndScan,
+//!     local_name: None,
+//!     advertising_data: &[],
+//!     conn_interval: (None, None),
+//! };
+//! // controller.set_discoverable(&params)
+//! # let _ = params;
+//! ```
+
+use core::convert::TryFrom;
+
+use bluenrg::gap::{self, BdAddrType, OwnAddressType};
+
+use super::{Address, BdAddr, UnsupportedAddressType};
+
+impl From<BdAddr> for gap::BdAddr {
+    #[inline]
+    fn from(v: BdAddr) -> Self {
+        Self(v.0)
+    }
+}
+
+impl From<gap::BdAddr> for BdAddr {
+    #[inline]
+    fn from(v: gap::BdAddr) -> Self {
+        Self(v.0)
+    }
+}
+
+/// Random address is classified by most significant two bits.
+impl From<BdAddrType> for Address {
+    fn from(v: BdAddrType) -> Self {
+        match v {
+            BdAddrType::Public(addr) => Self::le_public_from(addr.0),
+            BdAddrType::Random(addr) => Self::le_random_from(addr.0),
+        }
+    }
+}
+
+/// Peer address of connection and white list commands. Rejects BR/EDR Address.
+impl TryFrom<Address> for BdAddrType {
+    type Error = UnsupportedAddressType;
+
+    fn try_from(v: Address) -> Result<Self, Self::Error> {
+        match v {
+            Address::LePublic(..) => Ok(Self::Public(v.into_bd_addr().into())),
+            Address::LeRandom(..) => Ok(Self::Random(v.into_bd_addr().into())),
+            Address::BrEdr(..) => Err(UnsupportedAddressType(v.address_type())),
+        }
+    }
+}
+
+impl Address {
+    /// Convert to own address type and address of GAP commands. (e.g. `set_discoverable`)
+    ///
+    /// The address is for `hal::ConfigData::public_address` (public) or `le_set_random_address` (random).
+    /// Rejects BR/EDR Address.
+    pub fn to_bluenrg(&self) -> Result<(OwnAddressType, gap::BdAddr), UnsupportedAddressType> {
+        let ty = match self {
+            Self::LePublic(..) => OwnAddressType::Public,
+            Self::LeRandom(..) => OwnAddressType::Random,
+            Self::BrEdr(..) => return Err(UnsupportedAddressType(self.address_type())),
+        };
+        Ok((ty, (*self.as_ref()).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bd_addr() {
+        let b = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55];
+        let addr = gap::BdAddr::from(BdAddr::from(b));
+        assert_eq!(b, addr.0);
+        assert_eq!(b, <[u8; 6]>::from(BdAddr::from(addr)));
+    }
+
+    #[test]
+    fn test_bd_addr_type() {
+        let b = [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5];
+        for (addr, ty) in [
+            (Address::le_public_from(b), 0x00),
+            (Address::le_random_from(b), 0x01),
+        ] {
+            let v = BdAddrType::try_from(addr).unwrap();
+            let mut bytes = [0; 7];
+            v.copy_into_slice(&mut bytes);
+            assert_eq!([ty, 0x00, 0x11, 0x22, 0x33, 0x44, 0xf5], bytes);
+            assert_eq!(addr, Address::from(v));
+        }
+
+        let err = BdAddrType::try_from(Address::bredr_from(b)).unwrap_err();
+        assert_eq!("Unsupported address type. (BrEdr)", &err.to_string());
+    }
+
+    #[test]
+    fn test_to_bluenrg() {
+        let b = [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5];
+        let (ty, addr) = Address::le_public_from(b).to_bluenrg().unwrap();
+        assert_eq!(0x00, ty as u8);
+        assert_eq!(b, addr.0);
+        let (ty, addr) = Address::le_random_from(b).to_bluenrg().unwrap();
+        assert_eq!(0x01, ty as u8);
+        assert_eq!(b, addr.0);
+        assert!(Address::bredr_from(b).to_bluenrg().is_err());
+    }
+}
@@ -0,0 +1,120 @@
+//! `bluer::Address` holds the octets in display order, so they are reversed.
+
+use super::{Address, AddressType, BdAddr};
+
+impl From<BdAddr> for bluer::Address {
+    fn from(v: BdAddr) -> Self {
+        let mut b = v.0;
+        b.reverse();
+        Self(b)
+    }
+}
+
+impl From<bluer::Address> for BdAddr {
+    fn from(v: bluer::Address) -> Self {
+        let mut b = v.0;
+        b.reverse();
+        Self(b)
+    }
+}
+
+impl From<AddressType> for bluer::AddressType {
+    fn from(v: AddressType) -> Self {
+        match v {
+            AddressType::BrEdr => Self::BrEdr,
+            AddressType::LePublic => Self::LePublic,
+            AddressType::LeRandom => Self::LeRandom,
+        }
+    }
+}
+
+impl From<bluer::AddressType> for AddressType {
+    fn from(v: bluer::AddressType) -> Self {
+        match v {
+            bluer::AddressType::BrEdr => Self::BrEdr,
+            bluer::AddressType::LePublic => Self::LePublic,
+            bluer::AddressType::LeRandom => Self::LeRandom,
+        }
+    }
+}
+
+impl Address {
+    /// Construct from `bluer::Address` and `bluer::AddressType`.
+    pub fn from_bluer(addr: bluer::Address, ty: bluer::AddressType) -> Self {
+        let addr = BdAddr::from(addr);
+        match ty {
+            bluer::AddressType::BrEdr => addr.to_br_edr_addr(),
+            bluer::AddressType::LePublic => addr.to_le_public_addr(),
+            bluer::AddressType::LeRandom => addr.to_le_random_addr(),
+        }
+    }
+
+    /// Convert to `bluer::Address` and `bluer::AddressType`.
+    pub fn to_bluer(&self) -> (bluer::Address, bluer::AddressType) {
+        let ty = self.address_type().into();
+        (self.into_bd_addr().into(), ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RandomDeviceAddress;
+
+    #[test]
+    fn test_bd_addr() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        let baddr = bluer::Address::from(addr);
+        assert_eq!(bluer::Address([0x55, 0x44, 0x33, 0x22, 0x11, 0x00]), baddr);
+        assert_eq!(addr.to_string().to_uppercase(), baddr.to_string());
+        assert_eq!(addr, BdAddr::from(baddr));
+
+        let baddr = "F5:44:33:22:11:00".parse::<bluer::Address>().unwrap();
+        assert_eq!("f5:44:33:22:11:00", BdAddr::from(baddr).to_string());
+    }
+
+    #[test]
+    fn test_from_bluer() {
+        let baddr = "F5:44:33:22:11:00".parse::<bluer::Address>().unwrap();
+
+        let addr = Address::from_bluer(baddr, bluer::AddressType::BrEdr);
+        assert_eq!(Address::bredr_from_str("f5:44:33:22:11:00").unwrap(), addr);
+
+        let addr = Address::from_bluer(baddr, bluer::AddressType::LePublic);
+        assert_eq!(
+            Address::le_public_from_str("f5:44:33:22:11:00").unwrap(),
+            addr
+        );
+
+        let addr = Address::from_bluer(baddr, bluer::AddressType::LeRandom);
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Static(..))
+        ));
+        assert_eq!("f5:44:33:22:11:00", addr.to_string());
+    }
+
+    #[test]
+    fn test_to_bluer() {
+        let tests = [
+            (
+                Address::bredr_from_str("55:44:33:22:11:00").unwrap(),
+                bluer::AddressType::BrEdr,
+            ),
+            (
+                Address::le_public_from_str("55:44:33:22:11:00").unwrap(),
+                bluer::AddressType::LePublic,
+            ),
+            (
+                Address::le_random_from_str("55:44:33:22:11:00").unwrap(),
+                bluer::AddressType::LeRandom,
+            ),
+        ];
+        for (addr, expect) in tests {
+            let (baddr, ty) = addr.to_bluer();
+            assert_eq!("55:44:33:22:11:00", baddr.to_string().to_lowercase());
+            assert_eq!(expect, ty);
+            assert_eq!(addr, Address::from_bluer(baddr, ty));
+        }
+    }
+}
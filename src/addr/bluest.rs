@@ -0,0 +1,71 @@
+//! `bluest::DeviceId` is platform specific and does not expose its contents
+//! except via `Display`.
+//!
+//! - Linux: Bluetooth address. (e.g. `F5:44:33:22:11:00`)
+//! - Windows: Device Instance ID, ends with Bluetooth address. (e.g. `BluetoothLE#BluetoothLE00:1a:7d:da:71:13-f5:44:33:22:11:00`)
+//! - Android: Bluetooth address. (e.g. `F5:44:33:22:11:00`)
+//! - macOS / iOS: UUID assigned by CoreBluetooth. Bluetooth address is not available.
+
+use super::BdAddr;
+
+impl BdAddr {
+    /// Extract Bluetooth Device Address from `bluest::DeviceId`.
+    ///
+    /// Returns `None` if the platform does not expose the address (macOS / iOS)
+    /// or the device id is not in a known form.
+    pub fn from_bluest_device_id(id: &bluest::DeviceId) -> Option<Self> {
+        parse_device_id(&id.to_string())
+    }
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+fn parse_device_id(_: &str) -> Option<BdAddr> {
+    None
+}
+
+#[cfg(target_os = "windows")]
+fn parse_device_id(s: &str) -> Option<BdAddr> {
+    s.rsplit('-').next()?.parse().ok()
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "ios", target_os = "windows")))]
+fn parse_device_id(s: &str) -> Option<BdAddr> {
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    #[test]
+    fn test_parse_device_id() {
+        assert_eq!(
+            None,
+            parse_device_id("0F5B6E3D-2C1A-4B8E-9D7F-A1B2C3D4E5F6")
+        );
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn test_parse_device_id() {
+        assert_eq!(
+            Some(BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5])),
+            parse_device_id("BluetoothLE#BluetoothLE00:1a:7d:da:71:13-f5:44:33:22:11:00")
+        );
+        assert_eq!(
+            None,
+            parse_device_id("BluetoothLE#BluetoothLE00:1a:7d:da:71:13")
+        );
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "ios", target_os = "windows")))]
+    #[test]
+    fn test_parse_device_id() {
+        assert_eq!(
+            Some(BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5])),
+            parse_device_id("F5:44:33:22:11:00")
+        );
+        assert_eq!(None, parse_device_id("F5:44:33:22:11"));
+    }
+}
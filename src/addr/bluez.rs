@@ -0,0 +1,273 @@
+//! BlueZ represents Bluetooth Device Address as uppercase string, and address type as `public` or `random`.
+//!
+//! For string based bindings (e.g. `blurz`) without depending on them.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use super::{hex_digit, parse_groups, Address, AddressParseError, BdAddr, VariantParseError};
+
+/// Prefix of device object path segment.
+const DEV_PREFIX: &str = "dev_";
+
+/// Error for [`Address::from_bluez_device`]
+#[derive(Debug)]
+pub enum BluezAddressError {
+    /// Not an uppercase address string.
+    AddressParseError(AddressParseError),
+
+    /// Unknown address type string.
+    UnknownAddressType(VariantParseError),
+}
+
+impl fmt::Display for BluezAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddressParseError(e) => e.fmt(f),
+            Self::UnknownAddressType(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BluezAddressError {}
+
+impl From<AddressParseError> for BluezAddressError {
+    fn from(e: AddressParseError) -> Self {
+        Self::AddressParseError(e)
+    }
+}
+
+impl From<VariantParseError> for BluezAddressError {
+    fn from(e: VariantParseError) -> Self {
+        Self::UnknownAddressType(e)
+    }
+}
+
+/// Uppercase only [`hex_digit`], as BlueZ emits.
+fn upper_hex(b: u8) -> Option<u8> {
+    if b.is_ascii_lowercase() {
+        return None;
+    }
+    hex_digit(b)
+}
+
+impl BdAddr {
+    /// Parse uppercase address string as BlueZ emits. (e.g. `00:1A:7D:DA:71:13`)
+    ///
+    /// Unlike [`BdAddr::from_str`](core::str::FromStr::from_str), lowercase digits are rejected.
+    pub fn from_bluez_string(s: &str) -> Result<Self, AddressParseError> {
+        parse_groups(s.as_bytes(), 0, b':', upper_hex)
+    }
+
+    /// Format as uppercase address string as BlueZ emits. (e.g. `00:1A:7D:DA:71:13`)
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_bluez_string(&self) -> String {
+        self.format_with(Some(':'), true).to_string()
+    }
+
+    /// Parse `org.bluez.Device1` object path, or its final segment. (e.g. `/org/bluez/hci0/dev_00_1A_7D_DA_71_13`, `dev_00_1A_7D_DA_71_13`)
+    ///
+    /// The address is taken from the final segment, and must be uppercase as BlueZ emits.
+    /// Child objects of the device (e.g. `/org/bluez/hci0/dev_00_1A_7D_DA_71_13/service000a`) are rejected.
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = BdAddr::from_bluez_path("/org/bluez/hci0/dev_00_1A_7D_DA_71_13").unwrap();
+    /// assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+    /// assert_eq!(addr, BdAddr::from_bluez_path("dev_00_1A_7D_DA_71_13").unwrap());
+    /// ```
+    pub fn from_bluez_path(s: &str) -> Result<Self, AddressParseError> {
+        let start = s.rfind('/').map_or(0, |i| i + 1);
+        if !s[start..].starts_with(DEV_PREFIX) {
+            return Err(AddressParseError::INVALID);
+        }
+        parse_groups(s.as_bytes(), start + DEV_PREFIX.len(), b'_', upper_hex)
+    }
+
+    /// Format as device object path segment as BlueZ. (e.g. `dev_00_1A_7D_DA_71_13`)
+    ///
+    /// Append to the adapter path (e.g. `/org/bluez/hci0`) with `/` for the object path.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_bluez_path_segment(&self) -> String {
+        format!("{}{}", DEV_PREFIX, self.format_with(Some('_'), true))
+    }
+}
+
+impl Address {
+    /// Construct from `Address` and `AddressType` strings of `org.bluez.Device1`.
+    ///
+    /// | `AddressType` | Address |
+    /// |---------------|---------|
+    /// | `public` | LE Public Device Address |
+    /// | `random` | LE Random Device Address |
+    ///
+    /// BlueZ reports `public` for BR/EDR devices too, use [`BdAddr::to_br_edr_addr`] for them.
+    pub fn from_bluez_device(address: &str, address_type: &str) -> Result<Self, BluezAddressError> {
+        let addr = BdAddr::from_bluez_string(address)?;
+        Ok(Self::from_bluez_address_type(addr, address_type)?)
+    }
+
+    /// Tag with `AddressType` of `org.bluez.Device1`, as [`Address::from_bluez_device`].
+    pub(crate) fn from_bluez_address_type(
+        addr: BdAddr,
+        address_type: &str,
+    ) -> Result<Self, VariantParseError> {
+        match address_type {
+            "public" => Ok(addr.to_le_public_addr()),
+            "random" => Ok(addr.to_le_random_addr()),
+            x => Err(VariantParseError::address_type(x)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::addr::RandomDeviceAddress;
+
+    #[test]
+    fn test_bluez_string() {
+        let addr = BdAddr::from_bluez_string("00:1A:7D:DA:71:13").unwrap();
+        assert_eq!([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00], <[u8; 6]>::from(addr));
+        assert_eq!("00:1A:7D:DA:71:13", addr.to_bluez_string());
+
+        let addr = BdAddr::from([0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53]);
+        assert_eq!("53:03:8C:BC:BD:82", addr.to_bluez_string());
+        assert_eq!(
+            addr,
+            BdAddr::from_bluez_string("53:03:8C:BC:BD:82").unwrap()
+        );
+
+        for (s, message) in [
+            (
+                "00:1a:7d:da:71:13",
+                "invalid hex digit in group 1 at offset 4",
+            ),
+            (
+                "00:1A:7D:DA:71",
+                "wrong group count (expected 6, found 5) at offset 14",
+            ),
+            (
+                "00:1A:7D:DA:71:13:00",
+                "wrong group count (expected 6, found 7) at offset 17",
+            ),
+            (
+                "00-1A-7D-DA-71-13",
+                "invalid hex digit in group 0 at offset 2",
+            ),
+            (
+                "00:1A:7D:DA:71:13 ",
+                "invalid hex digit in group 5 at offset 17",
+            ),
+            ("0:01A:7D:DA:71:13", "group 0 too short at offset 1"),
+            (
+                "+0:1A:7D:DA:71:13",
+                "invalid hex digit in group 0 at offset 0",
+            ),
+            (
+                "/org/bluez/hci0/dev_00_1A_7D_DA_71_13",
+                "invalid hex digit in group 0 at offset 0",
+            ),
+            (
+                "00:1A:7D:DA:71:\u{e9}",
+                "invalid hex digit in group 5 at offset 15",
+            ),
+            ("", "empty group 0 at offset 0"),
+        ] {
+            let err = BdAddr::from_bluez_string(s).unwrap_err();
+            assert_eq!(message, err.to_string(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_bluez_path() {
+        let addr = BdAddr::from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+        assert_eq!("dev_00_1A_7D_DA_71_13", addr.to_bluez_path_segment());
+        for s in [
+            "dev_00_1A_7D_DA_71_13",
+            "/org/bluez/hci0/dev_00_1A_7D_DA_71_13",
+            "/org/bluez/hci1/dev_00_1A_7D_DA_71_13",
+        ] {
+            assert_eq!(addr, BdAddr::from_bluez_path(s).unwrap(), "{:?}", s);
+        }
+
+        let addr = BdAddr::from([0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53]);
+        assert_eq!(
+            addr,
+            BdAddr::from_bluez_path(&format!("/org/bluez/hci0/{}", addr.to_bluez_path_segment()))
+                .unwrap()
+        );
+
+        for (s, message) in [
+            (
+                "/org/bluez/hci0/dev_00_1A_7D_DA_71",
+                "wrong group count (expected 6, found 5) at offset 34",
+            ),
+            (
+                "dev_00_1A_7D_DA_71_13_00",
+                "wrong group count (expected 6, found 7) at offset 21",
+            ),
+            (
+                "/org/bluez/hci0/dev_00_1A_7D_DA_71_1G",
+                "invalid hex digit in group 5 at offset 36",
+            ),
+            (
+                "/org/bluez/hci0/dev_00_1a_7d_da_71_13",
+                "invalid hex digit in group 1 at offset 24",
+            ),
+            (
+                "dev_00:1A:7D:DA:71:13",
+                "invalid hex digit in group 0 at offset 6",
+            ),
+            ("dev_0_1A_7D_DA_71_13", "group 0 too short at offset 5"),
+            ("dev_", "empty group 0 at offset 4"),
+            (
+                "/org/bluez/hci0/00_1A_7D_DA_71_13",
+                "failed to parse address",
+            ),
+            ("00:1A:7D:DA:71:13", "failed to parse address"),
+            (
+                "/org/bluez/hci0/dev_00_1A_7D_DA_71_13/service000a",
+                "failed to parse address",
+            ),
+            (
+                "/org/bluez/hci0/dev_00_1A_7D_DA_71_13/",
+                "failed to parse address",
+            ),
+            ("", "failed to parse address"),
+        ] {
+            let err = BdAddr::from_bluez_path(s).unwrap_err();
+            assert_eq!(message, err.to_string(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_from_bluez_device() {
+        let addr = Address::from_bluez_device("00:1A:7D:DA:71:13", "public").unwrap();
+        assert_eq!(
+            Address::le_public_from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]),
+            addr
+        );
+        let addr = Address::from_bluez_device("53:03:8C:BC:BD:82", "random").unwrap();
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Resolvable(..))
+        ));
+
+        for ty in ["Public", "le-public", "br-edr", "bredr", ""] {
+            let err = Address::from_bluez_device("00:1A:7D:DA:71:13", ty).unwrap_err();
+            assert_eq!(format!("Unknown address type. ({:?})", ty), err.to_string());
+        }
+        let err = Address::from_bluez_device("00:1a:7d:da:71:13", "public").unwrap_err();
+        assert_eq!("invalid hex digit in group 1 at offset 4", err.to_string());
+    }
+}
@@ -0,0 +1,125 @@
+//! `bluez_async::MacAddress` holds the octets in display order. (see [byte order](BdAddr#byte-order) of [`BdAddr`])
+
+use bluez_async::{AddressType, MacAddress};
+
+use super::{Address, BdAddr};
+
+impl From<BdAddr> for MacAddress {
+    fn from(v: BdAddr) -> Self {
+        let mut b = v.0;
+        b.reverse();
+        b.into()
+    }
+}
+
+impl From<MacAddress> for BdAddr {
+    fn from(v: MacAddress) -> Self {
+        let mut b = <[u8; 6]>::from(v);
+        b.reverse();
+        Self(b)
+    }
+}
+
+impl Address {
+    /// Construct LE Address from `bluez_async::MacAddress` and `bluez_async::AddressType`.
+    pub fn from_bluez_async(addr: MacAddress, ty: AddressType) -> Self {
+        let addr = BdAddr::from(addr);
+        match ty {
+            AddressType::Public => addr.to_le_public_addr(),
+            AddressType::Random => addr.to_le_random_addr(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RandomDeviceAddress, RandomDeviceAddressKind};
+
+    #[test]
+    fn test_bd_addr() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]);
+        let mac = MacAddress::from(addr);
+        assert_eq!([0xf5, 0x44, 0x33, 0x22, 0x11, 0x00], <[u8; 6]>::from(mac));
+        assert_eq!("F5:44:33:22:11:00", mac.to_string());
+        assert_eq!(addr, BdAddr::from(mac));
+    }
+
+    #[test]
+    fn test_case() {
+        let upper = "F5:44:33:22:11:AA".parse::<MacAddress>().unwrap();
+        let lower = "f5:44:33:22:11:aa".parse::<MacAddress>().unwrap();
+        assert_eq!(BdAddr::from(upper), BdAddr::from(lower));
+        assert_eq!("f5:44:33:22:11:aa", BdAddr::from(upper).to_string());
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        for s in [
+            "",
+            "F5:44:33:22:11",
+            "F5:44:33:22:11:00:00",
+            "F5:44:33:22:11:000",
+            "G5:44:33:22:11:00",
+        ] {
+            assert!(s.parse::<MacAddress>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_conversion_edge_cases() {
+        for b in [[0x00; 6], [0xff; 6]] {
+            let mac = MacAddress::from(b);
+            let addr = BdAddr::from(mac);
+            assert_eq!(b, <[u8; 6]>::from(addr));
+            assert_eq!(b, <[u8; 6]>::from(MacAddress::from(addr)));
+        }
+
+        // kind by most significant two bits, which are the first octet of `MacAddress`
+        for (msb, kind) in [
+            (0x35, RandomDeviceAddressKind::NonResolvable),
+            (0x75, RandomDeviceAddressKind::Resolvable),
+            (0xb5, RandomDeviceAddressKind::Unknown),
+            (0xf5, RandomDeviceAddressKind::Static),
+        ] {
+            let mac = MacAddress::from([msb, 0x44, 0x33, 0x22, 0x11, 0x00]);
+            let bd_addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, msb]);
+            match Address::from_bluez_async(mac, AddressType::Random) {
+                Address::LeRandom(addr) => {
+                    assert_eq!(kind, addr.kind(), "{:02x}", msb);
+                    assert_eq!(bd_addr, *addr.as_ref());
+                }
+                addr => panic!("{:?}", addr),
+            }
+            assert_eq!(
+                bd_addr.to_le_public_addr(),
+                Address::from_bluez_async(mac, AddressType::Public)
+            );
+        }
+
+        // as BlueZ `AddressType` property
+        assert_eq!(AddressType::Public, "public".parse().unwrap());
+        assert_eq!(AddressType::Random, "random".parse().unwrap());
+        for s in ["", "Public", "le-public", "bredr"] {
+            assert!(s.parse::<AddressType>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_from_bluez_async() {
+        let mac = "F5:44:33:22:11:00".parse::<MacAddress>().unwrap();
+
+        let addr = Address::from_bluez_async(mac, AddressType::Public);
+        assert_eq!(
+            Address::le_public_from_str("f5:44:33:22:11:00").unwrap(),
+            addr
+        );
+
+        let addr = Address::from_bluez_async(mac, AddressType::Random);
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Static(..))
+        ));
+        assert_eq!("f5:44:33:22:11:00", addr.to_string());
+    }
+}
@@ -0,0 +1,165 @@
+//! BSON representation of [`BdAddr`], for use with `#[serde(with = ...)]`.
+//!
+//! Both modules accept either representation on deserialize.
+//!
+//! ```
+//! use bdaddr::BdAddr;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Scan {
+//!     #[serde(with = "bdaddr::bson::string")]
+//!     addr: BdAddr,
+//!     #[serde(with = "bdaddr::bson::binary")]
+//!     raw: BdAddr,
+//! }
+//! ```
+
+use std::convert::TryFrom;
+
+use bson_crate::spec::BinarySubtype;
+use bson_crate::{Binary, Bson};
+use serde::de::{Error, Unexpected};
+use serde::{Deserialize, Deserializer};
+
+use super::BdAddr;
+
+/// Stored as lowercase string. (e.g. `00:1a:7d:da:71:13`)
+impl From<BdAddr> for Bson {
+    fn from(v: BdAddr) -> Self {
+        Self::String(v.to_string())
+    }
+}
+
+/// Stored as generic subtype of 6 bytes in the same (wire) order as [`BdAddr`].
+impl From<BdAddr> for Binary {
+    fn from(v: BdAddr) -> Self {
+        Self {
+            subtype: BinarySubtype::Generic,
+            bytes: v.0.to_vec(),
+        }
+    }
+}
+
+fn deserialize<'de, D>(d: D) -> Result<BdAddr, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Bson::deserialize(d)? {
+        Bson::String(s) => s
+            .parse()
+            .map_err(|_| D::Error::invalid_value(Unexpected::Str(&s), &"Bluetooth Device Address")),
+        Bson::Binary(Binary { bytes, .. }) => <[u8; 6]>::try_from(&bytes[..])
+            .map(BdAddr)
+            .map_err(|_| D::Error::invalid_length(bytes.len(), &"6 bytes")),
+        v => Err(D::Error::invalid_type(
+            Unexpected::Other(&format!("{:?}", v.element_type())),
+            &"string or binary",
+        )),
+    }
+}
+
+/// Store as string. (e.g. `00:1a:7d:da:71:13`)
+pub mod string {
+    use serde::{Deserializer, Serializer};
+
+    use super::BdAddr;
+
+    /// Serialize as string.
+    pub fn serialize<S>(v: &BdAddr, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.collect_str(v)
+    }
+
+    /// Deserialize from string or binary.
+    pub fn deserialize<'de, D>(d: D) -> Result<BdAddr, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Store as `Binary` generic subtype of 6 bytes in the same (wire) order as [`BdAddr`].
+pub mod binary {
+    use bson_crate::Binary;
+    use serde::{Deserializer, Serialize, Serializer};
+
+    use super::BdAddr;
+
+    /// Serialize as binary.
+    pub fn serialize<S>(v: &BdAddr, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Binary::from(*v).serialize(s)
+    }
+
+    /// Deserialize from string or binary.
+    pub fn deserialize<'de, D>(d: D) -> Result<BdAddr, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bson_crate::{deserialize_from_document, doc, serialize_to_document};
+    use serde::Serialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Scan {
+        #[serde(with = "string")]
+        addr: BdAddr,
+        #[serde(with = "binary")]
+        raw: BdAddr,
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+        let scan = Scan { addr, raw: addr };
+        let doc = serialize_to_document(&scan).unwrap();
+        assert_eq!(
+            doc! {
+                "addr": "00:1a:7d:da:71:13",
+                "raw": Binary::from(addr),
+            },
+            doc
+        );
+        assert_eq!(
+            Binary::from(scan.addr).bytes,
+            vec![0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]
+        );
+        assert_eq!(scan, deserialize_from_document(doc).unwrap());
+    }
+
+    #[test]
+    fn test_migration() {
+        let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+        let doc = doc! {
+            "addr": Binary::from(addr),
+            "raw": Bson::from(addr),
+        };
+        let scan = deserialize_from_document::<Scan>(doc).unwrap();
+        assert_eq!(addr, scan.addr);
+        assert_eq!(addr, scan.raw);
+    }
+
+    #[test]
+    fn test_err() {
+        let raw = Binary::from("00:1a:7d:da:71:13".parse::<BdAddr>().unwrap());
+        for doc in [
+            doc! { "addr": "00:1a:7d:da:71", "raw": raw.clone() },
+            doc! { "addr": Binary { subtype: BinarySubtype::Generic, bytes: vec![0; 5] }, "raw": raw.clone() },
+            doc! { "addr": 1, "raw": raw.clone() },
+        ] {
+            assert!(deserialize_from_document::<Scan>(doc).is_err());
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! `bt_hci::param::BdAddr` holds the octets in the same (wire) order as [`BdAddr`].
+
+use bt_hci::param::{self, AddrKind};
+
+use super::{AddressType, BdAddr, UnknownAddressType};
+
+/// ```
+/// use bdaddr::BdAddr;
+/// use bt_hci::cmd::le::LeSetRandomAddr;
+///
+/// let addr = "f5:44:33:22:11:00".parse::<BdAddr>().unwrap();
+/// let cmd = LeSetRandomAddr::new(addr.into());
+/// ```
+impl From<BdAddr> for param::BdAddr {
+    #[inline]
+    fn from(v: BdAddr) -> Self {
+        Self(v.0)
+    }
+}
+
+impl From<param::BdAddr> for BdAddr {
+    #[inline]
+    fn from(v: param::BdAddr) -> Self {
+        Self(v.0)
+    }
+}
+
+impl AddressType {
+    /// Construct from `bt_hci::param::AddrKind`.
+    ///
+    /// `RESOLVABLE_PRIVATE_OR_PUBLIC` and `RESOLVABLE_PRIVATE_OR_RANDOM` are mapped to
+    /// the type of the identity address. (`LePublic` and `LeRandom`)
+    /// `ANONYMOUS_ADV` and reserved values are [`UnknownAddressType`].
+    pub fn from_bt_hci(kind: AddrKind) -> Result<Self, UnknownAddressType> {
+        match kind {
+            AddrKind::PUBLIC | AddrKind::RESOLVABLE_PRIVATE_OR_PUBLIC => Ok(Self::LePublic),
+            AddrKind::RANDOM | AddrKind::RESOLVABLE_PRIVATE_OR_RANDOM => Ok(Self::LeRandom),
+            x => Err(UnknownAddressType(x.as_raw())),
+        }
+    }
+
+    /// Convert to `bt_hci::param::AddrKind`.
+    ///
+    /// Returns `None` for `BrEdr`.
+    pub fn to_bt_hci(&self) -> Option<AddrKind> {
+        match self {
+            Self::BrEdr => None,
+            Self::LePublic => Some(AddrKind::PUBLIC),
+            Self::LeRandom => Some(AddrKind::RANDOM),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bd_addr() {
+        let b = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55];
+        let addr = param::BdAddr::from(BdAddr::from(b));
+        assert_eq!(&b[..], addr.raw());
+        assert_eq!(b, <[u8; 6]>::from(BdAddr::from(addr)));
+    }
+
+    #[test]
+    fn test_addr_kind() {
+        for (kind, expect) in [
+            (AddrKind::PUBLIC, AddressType::LePublic),
+            (AddrKind::RANDOM, AddressType::LeRandom),
+            (
+                AddrKind::RESOLVABLE_PRIVATE_OR_PUBLIC,
+                AddressType::LePublic,
+            ),
+            (
+                AddrKind::RESOLVABLE_PRIVATE_OR_RANDOM,
+                AddressType::LeRandom,
+            ),
+        ] {
+            assert_eq!(expect, AddressType::from_bt_hci(kind).unwrap());
+        }
+
+        let err = AddressType::from_bt_hci(AddrKind::ANONYMOUS_ADV).unwrap_err();
+        assert_eq!("Unknown address type. (0xff)", &format!("{}", err));
+        assert!(AddressType::from_bt_hci(AddrKind::new(4)).is_err());
+
+        assert_eq!(None, AddressType::BrEdr.to_bt_hci());
+        assert_eq!(Some(AddrKind::PUBLIC), AddressType::LePublic.to_bt_hci());
+        assert_eq!(Some(AddrKind::RANDOM), AddressType::LeRandom.to_bt_hci());
+    }
+}
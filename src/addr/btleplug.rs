@@ -0,0 +1,78 @@
+//! `btleplug::api::BDAddr` holds the octets in big-endian (display) order,
+//! while [`BdAddr`] holds them in little-endian (wire) order.
+//! So `55:44:33:22:11:00` is `[0x55, 0x44, 0x33, 0x22, 0x11, 0x00]` in btleplug
+//! and `[0x00, 0x11, 0x22, 0x33, 0x44, 0x55]` in this crate.
+
+use btleplug::api::{AddressType, BDAddr};
+
+use super::{Address, BdAddr};
+
+impl From<BdAddr> for BDAddr {
+    fn from(v: BdAddr) -> Self {
+        let mut b = v.0;
+        b.reverse();
+        b.into()
+    }
+}
+
+impl From<BDAddr> for BdAddr {
+    fn from(v: BDAddr) -> Self {
+        let mut b = v.into_inner();
+        b.reverse();
+        Self(b)
+    }
+}
+
+impl Address {
+    /// Construct LE Address from `btleplug::api::BDAddr` and `btleplug::api::AddressType`.
+    pub fn from_btleplug(addr: BDAddr, ty: AddressType) -> Self {
+        let addr = BdAddr::from(addr);
+        match ty {
+            AddressType::Public => addr.to_le_public_addr(),
+            AddressType::Random => addr.to_le_random_addr(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RandomDeviceAddress;
+
+    #[test]
+    fn test_bd_addr() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        let baddr = BDAddr::from(addr);
+        assert_eq!([0x55, 0x44, 0x33, 0x22, 0x11, 0x00], baddr.into_inner());
+        assert_eq!(addr.to_string(), format!("{:x}", baddr));
+        assert_eq!(addr, BdAddr::from(baddr));
+    }
+
+    #[test]
+    fn test_roundtrip_str() {
+        let baddr = "F5:44:33:22:11:00".parse::<BDAddr>().unwrap();
+        let addr = BdAddr::from(baddr);
+        assert_eq!("f5:44:33:22:11:00", addr.to_string());
+
+        let baddr = BDAddr::from(addr.to_string().parse::<BdAddr>().unwrap());
+        assert_eq!("F5:44:33:22:11:00", baddr.to_string());
+    }
+
+    #[test]
+    fn test_from_btleplug() {
+        let baddr = "F5:44:33:22:11:00".parse::<BDAddr>().unwrap();
+
+        let addr = Address::from_btleplug(baddr, AddressType::Public);
+        assert_eq!(
+            Address::le_public_from_str("f5:44:33:22:11:00").unwrap(),
+            addr
+        );
+
+        let addr = Address::from_btleplug(baddr, AddressType::Random);
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Static(..))
+        ));
+        assert_eq!("f5:44:33:22:11:00", addr.to_string());
+    }
+}
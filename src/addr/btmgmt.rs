@@ -0,0 +1,153 @@
+use std::convert::TryFrom;
+
+use btmgmt::packet;
+
+use super::{Address, AddressType, BdAddr, UnknownAddressType};
+
+impl AddressType {
+    /// Construct from Management API address type code.
+    ///
+    /// ref bluez/doc/mgmt-api.txt
+    ///
+    /// | code | type |
+    /// |------|------|
+    /// | 0 | BR/EDR |
+    /// | 1 | LE Public |
+    /// | 2 | LE Random |
+    pub fn from_mgmt_code(code: u8) -> Result<Self, UnknownAddressType> {
+        match code {
+            0 => Ok(Self::BrEdr),
+            1 => Ok(Self::LePublic),
+            2 => Ok(Self::LeRandom),
+            x => Err(UnknownAddressType(x)),
+        }
+    }
+
+    /// Get Management API address type code.
+    pub fn to_mgmt_code(&self) -> u8 {
+        match self {
+            Self::BrEdr => 0,
+            Self::LePublic => 1,
+            Self::LeRandom => 2,
+        }
+    }
+}
+
+impl From<AddressType> for packet::AddressType {
+    fn from(v: AddressType) -> Self {
+        match v {
+            AddressType::BrEdr => Self::BrEdr,
+            AddressType::LePublic => Self::LePublic,
+            AddressType::LeRandom => Self::LeRandom,
+        }
+    }
+}
+
+impl From<packet::AddressType> for AddressType {
+    fn from(v: packet::AddressType) -> Self {
+        match v {
+            packet::AddressType::BrEdr => Self::BrEdr,
+            packet::AddressType::LePublic => Self::LePublic,
+            packet::AddressType::LeRandom => Self::LeRandom,
+        }
+    }
+}
+
+impl From<BdAddr> for packet::Address {
+    fn from(v: BdAddr) -> Self {
+        Self::new(v.0.into())
+    }
+}
+
+impl From<packet::Address> for BdAddr {
+    fn from(v: packet::Address) -> Self {
+        Self((*v).clone().into())
+    }
+}
+
+impl From<Address> for (packet::Address, packet::AddressType) {
+    fn from(v: Address) -> Self {
+        let ty = v.address_type().into();
+        (v.into_bd_addr().into(), ty)
+    }
+}
+
+impl From<(packet::Address, packet::AddressType)> for Address {
+    fn from((addr, ty): (packet::Address, packet::AddressType)) -> Self {
+        let addr = BdAddr::from(addr);
+        match ty {
+            packet::AddressType::BrEdr => addr.to_br_edr_addr(),
+            packet::AddressType::LePublic => addr.to_le_public_addr(),
+            packet::AddressType::LeRandom => addr.to_le_random_addr(),
+        }
+    }
+}
+
+impl TryFrom<(packet::Address, u8)> for Address {
+    type Error = UnknownAddressType;
+
+    fn try_from((addr, code): (packet::Address, u8)) -> Result<Self, Self::Error> {
+        let ty = AddressType::from_mgmt_code(code)?;
+        Ok((addr, ty.into()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let tests = [
+            (
+                Address::bredr_from_str("55:44:33:22:11:00").unwrap(),
+                packet::AddressType::BrEdr,
+            ),
+            (
+                Address::le_public_from_str("55:44:33:22:11:00").unwrap(),
+                packet::AddressType::LePublic,
+            ),
+            (
+                Address::le_random_from_str("35:44:33:22:11:00").unwrap(),
+                packet::AddressType::LeRandom,
+            ),
+            (
+                Address::le_random_from_str("75:44:33:22:11:00").unwrap(),
+                packet::AddressType::LeRandom,
+            ),
+            (
+                Address::le_random_from_str("F5:44:33:22:11:00").unwrap(),
+                packet::AddressType::LeRandom,
+            ),
+            (
+                Address::le_random_from_str("B5:44:33:22:11:00").unwrap(),
+                packet::AddressType::LeRandom,
+            ),
+        ];
+        for (addr, expect) in tests {
+            let (maddr, ty) = <(packet::Address, packet::AddressType)>::from(addr);
+            assert_eq!(addr.to_string(), maddr.to_string());
+            assert_eq!(expect, ty);
+            assert_eq!(addr, Address::from((maddr, ty)));
+        }
+    }
+
+    #[test]
+    fn test_mgmt_code() {
+        let addr = "55:44:33:22:11:00".parse::<packet::Address>().unwrap();
+        for (code, expect) in [
+            (0, AddressType::BrEdr),
+            (1, AddressType::LePublic),
+            (2, AddressType::LeRandom),
+        ] {
+            assert_eq!(expect, AddressType::from_mgmt_code(code).unwrap());
+            assert_eq!(code, expect.to_mgmt_code());
+            let addr = Address::try_from((addr.clone(), code)).unwrap();
+            assert_eq!(expect, addr.address_type());
+        }
+
+        let err = Address::try_from((addr, 3)).unwrap_err();
+        assert_eq!("UnknownAddressType(3)", &format!("{:?}", err));
+        assert_eq!("Unknown address type. (0x03)", &format!("{}", err));
+    }
+}
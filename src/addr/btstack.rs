@@ -0,0 +1,141 @@
+//! BTstack `bd_addr_t` holds the octets in **big-endian (display) order**,
+//! that is reversed relative to [`BdAddr`]. (`bd_addr_t` of `00:1a:7d:da:71:13` is `[0x00, 0x1a, .., 0x13]`)
+//!
+//! This does not depend on `btstack-sys` crate, the raw `(addr, addr_type)` pair is used instead.
+//!
+//! ```
+//! use bdaddr::Address;
+//!
+//! // from `gap_event_advertising_report_get_address` / `_get_address_type`
+//! let addr = Address::from_btstack([0x53, 0x03, 0x8c, 0xbc, 0xbd, 0x82], 0x01).unwrap();
+//! assert_eq!("53:03:8c:bc:bd:82", addr.to_string());
+//! assert_eq!(([0x53, 0x03, 0x8c, 0xbc, 0xbd, 0x82], 0x01), addr.to_btstack().unwrap());
+//! ```
+
+use super::{Address, BdAddr, UnknownAddressType, UnsupportedAddressType};
+
+const BD_ADDR_TYPE_LE_PUBLIC: u8 = 0x00;
+const BD_ADDR_TYPE_LE_RANDOM: u8 = 0x01;
+const BD_ADDR_TYPE_LE_PUBLIC_IDENTITY: u8 = 0x02;
+const BD_ADDR_TYPE_LE_RANDOM_IDENTITY: u8 = 0x03;
+
+impl Address {
+    /// Construct from BTstack `bd_addr_t` (big-endian) and `bd_addr_type_t`.
+    ///
+    /// | `addr_type` | Address |
+    /// |-------------|---------|
+    /// | 0x00 `BD_ADDR_TYPE_LE_PUBLIC` | LE Public Device Address |
+    /// | 0x01 `BD_ADDR_TYPE_LE_RANDOM` | LE Random Device Address |
+    /// | 0x02 `BD_ADDR_TYPE_LE_PUBLIC_IDENTITY` | LE Public Device Address (identity) |
+    /// | 0x03 `BD_ADDR_TYPE_LE_RANDOM_IDENTITY` | LE Random Device Address (identity) |
+    ///
+    /// `BD_ADDR_TYPE_SCO` (0xfc), `BD_ADDR_TYPE_ACL` (0xfd) and `BD_ADDR_TYPE_UNKNOWN` (0xfe) are
+    /// connection pseudo-types, and are [`UnknownAddressType`].
+    pub fn from_btstack(addr: [u8; 6], addr_type: u8) -> Result<Self, UnknownAddressType> {
+        let mut b = addr;
+        b.reverse();
+        let addr = BdAddr(b);
+        match addr_type {
+            BD_ADDR_TYPE_LE_PUBLIC | BD_ADDR_TYPE_LE_PUBLIC_IDENTITY => {
+                Ok(addr.to_le_public_addr())
+            }
+            BD_ADDR_TYPE_LE_RANDOM | BD_ADDR_TYPE_LE_RANDOM_IDENTITY => {
+                Ok(addr.to_le_random_addr())
+            }
+            x => Err(UnknownAddressType(x)),
+        }
+    }
+
+    /// Convert to BTstack `bd_addr_t` (big-endian) and `bd_addr_type_t`.
+    ///
+    /// BR/EDR Address is not supported, BTstack takes bare `bd_addr_t` for it.
+    pub fn to_btstack(&self) -> Result<([u8; 6], u8), UnsupportedAddressType> {
+        let addr_type = match self {
+            Self::LePublic(..) => BD_ADDR_TYPE_LE_PUBLIC,
+            Self::LeRandom(..) => BD_ADDR_TYPE_LE_RANDOM,
+            Self::BrEdr(..) => return Err(UnsupportedAddressType(self.address_type())),
+        };
+        let mut b = self.as_ref().0;
+        b.reverse();
+        Ok((b, addr_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RandomDeviceAddress;
+
+    #[test]
+    fn test_byte_order() {
+        // `bd_addr_to_str` prints `00:1A:7D:DA:71:13` for bd_addr_t [00 1a 7d da 71 13]
+        let bd_addr_t = [0x00, 0x1a, 0x7d, 0xda, 0x71, 0x13];
+        let addr = Address::from_btstack(bd_addr_t, 0x00).unwrap();
+        assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+        assert_eq!(
+            [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00],
+            <[u8; 6]>::from(addr.into_bd_addr())
+        );
+        assert_eq!((bd_addr_t, 0x00), addr.to_btstack().unwrap());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for (b, addr_type, expect) in [
+            (
+                [0x00, 0x1a, 0x7d, 0xda, 0x71, 0x13],
+                0x00,
+                Address::le_public_from_str("00:1a:7d:da:71:13").unwrap(),
+            ),
+            (
+                [0xf5, 0x44, 0x33, 0x22, 0x11, 0x00],
+                0x01,
+                Address::le_random_from_str("f5:44:33:22:11:00").unwrap(),
+            ),
+        ] {
+            let addr = Address::from_btstack(b, addr_type).unwrap();
+            assert_eq!(expect, addr);
+            assert_eq!((b, addr_type), addr.to_btstack().unwrap());
+        }
+
+        let addr = Address::from_btstack([0x53, 0x03, 0x8c, 0xbc, 0xbd, 0x82], 0x01).unwrap();
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Resolvable(..))
+        ));
+    }
+
+    #[test]
+    fn test_identity() {
+        let b = [0xf5, 0x44, 0x33, 0x22, 0x11, 0x00];
+        let addr = Address::from_btstack(b, 0x02).unwrap();
+        assert_eq!(
+            Address::le_public_from_str("f5:44:33:22:11:00").unwrap(),
+            addr
+        );
+        assert_eq!((b, 0x00), addr.to_btstack().unwrap());
+
+        let addr = Address::from_btstack(b, 0x03).unwrap();
+        assert_eq!(
+            Address::le_random_from_str("f5:44:33:22:11:00").unwrap(),
+            addr
+        );
+        assert_eq!((b, 0x01), addr.to_btstack().unwrap());
+    }
+
+    #[test]
+    fn test_err() {
+        for (addr_type, expect) in [
+            (0xfc, "Unknown address type. (0xfc)"),
+            (0xfd, "Unknown address type. (0xfd)"),
+            (0xfe, "Unknown address type. (0xfe)"),
+            (0x04, "Unknown address type. (0x04)"),
+        ] {
+            let err = Address::from_btstack([0; 6], addr_type).unwrap_err();
+            assert_eq!(expect, &err.to_string());
+        }
+
+        let err = Address::bredr_from([0; 6]).to_btstack().unwrap_err();
+        assert_eq!("Unsupported address type. (BrEdr)", &err.to_string());
+    }
+}
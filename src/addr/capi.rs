@@ -0,0 +1,183 @@
+//! C ABI.
+//!
+//! The header can be generated by `cbindgen` with `cbindgen.toml` at the crate root.
+//!
+//! ```sh
+//! cbindgen --config cbindgen.toml --output bdaddr.h
+//! cargo rustc --release --features capi --crate-type staticlib
+//! ```
+//!
+//! Addresses are 6 bytes in little-endian (wire) order, as [`BdAddr`].
+//!
+//! # Error codes
+//!
+//! Functions return [`BDADDR_OK`] (or a non-negative result) on success, and one of negative
+//! `BDADDR_ERR_*` codes on failure. The codes are stable, new ones may be added.
+//! Output buffers are not written on failure.
+//! Panics never unwind across the boundary, they are reported as [`BDADDR_ERR_PANIC`].
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::panic::{self, UnwindSafe};
+use std::ptr;
+
+use super::{BdAddr, RandomDeviceAddress};
+
+/// Success.
+pub const BDADDR_OK: i32 = 0;
+
+/// Null pointer argument.
+pub const BDADDR_ERR_NULL: i32 = -1;
+
+/// Failed to parse address. (including invalid UTF-8)
+pub const BDADDR_ERR_PARSE: i32 = -2;
+
+/// Panicked inside the library.
+pub const BDADDR_ERR_PANIC: i32 = -3;
+
+fn guard<F>(f: F) -> i32
+where
+    F: FnOnce() -> i32 + UnwindSafe,
+{
+    panic::catch_unwind(f).unwrap_or(BDADDR_ERR_PANIC)
+}
+
+unsafe fn read_addr(addr: *const u8) -> Option<BdAddr> {
+    if addr.is_null() {
+        return None;
+    }
+    Some(BdAddr::from(ptr::read(addr as *const [u8; 6])))
+}
+
+/// Parse NUL-terminated address string (e.g. `00:1a:7d:da:71:13`) into `out`.
+///
+/// Returns [`BDADDR_OK`], [`BDADDR_ERR_NULL`] or [`BDADDR_ERR_PARSE`].
+///
+/// # Safety
+///
+/// `s` must be null or a NUL-terminated string, and `out` must be null or valid for writes of 6 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bdaddr_parse(s: *const c_char, out: *mut u8) -> i32 {
+    guard(|| {
+        if s.is_null() || out.is_null() {
+            return BDADDR_ERR_NULL;
+        }
+        let addr = match CStr::from_ptr(s).to_str().map(str::parse::<BdAddr>) {
+            Ok(Ok(addr)) => addr,
+            _ => return BDADDR_ERR_PARSE,
+        };
+        ptr::write(out as *mut [u8; 6], addr.into());
+        BDADDR_OK
+    })
+}
+
+/// Format address `addr` into `out` as NUL-terminated lowercase string. (17 characters and NUL)
+///
+/// Returns [`BDADDR_OK`] or [`BDADDR_ERR_NULL`].
+///
+/// # Safety
+///
+/// `addr` must be null or valid for reads of 6 bytes, and `out` must be null or valid for writes of 18 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bdaddr_format(addr: *const u8, out: *mut c_char) -> i32 {
+    guard(|| {
+        let addr = match read_addr(addr) {
+            Some(addr) if !out.is_null() => addr,
+            _ => return BDADDR_ERR_NULL,
+        };
+        let mut buf = [0; BdAddr::STR_LEN];
+        let s = addr.write_str_to(&mut buf);
+        ptr::copy_nonoverlapping(s.as_ptr(), out as *mut u8, BdAddr::STR_LEN);
+        *out.add(BdAddr::STR_LEN) = 0;
+        BDADDR_OK
+    })
+}
+
+/// Test address `addr` is a Resolvable Private Address generated from Identity Resolving Key `irk`. (16 bytes)
+///
+/// The IRK is in the same order as `ResolvablePrivateAddress::matches`.
+///
+/// Returns `1` if matches, `0` if not matches or not a Resolvable Private Address, or [`BDADDR_ERR_NULL`].
+///
+/// # Safety
+///
+/// `addr` must be null or valid for reads of 6 bytes, and `irk` must be null or valid for reads of 16 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bdaddr_rpa_matches(addr: *const u8, irk: *const u8) -> i32 {
+    guard(|| {
+        let addr = match read_addr(addr) {
+            Some(addr) if !irk.is_null() => addr,
+            _ => return BDADDR_ERR_NULL,
+        };
+        let irk = &*(irk as *const [u8; 16]);
+        match RandomDeviceAddress::new(addr) {
+            RandomDeviceAddress::Resolvable(addr) => addr.matches(irk) as i32,
+            _ => 0,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut out = [0xff; 6];
+        let r = unsafe { bdaddr_parse(b"00:1A:7D:DA:71:13\0".as_ptr() as _, out.as_mut_ptr()) };
+        assert_eq!(BDADDR_OK, r);
+        assert_eq!([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00], out);
+
+        let mut out = [0xff; 6];
+        for s in [&b"00:1a:7d:da:71\0"[..], b"\xff\0"] {
+            let r = unsafe { bdaddr_parse(s.as_ptr() as _, out.as_mut_ptr()) };
+            assert_eq!(BDADDR_ERR_PARSE, r);
+        }
+        assert_eq!([0xff; 6], out);
+
+        let r = unsafe { bdaddr_parse(ptr::null(), out.as_mut_ptr()) };
+        assert_eq!(BDADDR_ERR_NULL, r);
+        let r = unsafe { bdaddr_parse(b"00:1a:7d:da:71:13\0".as_ptr() as _, ptr::null_mut()) };
+        assert_eq!(BDADDR_ERR_NULL, r);
+    }
+
+    #[test]
+    fn test_format() {
+        let addr = [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00];
+        let mut out = [0x7f as c_char; 18];
+        let r = unsafe { bdaddr_format(addr.as_ptr(), out.as_mut_ptr()) };
+        assert_eq!(BDADDR_OK, r);
+        let s = unsafe { CStr::from_ptr(out.as_ptr()) };
+        assert_eq!("00:1a:7d:da:71:13", s.to_str().unwrap());
+
+        let r = unsafe { bdaddr_format(ptr::null(), out.as_mut_ptr()) };
+        assert_eq!(BDADDR_ERR_NULL, r);
+        let r = unsafe { bdaddr_format(addr.as_ptr(), ptr::null_mut()) };
+        assert_eq!(BDADDR_ERR_NULL, r);
+    }
+
+    #[test]
+    fn test_rpa_matches() {
+        let irk = [
+            25, 120, 162, 175, 221, 117, 123, 237, 252, 157, 198, 158, 149, 215, 51, 179,
+        ];
+        let rpa = [130, 189, 188, 140, 3, 83];
+        assert_eq!(1, unsafe { bdaddr_rpa_matches(rpa.as_ptr(), irk.as_ptr()) });
+        let other = [131, 189, 188, 140, 3, 83];
+        assert_eq!(0, unsafe {
+            bdaddr_rpa_matches(other.as_ptr(), irk.as_ptr())
+        });
+        let public = [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00];
+        assert_eq!(0, unsafe {
+            bdaddr_rpa_matches(public.as_ptr(), irk.as_ptr())
+        });
+        assert_eq!(BDADDR_ERR_NULL, unsafe {
+            bdaddr_rpa_matches(rpa.as_ptr(), ptr::null())
+        });
+    }
+
+    #[test]
+    fn test_guard() {
+        assert_eq!(BDADDR_ERR_PANIC, guard(|| panic!("boom")));
+    }
+}
@@ -0,0 +1,159 @@
+//! Command line argument parsing with `clap`.
+//!
+//! ```
+//! use bdaddr::{Address, AddressType, BdAddr};
+//! use clap::{value_parser, Arg, Command};
+//!
+//! let cmd = Command::new("app")
+//!     .arg(Arg::new("address").long("address").value_parser(value_parser!(BdAddr)))
+//!     .arg(Arg::new("address-type").long("address-type").value_parser(value_parser!(AddressType)))
+//!     .arg(Arg::new("peer").long("peer").value_parser(value_parser!(Address)));
+//! let m = cmd.get_matches_from([
+//!     "app",
+//!     "--address", "00:1a:7d:da:71:13",
+//!     "--address-type", "public",
+//!     "--peer", "random/53:03:8c:bc:bd:82",
+//! ]);
+//! assert_eq!(Some(&AddressType::LePublic), m.get_one("address-type"));
+//! ```
+
+use clap::builder::ValueParserFactory;
+use clap::builder::{PossibleValue, StringValueParser, TryMapValueParser, TypedValueParser};
+use clap::ValueEnum;
+
+use super::{Address, AddressFromStrError, AddressParseError, AddressType, BdAddr};
+
+/// `public`, `random` or `bredr`. (same as [`AddressType::as_ref`])
+impl ValueEnum for AddressType {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::LePublic, Self::LeRandom, Self::BrEdr]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::LePublic => PossibleValue::new("public").help("LE Public Device Address"),
+            Self::LeRandom => PossibleValue::new("random").help("LE Random Device Address"),
+            Self::BrEdr => PossibleValue::new("bredr").help("Classic BR/EDR Address"),
+        })
+    }
+}
+
+impl ValueParserFactory for BdAddr {
+    type Parser =
+        TryMapValueParser<StringValueParser, fn(String) -> Result<Self, AddressParseError>>;
+
+    fn value_parser() -> Self::Parser {
+        fn parse(s: String) -> Result<BdAddr, AddressParseError> {
+            s.parse()
+        }
+        StringValueParser::new().try_map(parse as fn(_) -> _)
+    }
+}
+
+/// `<address type>/<address>` as [`Address::from_str`](std::str::FromStr::from_str).
+/// (e.g. `public/00:1a:7d:da:71:13`, `random/53:03:8c:bc:bd:82` or `bredr/00:1a:7d:da:71:13`)
+impl ValueParserFactory for Address {
+    type Parser =
+        TryMapValueParser<StringValueParser, fn(String) -> Result<Self, AddressFromStrError>>;
+
+    fn value_parser() -> Self::Parser {
+        fn parse(s: String) -> Result<Address, AddressFromStrError> {
+            s.parse()
+        }
+        StringValueParser::new().try_map(parse as fn(_) -> _)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::error::ErrorKind;
+    use clap::{value_parser, Arg, Command};
+
+    fn cmd() -> Command {
+        Command::new("test")
+            .arg(
+                Arg::new("address")
+                    .long("address")
+                    .value_parser(value_parser!(BdAddr)),
+            )
+            .arg(
+                Arg::new("address-type")
+                    .long("address-type")
+                    .value_parser(value_parser!(AddressType)),
+            )
+            .arg(
+                Arg::new("peer")
+                    .long("peer")
+                    .value_parser(value_parser!(Address)),
+            )
+    }
+
+    #[test]
+    fn test_bd_addr() {
+        let m = cmd()
+            .try_get_matches_from(["test", "--address", "00:1A:7D:DA:71:13"])
+            .unwrap();
+        assert_eq!(
+            Some(&BdAddr([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00])),
+            m.get_one::<BdAddr>("address")
+        );
+
+        let err = cmd()
+            .try_get_matches_from(["test", "--address", "00:1a:7d:da:71"])
+            .unwrap_err();
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+        assert!(err
+            .to_string()
+            .contains("wrong group count (expected 6, found 5) at offset 14"));
+    }
+
+    #[test]
+    fn test_address_type() {
+        for (arg, ty) in [
+            ("public", AddressType::LePublic),
+            ("random", AddressType::LeRandom),
+            ("bredr", AddressType::BrEdr),
+        ] {
+            let m = cmd()
+                .try_get_matches_from(["test", "--address-type", arg])
+                .unwrap();
+            assert_eq!(Some(&ty), m.get_one::<AddressType>("address-type"));
+        }
+
+        for arg in ["le_public", "le-public", "br-edr"] {
+            let err = cmd()
+                .try_get_matches_from(["test", "--address-type", arg])
+                .unwrap_err();
+            assert_eq!(ErrorKind::InvalidValue, err.kind(), "{}", arg);
+        }
+    }
+
+    #[test]
+    fn test_address() {
+        let m = cmd()
+            .try_get_matches_from(["test", "--peer", "random/53:03:8c:bc:bd:82"])
+            .unwrap();
+        assert_eq!(
+            Some(&Address::le_random_from([
+                0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53
+            ])),
+            m.get_one::<Address>("peer")
+        );
+
+        for (arg, msg) in [
+            ("53:03:8c:bc:bd:82", "expect <address type>/<address>"),
+            ("le/53:03:8c:bc:bd:82", "Unknown address type. (\"le\")"),
+            (
+                "public/53:03:8c:bc:bd",
+                "wrong group count (expected 6, found 5) at offset 21",
+            ),
+        ] {
+            let err = cmd()
+                .try_get_matches_from(["test", "--peer", arg])
+                .unwrap_err();
+            assert_eq!(ErrorKind::ValueValidation, err.kind());
+            assert!(err.to_string().contains(msg), "{}", err);
+        }
+    }
+}
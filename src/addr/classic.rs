@@ -0,0 +1,324 @@
+//! NAP / UAP / LAP parts of BR/EDR address.
+//!
+//! ref BLUETOOTH CORE SPECIFICATION | Vol 2, Part B | 1.2 BLUETOOTH DEVICE ADDRESSING
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use super::{hex_digit, parse_groups_of, AddressParseError, AddressParseErrorKind, BdAddr};
+
+/// Hex digits of NAP, UAP and LAP in classic form.
+const WIDTHS: [usize; 3] = [4, 2, 6];
+
+/// LAP is wider than 24 bits.
+#[derive(Debug)]
+pub struct InvalidLap(u32);
+
+impl fmt::Display for InvalidLap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid LAP. (expect: up to 0xffffff, but 0x{:x})",
+            self.0
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidLap {}
+
+/// Lower Address Part. (24 bits)
+///
+/// This is also Inquiry Access Code (IAC) of HCI_Inquiry and HCI_Write_Current_IAC_LAP.
+///
+/// ```
+/// use bdaddr::{BdAddr, Lap};
+///
+/// let addr = "00:1a:7d:9e:8b:33".parse::<BdAddr>().unwrap();
+/// assert_eq!(Lap::GIAC, addr.lap());
+/// assert_eq!("9e8b33", Lap::GIAC.to_string());
+/// assert_eq!([0x33, 0x8b, 0x9e], Lap::GIAC.to_le_bytes()); // HCI_Inquiry LAP parameter
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lap(u32);
+
+impl Lap {
+    /// General/Unlimited Inquiry Access Code.
+    pub const GIAC: Self = Self(0x9e8b33);
+
+    /// Limited Dedicated Inquiry Access Code.
+    pub const LIAC: Self = Self(0x9e8b00);
+
+    /// Construct from bytes in little-endian (wire) order.
+    pub const fn from_le_bytes(b: [u8; 3]) -> Self {
+        Self(u32::from_le_bytes([b[0], b[1], b[2], 0]))
+    }
+
+    /// Bytes in little-endian (wire) order.
+    pub const fn to_le_bytes(self) -> [u8; 3] {
+        let [b0, b1, b2, _] = self.0.to_le_bytes();
+        [b0, b1, b2]
+    }
+}
+
+impl From<Lap> for u32 {
+    fn from(v: Lap) -> Self {
+        v.0
+    }
+}
+
+impl TryFrom<u32> for Lap {
+    type Error = InvalidLap;
+
+    fn try_from(v: u32) -> Result<Self, Self::Error> {
+        if v > 0xff_ffff {
+            return Err(InvalidLap(v));
+        }
+        Ok(Self(v))
+    }
+}
+
+/// 6 hex digits. (e.g. `9e8b33`)
+impl fmt::Display for Lap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:06x}", self.0)
+    }
+}
+
+impl fmt::Debug for Lap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// Exactly 6 hex digits. (case-insensitive)
+impl FromStr for Lap {
+    type Err = AddressParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |kind, pos| Err(AddressParseError::new(kind, 0, pos));
+        let mut v = 0;
+        for (pos, c) in s.bytes().enumerate() {
+            let d = match hex_digit(c) {
+                Some(d) => d as u32,
+                None => return err(AddressParseErrorKind::InvalidDigit, pos),
+            };
+            if pos == 6 {
+                return err(AddressParseErrorKind::GroupTooLong, pos);
+            }
+            v = v << 4 | d;
+        }
+        match s.len() {
+            0 => err(AddressParseErrorKind::Empty, 0),
+            n if n < 6 => err(AddressParseErrorKind::GroupTooShort, n),
+            _ => Ok(Self(v)),
+        }
+    }
+}
+
+impl BdAddr {
+    /// Construct from NAP, UAP and LAP.
+    ///
+    /// `lap` wider than 24 bits is rejected, rather than masked. (same as [`Lap::try_from`])
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = BdAddr::from_nap_uap_lap(0x001a, 0x7d, 0xda7113).unwrap();
+    /// assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+    /// assert!(BdAddr::from_nap_uap_lap(0x001a, 0x7d, 0x0100_0000).is_err());
+    /// ```
+    pub fn from_nap_uap_lap(nap: u16, uap: u8, lap: u32) -> Result<Self, InvalidLap> {
+        let [l0, l1, l2] = Lap::try_from(lap)?.to_le_bytes();
+        let [n0, n1] = nap.to_le_bytes();
+        Ok(Self([l0, l1, l2, uap, n0, n1]))
+    }
+
+    /// Get Non-significant Address Part. (16 bits, most significant 2 octets in display order)
+    ///
+    /// e.g. `0x001a` of `00:1a:7d:da:71:13`. These are the last 2 octets in wire order.
+    pub fn nap(&self) -> u16 {
+        u16::from_le_bytes([self.0[4], self.0[5]])
+    }
+
+    /// Get Upper Address Part. (8 bits, the 3rd octet in display order)
+    ///
+    /// e.g. `0x7d` of `00:1a:7d:da:71:13`. This is the 4th octet in wire order.
+    pub fn uap(&self) -> u8 {
+        self.0[3]
+    }
+
+    /// Get Lower Address Part. (24 bits, least significant 3 octets in display order)
+    ///
+    /// e.g. `da7113` of `00:1a:7d:da:71:13`. These are the first 3 octets in wire order.
+    pub fn lap(&self) -> Lap {
+        Lap::from_le_bytes([self.0[0], self.0[1], self.0[2]])
+    }
+
+    /// Parse classic `NAP-UAP-LAP` form. (e.g. `001a-7d-da7113`, case-insensitive)
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = BdAddr::from_classic_str("001a-7d-da7113").unwrap();
+    /// assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+    /// ```
+    pub fn from_classic_str(s: &str) -> Result<Self, AddressParseError> {
+        parse_groups_of(s.as_bytes(), 0, &WIDTHS, b'-', &[], hex_digit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nap_uap_lap() {
+        let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+        assert_eq!(0x001a, addr.nap());
+        assert_eq!(0x7d, addr.uap());
+        assert_eq!(0xda7113, u32::from(addr.lap()));
+
+        for (nap, uap, lap, s) in [
+            (0x0000, 0x00, 0x000000, "00:00:00:00:00:00"),
+            (0xffff, 0xff, 0xffffff, "ff:ff:ff:ff:ff:ff"),
+            (0x8000, 0x00, 0x000000, "80:00:00:00:00:00"),
+            (0x0000, 0x80, 0x000000, "00:00:80:00:00:00"),
+            (0x0000, 0x00, 0x800000, "00:00:00:80:00:00"),
+            (0x0001, 0x01, 0x000001, "00:01:01:00:00:01"),
+            (0x9e8b, 0x33, 0x9e8b33, "9e:8b:33:9e:8b:33"),
+        ] {
+            let addr = BdAddr::from_nap_uap_lap(nap, uap, lap).unwrap();
+            assert_eq!(s, addr.to_string());
+            assert_eq!(addr, s.parse().unwrap());
+            assert_eq!(
+                (nap, uap, lap),
+                (addr.nap(), addr.uap(), u32::from(addr.lap()))
+            );
+        }
+
+        for lap in [0x0100_0000, 0x8000_0000, u32::MAX] {
+            let err = BdAddr::from_nap_uap_lap(0, 0, lap).unwrap_err();
+            assert_eq!(
+                format!("Invalid LAP. (expect: up to 0xffffff, but 0x{:x})", lap),
+                err.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_lap() {
+        assert_eq!(0x9e8b33, u32::from(Lap::GIAC));
+        assert_eq!(0x9e8b00, u32::from(Lap::LIAC));
+        assert_eq!(Lap::GIAC, Lap::try_from(0x9e8b33).unwrap());
+        assert_eq!([0x00, 0x8b, 0x9e], Lap::LIAC.to_le_bytes());
+        assert_eq!(Lap::LIAC, Lap::from_le_bytes([0x00, 0x8b, 0x9e]));
+
+        let addr = BdAddr::from_nap_uap_lap(0x001a, 0x7d, 0x9e8b00).unwrap();
+        assert_eq!(Lap::LIAC, addr.lap());
+
+        for v in [0, 1, 0x800000, 0xffffff] {
+            let lap = Lap::try_from(v).unwrap();
+            assert_eq!(v, u32::from(lap));
+            assert_eq!(lap, Lap::from_le_bytes(lap.to_le_bytes()));
+            assert_eq!(lap, lap.to_string().parse().unwrap());
+        }
+        let err = Lap::try_from(0x0100_0000).unwrap_err();
+        assert_eq!(
+            "Invalid LAP. (expect: up to 0xffffff, but 0x1000000)",
+            err.to_string()
+        );
+
+        assert_eq!("9e8b33", Lap::GIAC.to_string());
+        assert_eq!("9e8b00", format!("{:?}", Lap::LIAC));
+        assert_eq!("000001", Lap::try_from(1).unwrap().to_string());
+        for s in ["9e8b33", "9E8B33", "9e8B33"] {
+            assert_eq!(Lap::GIAC, s.parse().unwrap(), "{:?}", s);
+        }
+        for (s, message) in [
+            ("", "empty group 0 at offset 0"),
+            ("9e8b3", "group 0 too short at offset 5"),
+            ("9e8b333", "group 0 too long at offset 6"),
+            ("0x9e8b33", "invalid hex digit in group 0 at offset 1"),
+            ("9e8b3g", "invalid hex digit in group 0 at offset 5"),
+            ("+9e8b33", "invalid hex digit in group 0 at offset 0"),
+        ] {
+            let err = s.parse::<Lap>().unwrap_err();
+            assert_eq!(message, err.to_string(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_classic_str() {
+        for (s, expect) in [
+            ("0011-22-334455", "00:11:22:33:44:55"),
+            ("001a-7d-da7113", "00:1a:7d:da:71:13"),
+            ("001A-7D-DA7113", "00:1a:7d:da:71:13"),
+            ("ffff-ff-ffffff", "ff:ff:ff:ff:ff:ff"),
+        ] {
+            let addr = BdAddr::from_classic_str(s).unwrap();
+            assert_eq!(expect, addr.to_string(), "{:?}", s);
+        }
+
+        use AddressParseErrorKind::*;
+        for (s, kind, message) in [
+            ("", Empty, "empty group 0 at offset 0"),
+            (
+                "011-22-334455",
+                GroupTooShort,
+                "group 0 too short at offset 3",
+            ),
+            (
+                "00110-22-334455",
+                GroupTooLong,
+                "group 0 too long at offset 4",
+            ),
+            (
+                "0011-2-334455",
+                GroupTooShort,
+                "group 1 too short at offset 6",
+            ),
+            (
+                "0011-22-3344556",
+                GroupTooLong,
+                "group 2 too long at offset 14",
+            ),
+            (
+                "0011-22-33445G",
+                InvalidDigit,
+                "invalid hex digit in group 2 at offset 13",
+            ),
+            (
+                "0011:22:334455",
+                InvalidDigit,
+                "invalid hex digit in group 0 at offset 4",
+            ),
+            (
+                "0011-22",
+                WrongGroupCount {
+                    expected: 3,
+                    found: 2,
+                },
+                "wrong group count (expected 3, found 2) at offset 7",
+            ),
+            (
+                "0011-22-334455-",
+                WrongGroupCount {
+                    expected: 3,
+                    found: 4,
+                },
+                "wrong group count (expected 3, found 4) at offset 14",
+            ),
+            (
+                "00:11:22:33:44:55",
+                InvalidDigit,
+                "invalid hex digit in group 0 at offset 2",
+            ),
+        ] {
+            let err = BdAddr::from_classic_str(s).unwrap_err();
+            assert_eq!(kind, err.kind(), "{:?}", s);
+            assert_eq!(message, err.to_string(), "{:?}", s);
+        }
+    }
+}
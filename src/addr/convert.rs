@@ -0,0 +1,426 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use super::{
+    Address, AddressFromStrError, AddressParseError, BdAddr, NonResolvablePrivateAddress,
+    PublicDeviceAddress, RandomDeviceAddress, ResolvablePrivateAddress, StaticDeviceAddress,
+};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Conversion into [`BdAddr`] for generic APIs.
+///
+/// Implemented for `&str` / `String` (parsed as `00:1a:7d:da:71:13`), `[u8; 6]` (little-endian),
+/// [`BdAddr`], [`Address`] and the typed wrappers, and references of them.
+/// This trait is sealed, downstream crates can use but not implement it.
+///
+/// ```
+/// use bdaddr::{AddressParseError, BdAddr, TryIntoBdAddr};
+///
+/// fn is_blocked(addr: impl TryIntoBdAddr) -> Result<bool, AddressParseError> {
+///     let blocked: BdAddr = "00:1a:7d:da:71:13".parse()?;
+///     Ok(addr.try_into_bd_addr()? == blocked)
+/// }
+///
+/// assert!(is_blocked("00:1a:7d:da:71:13").unwrap());
+/// assert!(is_blocked([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]).unwrap());
+/// assert!(is_blocked(bdaddr::Address::bredr_from_str("00:1a:7d:da:71:13").unwrap()).unwrap());
+/// assert!(is_blocked("00:1a:7d").is_err());
+/// ```
+///
+/// Use `AsRef<BdAddr>` for borrowed values, which never fail.
+///
+/// ```
+/// use bdaddr::BdAddr;
+///
+/// fn oui<A: AsRef<BdAddr>>(addr: &A) -> [u8; 3] {
+///     addr.as_ref().oui()
+/// }
+///
+/// let addr = bdaddr::Address::le_public_from_str("00:1a:7d:da:71:13").unwrap();
+/// assert_eq!([0x00, 0x1a, 0x7d], oui(&addr));
+/// ```
+pub trait TryIntoBdAddr: private::Sealed {
+    /// Convert into [`BdAddr`].
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError>;
+}
+
+/// Conversion into [`Address`] for generic APIs.
+///
+/// Implemented for [`Address`] and the typed wrappers, references of them, and `&str` / `String`
+/// of `<address type>/<address>` as [`Address::from_str`](core::str::FromStr::from_str).
+/// [`BdAddr`] and bytes are not implemented, as they do not carry address type.
+/// This trait is sealed, downstream crates can use but not implement it.
+///
+/// ```
+/// use bdaddr::{Address, AddressFromStrError, AddressType, TryIntoAddress};
+///
+/// fn is_le(addr: impl TryIntoAddress) -> Result<bool, AddressFromStrError> {
+///     Ok(addr.try_into_address()?.address_type() != AddressType::BrEdr)
+/// }
+///
+/// assert!(is_le("random/53:03:8c:bc:bd:82").unwrap());
+/// assert!(!is_le(Address::bredr_from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00])).unwrap());
+/// assert!(is_le("53:03:8c:bc:bd:82").is_err());
+/// ```
+pub trait TryIntoAddress: private::Sealed {
+    /// Convert into [`Address`].
+    fn try_into_address(self) -> Result<Address, AddressFromStrError>;
+}
+
+impl private::Sealed for &str {}
+
+impl TryIntoBdAddr for &str {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        self.parse()
+    }
+}
+
+impl TryIntoAddress for &str {
+    fn try_into_address(self) -> Result<Address, AddressFromStrError> {
+        self.parse()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl private::Sealed for String {}
+
+#[cfg(feature = "alloc")]
+impl TryIntoBdAddr for String {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        self.parse()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryIntoAddress for String {
+    fn try_into_address(self) -> Result<Address, AddressFromStrError> {
+        self.parse()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl private::Sealed for &String {}
+
+#[cfg(feature = "alloc")]
+impl TryIntoBdAddr for &String {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        self.parse()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryIntoAddress for &String {
+    fn try_into_address(self) -> Result<Address, AddressFromStrError> {
+        self.parse()
+    }
+}
+
+impl private::Sealed for [u8; 6] {}
+
+impl TryIntoBdAddr for [u8; 6] {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        Ok(self.into())
+    }
+}
+
+impl private::Sealed for &[u8; 6] {}
+
+impl TryIntoBdAddr for &[u8; 6] {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        Ok(*BdAddr::from_ref(self))
+    }
+}
+
+impl private::Sealed for BdAddr {}
+
+impl TryIntoBdAddr for BdAddr {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        Ok(self)
+    }
+}
+
+impl private::Sealed for &BdAddr {}
+
+impl TryIntoBdAddr for &BdAddr {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        Ok(*self)
+    }
+}
+
+impl private::Sealed for Address {}
+
+impl TryIntoBdAddr for Address {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        Ok(self.into_bd_addr())
+    }
+}
+
+impl TryIntoAddress for Address {
+    fn try_into_address(self) -> Result<Address, AddressFromStrError> {
+        Ok(self)
+    }
+}
+
+impl private::Sealed for &Address {}
+
+impl TryIntoBdAddr for &Address {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        Ok(*self.as_ref())
+    }
+}
+
+impl TryIntoAddress for &Address {
+    fn try_into_address(self) -> Result<Address, AddressFromStrError> {
+        Ok(*self)
+    }
+}
+
+impl private::Sealed for PublicDeviceAddress {}
+
+impl TryIntoBdAddr for PublicDeviceAddress {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        Ok(self.0)
+    }
+}
+
+impl TryIntoAddress for PublicDeviceAddress {
+    fn try_into_address(self) -> Result<Address, AddressFromStrError> {
+        Ok(self.into())
+    }
+}
+
+impl private::Sealed for &PublicDeviceAddress {}
+
+impl TryIntoBdAddr for &PublicDeviceAddress {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        Ok(self.0)
+    }
+}
+
+impl TryIntoAddress for &PublicDeviceAddress {
+    fn try_into_address(self) -> Result<Address, AddressFromStrError> {
+        Ok((*self).into())
+    }
+}
+
+impl private::Sealed for RandomDeviceAddress {}
+
+impl TryIntoBdAddr for RandomDeviceAddress {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        Ok(self.into_parts().1)
+    }
+}
+
+impl TryIntoAddress for RandomDeviceAddress {
+    fn try_into_address(self) -> Result<Address, AddressFromStrError> {
+        Ok(self.into())
+    }
+}
+
+impl private::Sealed for &RandomDeviceAddress {}
+
+impl TryIntoBdAddr for &RandomDeviceAddress {
+    fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+        Ok(*self.as_ref())
+    }
+}
+
+impl TryIntoAddress for &RandomDeviceAddress {
+    fn try_into_address(self) -> Result<Address, AddressFromStrError> {
+        Ok((*self).into())
+    }
+}
+
+macro_rules! impl_for_random_kind {
+    ($($ty:ident),*) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl TryIntoBdAddr for $ty {
+                fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+                    Ok(self.0)
+                }
+            }
+
+            impl TryIntoAddress for $ty {
+                fn try_into_address(self) -> Result<Address, AddressFromStrError> {
+                    Ok(RandomDeviceAddress::from(self).into())
+                }
+            }
+
+            impl private::Sealed for &$ty {}
+
+            impl TryIntoBdAddr for &$ty {
+                fn try_into_bd_addr(self) -> Result<BdAddr, AddressParseError> {
+                    Ok(self.0)
+                }
+            }
+
+            impl TryIntoAddress for &$ty {
+                fn try_into_address(self) -> Result<Address, AddressFromStrError> {
+                    Ok(RandomDeviceAddress::from(*self).into())
+                }
+            }
+        )*
+    };
+}
+
+impl_for_random_kind!(
+    NonResolvablePrivateAddress,
+    ResolvablePrivateAddress,
+    StaticDeviceAddress
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AddressType;
+
+    const BYTES: [u8; 6] = [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00];
+
+    fn bd_addr(addr: impl TryIntoBdAddr) -> Result<BdAddr, AddressParseError> {
+        addr.try_into_bd_addr()
+    }
+
+    fn address(addr: impl TryIntoAddress) -> Result<Address, AddressFromStrError> {
+        addr.try_into_address()
+    }
+
+    /// Through the impl for reference.
+    fn bd_addr_ref<T>(addr: &T) -> Result<BdAddr, AddressParseError>
+    where
+        for<'a> &'a T: TryIntoBdAddr,
+    {
+        addr.try_into_bd_addr()
+    }
+
+    /// Through the impl for reference.
+    fn address_ref<T>(addr: &T) -> Result<Address, AddressFromStrError>
+    where
+        for<'a> &'a T: TryIntoAddress,
+    {
+        addr.try_into_address()
+    }
+
+    fn borrowed<A: AsRef<BdAddr>>(addr: &A) -> BdAddr {
+        *addr.as_ref()
+    }
+
+    #[test]
+    fn test_try_into_bd_addr() {
+        let expected = BdAddr::from(BYTES);
+        let s = "00:1a:7d:da:71:13".to_string();
+        assert_eq!(expected, bd_addr("00:1a:7d:da:71:13").unwrap());
+        assert_eq!(expected, bd_addr(&s).unwrap());
+        assert_eq!(expected, bd_addr(s).unwrap());
+        assert_eq!(expected, bd_addr(BYTES).unwrap());
+        let b: &[u8; 6] = &BYTES;
+        assert_eq!(expected, bd_addr(b).unwrap());
+        assert_eq!(expected, bd_addr_ref(&expected).unwrap());
+        assert_eq!(expected, bd_addr(expected).unwrap());
+
+        let addr = Address::bredr_from(BYTES);
+        assert_eq!(expected, bd_addr_ref(&addr).unwrap());
+        assert_eq!(expected, bd_addr(addr).unwrap());
+        let addr = PublicDeviceAddress::from(BYTES);
+        assert_eq!(expected, bd_addr_ref(&addr).unwrap());
+        assert_eq!(expected, bd_addr(addr).unwrap());
+        let addr = RandomDeviceAddress::from(BYTES);
+        assert_eq!(expected, bd_addr_ref(&addr).unwrap());
+        assert_eq!(expected, bd_addr(addr).unwrap());
+
+        let b = [0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53];
+        let addr = ResolvablePrivateAddress::try_from(b).unwrap();
+        assert_eq!(BdAddr::from(b), bd_addr_ref(&addr).unwrap());
+        assert_eq!(BdAddr::from(b), bd_addr(addr).unwrap());
+        let addr = NonResolvablePrivateAddress::try_from(BYTES).unwrap();
+        assert_eq!(expected, bd_addr_ref(&addr).unwrap());
+        assert_eq!(expected, bd_addr(addr).unwrap());
+        let b = [0x13, 0x71, 0xda, 0x7d, 0x1a, 0xc0];
+        let addr = StaticDeviceAddress::try_from(b).unwrap();
+        assert_eq!(BdAddr::from(b), bd_addr_ref(&addr).unwrap());
+        assert_eq!(BdAddr::from(b), bd_addr(addr).unwrap());
+
+        assert!(bd_addr("00:1a:7d:da:71").is_err());
+        assert!(bd_addr("public/00:1a:7d:da:71:13").is_err());
+    }
+
+    #[test]
+    fn test_try_into_address() {
+        for (s, ty) in [
+            ("public/00:1a:7d:da:71:13", AddressType::LePublic),
+            ("random/00:1a:7d:da:71:13", AddressType::LeRandom),
+            ("bredr/00:1a:7d:da:71:13", AddressType::BrEdr),
+        ] {
+            let expected = BdAddr::from(BYTES).to_addr(ty);
+            assert_eq!(expected, address(s).unwrap());
+            let owned = s.to_string();
+            assert_eq!(expected, address(&owned).unwrap());
+            assert_eq!(expected, address(owned).unwrap());
+            assert_eq!(expected, address_ref(&expected).unwrap());
+            assert_eq!(expected, address(expected).unwrap());
+        }
+
+        let addr = PublicDeviceAddress::from(BYTES);
+        assert_eq!(Address::le_public_from(BYTES), address_ref(&addr).unwrap());
+        assert_eq!(Address::le_public_from(BYTES), address(addr).unwrap());
+        let addr = RandomDeviceAddress::from(BYTES);
+        assert_eq!(Address::le_random_from(BYTES), address_ref(&addr).unwrap());
+        assert_eq!(Address::le_random_from(BYTES), address(addr).unwrap());
+
+        let b = [0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53];
+        let addr = ResolvablePrivateAddress::try_from(b).unwrap();
+        assert_eq!(Address::le_random_from(b), address_ref(&addr).unwrap());
+        assert_eq!(Address::le_random_from(b), address(addr).unwrap());
+        let addr = NonResolvablePrivateAddress::try_from(BYTES).unwrap();
+        assert_eq!(Address::le_random_from(BYTES), address_ref(&addr).unwrap());
+        assert_eq!(Address::le_random_from(BYTES), address(addr).unwrap());
+        let b = [0x13, 0x71, 0xda, 0x7d, 0x1a, 0xc0];
+        let addr = StaticDeviceAddress::try_from(b).unwrap();
+        assert_eq!(Address::le_random_from(b), address_ref(&addr).unwrap());
+        assert_eq!(Address::le_random_from(b), address(addr).unwrap());
+
+        assert!(matches!(
+            address("00:1a:7d:da:71:13"),
+            Err(AddressFromStrError::Format)
+        ));
+        assert!(matches!(
+            address("le-public/00:1a:7d:da:71:13"),
+            Err(AddressFromStrError::UnknownAddressType(..))
+        ));
+        assert!(matches!(
+            address("br-edr/00:1a:7d:da:71:13"),
+            Err(AddressFromStrError::UnknownAddressType(..))
+        ));
+        assert!(matches!(
+            address("public/00:1a:7d"),
+            Err(AddressFromStrError::AddressParseError(..))
+        ));
+    }
+
+    #[test]
+    fn test_as_ref() {
+        let expected = BdAddr::from(BYTES);
+        assert_eq!(expected, borrowed(&expected));
+        assert_eq!(expected, borrowed(&Address::le_public_from(BYTES)));
+        assert_eq!(expected, borrowed(&PublicDeviceAddress::from(BYTES)));
+        assert_eq!(expected, borrowed(&RandomDeviceAddress::from(BYTES)));
+        assert_eq!(
+            expected,
+            borrowed(&NonResolvablePrivateAddress::try_from(BYTES).unwrap())
+        );
+        let b = [0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53];
+        assert_eq!(
+            BdAddr::from(b),
+            borrowed(&ResolvablePrivateAddress::try_from(b).unwrap())
+        );
+        let b = [0x13, 0x71, 0xda, 0x7d, 0x1a, 0xc0];
+        assert_eq!(
+            BdAddr::from(b),
+            borrowed(&StaticDeviceAddress::try_from(b).unwrap())
+        );
+    }
+}
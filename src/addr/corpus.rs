@@ -0,0 +1,222 @@
+use alloc::vec::Vec;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::matches::ah;
+use super::{Address, RandomDeviceAddress, StaticDeviceAddress, RANDOM_PART_MASK};
+
+/// Proportions of address kinds emitted by [`CorpusGenerator`].
+///
+/// Each field is a weight relative to the sum. (e.g. percentage)
+#[cfg_attr(docsrs, doc(cfg(all(feature = "testing", feature = "rand"))))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorpusMix {
+    /// LE Public Device Address with one of [`CorpusGenerator::OUIS`].
+    pub public: u32,
+    /// Static Device Address.
+    pub static_random: u32,
+    /// Resolvable Private Address from one of [`CorpusGenerator::irks`].
+    pub resolvable: u32,
+}
+
+impl Default for CorpusMix {
+    /// 60% public, 20% static, 20% resolvable.
+    fn default() -> Self {
+        Self {
+            public: 60,
+            static_random: 20,
+            resolvable: 20,
+        }
+    }
+}
+
+/// Reproducible generator of realistic address corpus, for load testing.
+///
+/// ```
+/// use bdaddr::{Address, CorpusGenerator, CorpusMix, RandomDeviceAddress};
+///
+/// let gen = CorpusGenerator::new(42, CorpusMix::default(), 8);
+/// let irks = gen.irks().to_vec();
+/// for addr in gen.take(100) {
+///     if let Address::LeRandom(RandomDeviceAddress::Resolvable(rpa)) = addr {
+///         assert!(irks.iter().any(|irk| rpa.matches(irk)));
+///     }
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(all(feature = "testing", feature = "rand"))))]
+#[derive(Debug, Clone)]
+pub struct CorpusGenerator {
+    rng: StdRng,
+    mix: CorpusMix,
+    irks: Vec<[u8; 16]>,
+}
+
+impl CorpusGenerator {
+    /// Common OUIs of public addresses. (display order)
+    pub const OUIS: &'static [[u8; 3]] = &[
+        [0xb8, 0x27, 0xeb], // Raspberry Pi Foundation
+        [0xdc, 0xa6, 0x32], // Raspberry Pi Trading
+        [0x24, 0x0a, 0xc4], // Espressif
+        [0xa4, 0xc1, 0x38], // Telink Semiconductor
+        [0x00, 0x1c, 0xb3], // Apple
+        [0x00, 0x1b, 0x21], // Intel
+    ];
+
+    /// Construct with seed, mix, and the number of synthetic IRKs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all weights of `mix` are zero, or `irks` is zero while `mix.resolvable` is not.
+    pub fn new(seed: u64, mix: CorpusMix, irks: usize) -> Self {
+        assert!(
+            mix.public as u64 + mix.static_random as u64 + mix.resolvable as u64 > 0,
+            "empty mix"
+        );
+        assert!(mix.resolvable == 0 || irks > 0, "no IRKs for resolvable");
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let irks = (0..irks).map(|_| rng.gen()).collect();
+        Self { rng, mix, irks }
+    }
+
+    /// Synthetic IRKs of emitted Resolvable Private Addresses. (same byte order as [`ResolvablePrivateAddress::matches`](crate::ResolvablePrivateAddress::matches))
+    pub fn irks(&self) -> &[[u8; 16]] {
+        &self.irks
+    }
+
+    fn public(&mut self) -> Address {
+        let oui = Self::OUIS[self.rng.gen_range(0..Self::OUIS.len())];
+        let nic: [u8; 3] = self.rng.gen();
+        Address::le_public_from([nic[0], nic[1], nic[2], oui[2], oui[1], oui[0]])
+    }
+
+    fn static_random(&mut self) -> Address {
+        let bits = self.rng.gen_range(1..RANDOM_PART_MASK);
+        match StaticDeviceAddress::from_random_part(bits) {
+            Ok(addr) => Address::LeRandom(RandomDeviceAddress::Static(addr)),
+            Err(..) => unreachable!(),
+        }
+    }
+
+    fn resolvable(&mut self) -> Address {
+        let irk = self.irks[self.rng.gen_range(0..self.irks.len())];
+        // 22 random bits of prand, neither all zeros nor all ones
+        let bits = self.rng.gen_range(1..(1u32 << 22) - 1);
+        let prand = [bits as u8, (bits >> 8) as u8, (bits >> 16) as u8 | 0x40];
+        let hash = ah(&irk, prand).expect("AES backend failed");
+        Address::le_random_from([hash[0], hash[1], hash[2], prand[0], prand[1], prand[2]])
+    }
+}
+
+impl Iterator for CorpusGenerator {
+    type Item = Address;
+
+    /// Never returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the AES backend fails.
+    fn next(&mut self) -> Option<Self::Item> {
+        let CorpusMix {
+            public,
+            static_random,
+            resolvable,
+        } = self.mix;
+        let n = self
+            .rng
+            .gen_range(0..public as u64 + static_random as u64 + resolvable as u64);
+        let addr = if n < public as u64 {
+            self.public()
+        } else if n < public as u64 + static_random as u64 {
+            self.static_random()
+        } else {
+            self.resolvable()
+        };
+        Some(addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RandomDeviceAddressKind;
+
+    #[test]
+    fn test_mix() {
+        let mix = CorpusMix {
+            public: 50,
+            static_random: 30,
+            resolvable: 20,
+        };
+        let mut gen = CorpusGenerator::new(1, mix, 16);
+        assert_eq!(16, gen.irks().len());
+
+        let n = 10000;
+        let (mut public, mut static_random, mut resolvable) = (0, 0, 0);
+        for addr in gen.by_ref().take(n) {
+            match addr {
+                Address::LePublic(addr) => {
+                    let mut b = <[u8; 6]>::from(*addr.as_ref());
+                    b.reverse();
+                    assert!(CorpusGenerator::OUIS.contains(&[b[0], b[1], b[2]]));
+                    public += 1;
+                }
+                Address::LeRandom(RandomDeviceAddress::Static(..)) => static_random += 1,
+                Address::LeRandom(RandomDeviceAddress::Resolvable(..)) => resolvable += 1,
+                x => panic!("{:?}", x),
+            }
+        }
+        // within 3% of the weights
+        assert!((4700..=5300).contains(&public), "{}", public);
+        assert!((2700..=3300).contains(&static_random), "{}", static_random);
+        assert!((1700..=2300).contains(&resolvable), "{}", resolvable);
+
+        let mix = CorpusMix {
+            public: 0,
+            static_random: 1,
+            resolvable: 0,
+        };
+        let gen = CorpusGenerator::new(1, mix, 0);
+        assert!(gen
+            .take(1000)
+            .all(|addr| matches!(addr, Address::LeRandom(RandomDeviceAddress::Static(..)))));
+    }
+
+    #[test]
+    fn test_resolvable() {
+        let mix = CorpusMix {
+            public: 0,
+            static_random: 0,
+            resolvable: 1,
+        };
+        let mut gen = CorpusGenerator::new(2, mix, 4);
+        let irks = gen.irks().to_vec();
+        for addr in gen.by_ref().take(1000) {
+            let addr = match addr {
+                Address::LeRandom(RandomDeviceAddress::Resolvable(addr)) => addr,
+                x => panic!("{:?}", x),
+            };
+            assert_eq!(1, irks.iter().filter(|irk| addr.matches(irk)).count());
+        }
+    }
+
+    #[test]
+    fn test_seed() {
+        let a = CorpusGenerator::new(3, CorpusMix::default(), 8);
+        let b = CorpusGenerator::new(3, CorpusMix::default(), 8);
+        assert_eq!(a.irks(), b.irks());
+        assert!(a.take(1000).eq(b.take(1000)));
+
+        let a = CorpusGenerator::new(3, CorpusMix::default(), 8);
+        let b = CorpusGenerator::new(4, CorpusMix::default(), 8);
+        assert_ne!(a.irks(), b.irks());
+        assert!(!a.take(1000).eq(b.take(1000)));
+
+        let gen = CorpusGenerator::new(3, CorpusMix::default(), 8);
+        assert!(gen.take(1000).all(|addr| match addr {
+            Address::LeRandom(addr) => addr.kind() != RandomDeviceAddressKind::Unknown,
+            _ => true,
+        }));
+    }
+}
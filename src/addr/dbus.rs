@@ -0,0 +1,169 @@
+//! D-Bus (BlueZ) represents Bluetooth Device Address as uppercase string. (e.g. `00:1A:7D:DA:71:13`)
+
+use std::fmt;
+
+use dbus::arg::{prop_cast, Append, Arg, ArgType, Get, Iter, IterAppend, PropMap};
+use dbus::Signature;
+
+use super::{Address, AddressParseError, BdAddr, VariantParseError};
+
+/// Error for [`Address::from_dbus_props`]
+#[derive(Debug)]
+pub enum DbusAddressError {
+    /// Property is missing or not a string.
+    Property(&'static str),
+
+    /// `Address` property is not a valid address.
+    AddressParseError(AddressParseError),
+
+    /// Unknown `AddressType` property.
+    UnknownAddressType(VariantParseError),
+}
+
+impl fmt::Display for DbusAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Property(name) => write!(f, "Missing or non-string property. ({})", name),
+            Self::AddressParseError(e) => e.fmt(f),
+            Self::UnknownAddressType(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for DbusAddressError {}
+
+impl From<AddressParseError> for DbusAddressError {
+    fn from(e: AddressParseError) -> Self {
+        Self::AddressParseError(e)
+    }
+}
+
+impl From<VariantParseError> for DbusAddressError {
+    fn from(e: VariantParseError) -> Self {
+        Self::UnknownAddressType(e)
+    }
+}
+
+impl Arg for BdAddr {
+    const ARG_TYPE: ArgType = ArgType::String;
+
+    fn signature() -> Signature<'static> {
+        <String as Arg>::signature()
+    }
+}
+
+impl Append for BdAddr {
+    fn append_by_ref(&self, i: &mut IterAppend) {
+        i.append(self.format_with(Some(':'), true).to_string())
+    }
+}
+
+/// Not a valid address string is reported as type mismatch by [`Iter::read`].
+impl<'a> Get<'a> for BdAddr {
+    fn get(i: &mut Iter<'a>) -> Option<Self> {
+        i.get::<&str>()?.parse().ok()
+    }
+}
+
+impl Address {
+    /// Construct from `Address` and `AddressType` properties of `org.bluez.Device1`.
+    ///
+    /// `AddressType` is mapped as [`Address::from_bluez_device`].
+    pub fn from_dbus_props(props: &PropMap) -> Result<Self, DbusAddressError> {
+        let addr = prop_cast::<String>(props, "Address")
+            .ok_or(DbusAddressError::Property("Address"))?
+            .parse::<BdAddr>()?;
+        let ty = prop_cast::<String>(props, "AddressType")
+            .ok_or(DbusAddressError::Property("AddressType"))?;
+        Ok(Self::from_bluez_address_type(addr, ty)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbus::arg::{RefArg, Variant};
+    use dbus::Message;
+
+    fn props(addr: &str, ty: &str) -> PropMap {
+        let mut props = PropMap::new();
+        props.insert(
+            "Address".into(),
+            Variant(Box::new(addr.to_string()) as Box<dyn RefArg>),
+        );
+        props.insert(
+            "AddressType".into(),
+            Variant(Box::new(ty.to_string()) as Box<dyn RefArg>),
+        );
+        props
+    }
+
+    #[test]
+    fn test_arg() {
+        let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+        let msg = Message::new_signal("/", "org.example", "Test")
+            .unwrap()
+            .append1(addr);
+        assert_eq!("s", &*BdAddr::signature());
+        assert_eq!("00:1A:7D:DA:71:13", msg.read1::<&str>().unwrap());
+        assert_eq!(addr, msg.read1::<BdAddr>().unwrap());
+    }
+
+    #[test]
+    fn test_arg_err() {
+        let msg = Message::new_signal("/", "org.example", "Test")
+            .unwrap()
+            .append1("not an address");
+        assert!(msg.read1::<BdAddr>().is_err());
+
+        let msg = Message::new_signal("/", "org.example", "Test")
+            .unwrap()
+            .append1(0u32);
+        let err = msg.read1::<BdAddr>().unwrap_err();
+        assert_eq!(ArgType::UInt32, err.found_arg_type());
+    }
+
+    #[test]
+    fn test_props() {
+        let addr = Address::from_dbus_props(&props("00:1A:7D:DA:71:13", "public")).unwrap();
+        assert_eq!(
+            Address::le_public_from_str("00:1a:7d:da:71:13").unwrap(),
+            addr
+        );
+
+        let addr = Address::from_dbus_props(&props("F5:44:33:22:11:00", "random")).unwrap();
+        assert_eq!(
+            Address::le_random_from_str("f5:44:33:22:11:00").unwrap(),
+            addr
+        );
+    }
+
+    #[test]
+    fn test_props_err() {
+        let err = Address::from_dbus_props(&PropMap::new()).unwrap_err();
+        assert_eq!(
+            "Missing or non-string property. (Address)",
+            &format!("{}", err)
+        );
+
+        let err = Address::from_dbus_props(&props("00:1A:7D:DA:71", "public")).unwrap_err();
+        assert_eq!(
+            "wrong group count (expected 6, found 5) at offset 14",
+            &format!("{}", err)
+        );
+
+        let err = Address::from_dbus_props(&props("00:1A:7D:DA:71:13", "br/edr")).unwrap_err();
+        assert_eq!("Unknown address type. (\"br/edr\")", &format!("{}", err));
+
+        let mut props = props("00:1A:7D:DA:71:13", "public");
+        props.insert(
+            "AddressType".into(),
+            Variant(Box::new(1u8) as Box<dyn RefArg>),
+        );
+        let err = Address::from_dbus_props(&props).unwrap_err();
+        assert_eq!(
+            "Missing or non-string property. (AddressType)",
+            &format!("{}", err)
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Diesel column representation.
+//!
+//! [`BdAddr`] is mapped to `Text` as lowercase string (e.g. `00:1a:7d:da:71:13`),
+//! or `Binary` as 6 bytes in the same (wire) order as [`BdAddr`].
+//!
+//! Serializing to `Text` requires `diesel-postgres` or `diesel-sqlite` feature.
+//!
+//! ```ignore
+//! diesel::table! {
+//!     device (addr) {
+//!         addr -> Text,
+//!         name -> Text,
+//!     }
+//! }
+//!
+//! #[derive(Queryable, Insertable)]
+//! #[diesel(table_name = device)]
+//! struct Device {
+//!     addr: bdaddr::BdAddr,
+//!     name: String,
+//! }
+//! ```
+
+use std::convert::TryFrom;
+
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{Binary, Text};
+
+use super::BdAddr;
+
+impl<DB: Backend> FromSql<Text, DB> for BdAddr
+where
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let s = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+        s.parse().map_err(|e| format!("{} ({:?})", e, s).into())
+    }
+}
+
+impl<DB: Backend> FromSql<Binary, DB> for BdAddr
+where
+    Vec<u8>: FromSql<Binary, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let b = <Vec<u8> as FromSql<Binary, DB>>::from_sql(bytes)?;
+        Ok(Self::try_from(&b[..])?)
+    }
+}
+
+impl<DB: Backend> ToSql<Binary, DB> for BdAddr
+where
+    [u8]: ToSql<Binary, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        <[u8] as ToSql<Binary, DB>>::to_sql(&self.0[..], out)
+    }
+}
+
+#[cfg(feature = "diesel-postgres")]
+impl ToSql<Text, diesel::pg::Pg> for BdAddr {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::pg::Pg>) -> serialize::Result {
+        use std::io::Write;
+
+        write!(out, "{}", self)?;
+        Ok(serialize::IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl ToSql<Text, diesel::sqlite::Sqlite> for BdAddr {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::sqlite::Sqlite>) -> serialize::Result {
+        out.set_value(self.to_string());
+        Ok(serialize::IsNull::No)
+    }
+}
+
+#[cfg(all(test, feature = "diesel-sqlite"))]
+mod tests {
+    use std::error::Error;
+
+    use super::*;
+    use diesel::prelude::*;
+
+    diesel::table! {
+        device (addr) {
+            addr -> Text,
+            raw -> Binary,
+        }
+    }
+
+    #[derive(Debug, PartialEq, Queryable, Insertable)]
+    #[diesel(table_name = device)]
+    struct Device {
+        addr: BdAddr,
+        raw: BdAddr,
+    }
+
+    fn cause(err: &diesel::result::Error) -> &dyn Error {
+        let mut err: &dyn Error = err;
+        while let Some(source) = err.source() {
+            err = source;
+        }
+        err
+    }
+
+    fn setup() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        diesel::sql_query(
+            "CREATE TABLE device (addr TEXT PRIMARY KEY NOT NULL, raw BLOB NOT NULL)",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut conn = setup();
+        let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+        let dev = Device { addr, raw: addr };
+        diesel::insert_into(device::table)
+            .values(&dev)
+            .execute(&mut conn)
+            .unwrap();
+
+        let found = device::table
+            .filter(device::addr.eq(&addr))
+            .first::<Device>(&mut conn)
+            .unwrap();
+        assert_eq!(dev, found);
+
+        let (s, raw) = device::table
+            .select((device::addr, device::raw))
+            .first::<(String, Vec<u8>)>(&mut conn)
+            .unwrap();
+        assert_eq!("00:1a:7d:da:71:13", s);
+        assert_eq!(vec![0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00], raw);
+    }
+
+    #[test]
+    fn test_err() {
+        let mut conn = setup();
+        diesel::sql_query("INSERT INTO device VALUES ('00:1a:7d:da:71', x'1371da7d1a')")
+            .execute(&mut conn)
+            .unwrap();
+
+        let err = device::table
+            .select(device::addr)
+            .first::<BdAddr>(&mut conn)
+            .unwrap_err();
+        assert_eq!(
+            "wrong group count (expected 6, found 5) at offset 14 (\"00:1a:7d:da:71\")",
+            &format!("{}", cause(&err))
+        );
+
+        let err = device::table
+            .select(device::raw)
+            .first::<BdAddr>(&mut conn)
+            .unwrap_err();
+        assert_eq!(
+            "Invalid address length. (expect: 6, but 5)",
+            &format!("{}", cause(&err))
+        );
+    }
+}
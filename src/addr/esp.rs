@@ -0,0 +1,161 @@
+//! NimBLE `ble_addr_t` holds `val` in the same (wire) order as [`BdAddr`].
+//! That is reversed relative to the display order.
+//!
+//! Conversions take the raw `(type, val)` pair, or [`ble_addr_t`] of the same layout as NimBLE headers.
+//! (see [platform integrations](crate#platform-integrations))
+//!
+//! ```ignore
+//! use bdaddr::Address;
+//! use esp32_nimble::BLEAddress;
+//!
+//! let addr: BLEAddress = ...;
+//! let addr = Address::from_esp(addr.addr_type() as u8, addr.as_le_bytes())?;
+//! ```
+
+#![allow(non_camel_case_types)]
+
+use core::convert::TryFrom;
+
+use super::{Address, BdAddr, UnknownAddressType, UnsupportedAddressType};
+
+const BLE_ADDR_PUBLIC: u8 = 0x00;
+const BLE_ADDR_RANDOM: u8 = 0x01;
+const BLE_ADDR_PUBLIC_ID: u8 = 0x02;
+const BLE_ADDR_RANDOM_ID: u8 = 0x03;
+
+/// `ble_addr_t` (nimble/ble.h)
+///
+/// Same layout as `esp_idf_sys::ble_addr_t`, this can be cast from pointer of GAP event fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ble_addr_t {
+    pub type_: u8,
+    pub val: [u8; 6],
+}
+
+/// Same as [`Address::from_esp`].
+impl TryFrom<ble_addr_t> for Address {
+    type Error = UnknownAddressType;
+
+    fn try_from(v: ble_addr_t) -> Result<Self, Self::Error> {
+        Self::from_esp(v.type_, v.val)
+    }
+}
+
+/// Same as [`Address::to_esp`].
+impl TryFrom<Address> for ble_addr_t {
+    type Error = UnsupportedAddressType;
+
+    fn try_from(v: Address) -> Result<Self, Self::Error> {
+        let (type_, val) = v.to_esp()?;
+        Ok(Self { type_, val })
+    }
+}
+
+impl Address {
+    /// Construct from NimBLE `ble_addr_t` (`type`, `val`).
+    ///
+    /// | `type` | Address |
+    /// |--------|---------|
+    /// | 0x00 `BLE_ADDR_PUBLIC` | LE Public Device Address |
+    /// | 0x01 `BLE_ADDR_RANDOM` | LE Random Device Address |
+    /// | 0x02 `BLE_ADDR_PUBLIC_ID` (`RPA_PUBLIC`) | LE Public Device Address (identity) |
+    /// | 0x03 `BLE_ADDR_RANDOM_ID` (`RPA_RANDOM`) | LE Random Device Address (identity) |
+    pub fn from_esp(ty: u8, val: [u8; 6]) -> Result<Self, UnknownAddressType> {
+        let addr = BdAddr::from(val);
+        match ty {
+            BLE_ADDR_PUBLIC | BLE_ADDR_PUBLIC_ID => Ok(addr.to_le_public_addr()),
+            BLE_ADDR_RANDOM | BLE_ADDR_RANDOM_ID => Ok(addr.to_le_random_addr()),
+            x => Err(UnknownAddressType(x)),
+        }
+    }
+
+    /// Convert to NimBLE `ble_addr_t` (`type`, `val`).
+    ///
+    /// BR/EDR Address is not supported.
+    pub fn to_esp(&self) -> Result<(u8, [u8; 6]), UnsupportedAddressType> {
+        let ty = match self {
+            Self::LePublic(..) => BLE_ADDR_PUBLIC,
+            Self::LeRandom(..) => BLE_ADDR_RANDOM,
+            Self::BrEdr(..) => return Err(UnsupportedAddressType(self.address_type())),
+        };
+        Ok((ty, self.into_bd_addr().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RandomDeviceAddress;
+
+    #[test]
+    fn test_byte_order() {
+        // `I (1234) NimBLE: addr=f5:44:33:22:11:00` for `val` [00 11 22 33 44 f5]
+        let val = [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5];
+        let addr = Address::from_esp(0x01, val).unwrap();
+        assert_eq!("f5:44:33:22:11:00", addr.to_string());
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Static(..))
+        ));
+        assert_eq!((0x01, val), addr.to_esp().unwrap());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let val = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55];
+        for (ty, expect) in [
+            (0x00, Address::le_public_from(val)),
+            (0x01, Address::le_random_from(val)),
+        ] {
+            let addr = Address::from_esp(ty, val).unwrap();
+            assert_eq!(expect, addr);
+            assert_eq!((ty, val), addr.to_esp().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_identity() {
+        let val = [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5];
+        let addr = Address::from_esp(0x02, val).unwrap();
+        assert_eq!(Address::le_public_from(val), addr);
+        assert_eq!((0x00, val), addr.to_esp().unwrap());
+
+        let addr = Address::from_esp(0x03, val).unwrap();
+        assert_eq!(Address::le_random_from(val), addr);
+        assert_eq!((0x01, val), addr.to_esp().unwrap());
+    }
+
+    #[test]
+    fn test_ble_addr_t() {
+        assert_eq!(7, core::mem::size_of::<ble_addr_t>());
+        assert_eq!(1, core::mem::align_of::<ble_addr_t>());
+
+        // `disc.addr` of BLE_GAP_EVENT_DISC logged as `type=1 val=82 bd bc 8c 03 53` (53:03:8c:bc:bd:82)
+        let raw: [u8; 7] = [0x01, 0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53];
+        let v = unsafe { (raw.as_ptr() as *const ble_addr_t).read() };
+        let addr = Address::try_from(v).unwrap();
+        assert_eq!("53:03:8c:bc:bd:82", addr.to_string());
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Resolvable(..))
+        ));
+        assert_eq!(v, ble_addr_t::try_from(addr).unwrap());
+
+        let v = ble_addr_t {
+            type_: 0x04,
+            val: [0; 6],
+        };
+        assert!(Address::try_from(v).is_err());
+        assert!(ble_addr_t::try_from(Address::bredr_from([0; 6])).is_err());
+    }
+
+    #[test]
+    fn test_err() {
+        let err = Address::from_esp(0x04, [0; 6]).unwrap_err();
+        assert_eq!("Unknown address type. (0x04)", &format!("{}", err));
+
+        let err = Address::bredr_from([0; 6]).to_esp().unwrap_err();
+        assert_eq!("Unsupported address type. (BrEdr)", &format!("{}", err));
+    }
+}
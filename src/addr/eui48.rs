@@ -0,0 +1,78 @@
+//! `eui48::MacAddress` holds the octets in display order. (see [byte order](BdAddr#byte-order) of [`BdAddr`])
+
+use eui48::MacAddress;
+#[cfg(feature = "alloc")]
+use eui48::MacAddressFormat;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use super::{Address, BdAddr};
+
+impl From<BdAddr> for MacAddress {
+    fn from(v: BdAddr) -> Self {
+        let mut b = v.0;
+        b.reverse();
+        Self::new(b)
+    }
+}
+
+impl From<MacAddress> for BdAddr {
+    fn from(v: MacAddress) -> Self {
+        let mut b = v.to_array();
+        b.reverse();
+        Self(b)
+    }
+}
+
+impl Address {
+    /// Format by `eui48::MacAddressFormat`.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_eui48_string(&self, fmt: MacAddressFormat) -> String {
+        MacAddress::from(self.into_bd_addr()).to_string(fmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]);
+        let mac = MacAddress::from(addr);
+        assert_eq!([0xf5, 0x44, 0x33, 0x22, 0x11, 0x00], mac.to_array());
+        assert_eq!(addr.to_string(), mac.to_hex_string());
+        assert_eq!(addr, BdAddr::from(mac));
+    }
+
+    #[test]
+    fn test_parse() {
+        let mac = MacAddress::parse_str("F5-44-33-22-11-00").unwrap();
+        let addr = "f5:44:33:22:11:00".parse::<BdAddr>().unwrap();
+        assert_eq!(addr, BdAddr::from(mac));
+        assert_eq!(mac, MacAddress::from(addr));
+    }
+
+    #[test]
+    fn test_to_eui48_string() {
+        let addr = Address::le_random_from_str("f5:44:33:22:11:00").unwrap();
+        assert_eq!(
+            "f5-44-33-22-11-00",
+            addr.to_eui48_string(MacAddressFormat::Canonical)
+        );
+        assert_eq!(
+            "f5:44:33:22:11:00",
+            addr.to_eui48_string(MacAddressFormat::HexString)
+        );
+        assert_eq!(
+            "f544.3322.1100",
+            addr.to_eui48_string(MacAddressFormat::DotNotation)
+        );
+        assert_eq!(
+            "0xf54433221100",
+            addr.to_eui48_string(MacAddressFormat::Hexadecimal)
+        );
+    }
+}
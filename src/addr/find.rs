@@ -0,0 +1,149 @@
+use core::iter;
+use core::ops::Range;
+
+use super::{hex_digit, BdAddr};
+
+/// `HH:HH:HH:HH:HH:HH`
+const LEN: usize = 17;
+
+/// BlueZ D-Bus object path prefix for underscore form. (e.g. `/org/bluez/hci0/dev_00_1A_7D_DA_71_13`)
+const DEV_PREFIX: &[u8] = b"dev_";
+
+/// Exactly six groups of two hex digits at `pos`, returns separator and address.
+fn candidate(text: &[u8], pos: usize) -> Option<(u8, BdAddr)> {
+    let s = text.get(pos..pos + LEN)?;
+    let sep = s[2];
+    if !matches!(sep, b':' | b'-' | b'_') {
+        return None;
+    }
+    let mut addr = [0; 6];
+    for (i, b) in addr.iter_mut().rev().enumerate() {
+        let group = &s[i * 3..i * 3 + 2];
+        if i < 5 && s[i * 3 + 2] != sep {
+            return None;
+        }
+        *b = hex_digit(group[0])? << 4 | hex_digit(group[1])?;
+    }
+    Some((sep, addr.into()))
+}
+
+fn is_boundary_before(text: &[u8], pos: usize, sep: u8) -> bool {
+    let before = &text[..pos];
+    if sep == b'_' {
+        return before.ends_with(DEV_PREFIX);
+    }
+    match before.last() {
+        None => true,
+        Some(b) => !b.is_ascii_alphanumeric() && *b != sep,
+    }
+}
+
+fn is_boundary_after(text: &[u8], end: usize, sep: u8) -> bool {
+    match text.get(end..) {
+        Some([]) | None => true,
+        // trailing separator as punctuation (e.g. `00:1a:7d:da:71:13: connected`), but not 7th group or `::`
+        Some([b, rest @ ..]) if *b == sep => match rest.first() {
+            Some(n) => !n.is_ascii_hexdigit() && *n != sep,
+            None => true,
+        },
+        Some([b, ..]) => !b.is_ascii_alphanumeric(),
+    }
+}
+
+/// Scan text for embedded addresses, and iterate byte ranges and addresses.
+///
+/// Colon-separated (`00:1a:7d:da:71:13`), dash-separated (`00-1A-7D-DA-71-13`) and BlueZ object path
+/// (`dev_00_1A_7D_DA_71_13`) forms are detected, in either case.
+/// A match must be exactly six groups of two hex digits with the same separator, and must not be
+/// adjacent to alphanumeric characters or the separator, so that IPv6 addresses, UUIDs or
+/// timestamps are not reported. The range covers the address only. (without `dev_`)
+///
+/// ```
+/// let log = "[12:00:01] connected 00:1A:7D:DA:71:13 (rssi -60)\n\
+///            path /org/bluez/hci0/dev_53_03_8C_BC_BD_82\n";
+/// let found: Vec<_> = bdaddr::find_addresses(log).collect();
+/// assert_eq!(2, found.len());
+/// assert_eq!(21..38, found[0].0);
+/// assert_eq!("00:1A:7D:DA:71:13", &log[found[0].0.clone()]);
+/// assert_eq!("53:03:8c:bc:bd:82", found[1].1.to_string());
+/// ```
+pub fn find_addresses(text: &str) -> impl Iterator<Item = (Range<usize>, BdAddr)> + '_ {
+    let text = text.as_bytes();
+    let mut pos = 0;
+    iter::from_fn(move || {
+        while pos + LEN <= text.len() {
+            let start = pos;
+            if let Some((sep, addr)) = candidate(text, start) {
+                let end = start + LEN;
+                if is_boundary_before(text, start, sep) && is_boundary_after(text, end, sep) {
+                    pos = end;
+                    return Some((start..end, addr));
+                }
+            }
+            pos += 1;
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = "\
+2024-01-02 12:34:56.789 bluetoothd[1234]: src/device.c:device_connect() addr 00:1A:7D:DA:71:13
+2024-01-02 12:34:57.001 kernel: Bluetooth: hci0: link 53-03-8c-bc-bd-82 established
+signal path=/org/bluez/hci0/dev_C5_44_33_22_11_00; interface=org.bluez.Device1
+uuid 0000180d-0000-1000-8000-00805f9b34fb, short 12:34:56, ipv6 fe80::1a:2b:3c:4d:5e:6f
+seven 00:11:22:33:44:55:66 mixed 00:11-22:33:44:55 long 000:11:22:33:44:55 bare 001a7dda7113
+x00:11:22:33:44:55 00:11:22:33:44:55g [aa:bb:cc:dd:ee:ff] done: 01:23:45:67:89:ab: ok
+dev_00_11_22 not_00_11_22_33_44_55 ipv6-ish 00:11:22:33:44:55::1 \u{e9}00:11:22:33:44:55\u{e9}";
+
+    #[test]
+    fn test_find_addresses() {
+        let found = find_addresses(FIXTURE)
+            .map(|(range, addr)| (&FIXTURE[range], addr.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("00:1A:7D:DA:71:13", "00:1a:7d:da:71:13".to_string()),
+                ("53-03-8c-bc-bd-82", "53:03:8c:bc:bd:82".to_string()),
+                ("C5_44_33_22_11_00", "c5:44:33:22:11:00".to_string()),
+                ("aa:bb:cc:dd:ee:ff", "aa:bb:cc:dd:ee:ff".to_string()),
+                ("01:23:45:67:89:ab", "01:23:45:67:89:ab".to_string()),
+                ("00:11:22:33:44:55", "00:11:22:33:44:55".to_string()),
+            ],
+            found
+        );
+    }
+
+    #[test]
+    fn test_find_addresses_ranges() {
+        let text = "00:11:22:33:44:55 66:77:88:99:aa:bb";
+        let found = find_addresses(text).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (0..17, BdAddr::from([0x55, 0x44, 0x33, 0x22, 0x11, 0x00])),
+                (18..35, BdAddr::from([0xbb, 0xaa, 0x99, 0x88, 0x77, 0x66])),
+            ],
+            found
+        );
+        let bytes = <[u8; 6]>::from(found[0].1);
+        assert_eq!([0x55, 0x44, 0x33, 0x22, 0x11, 0x00], bytes);
+    }
+
+    #[test]
+    fn test_find_addresses_empty() {
+        for text in [
+            "",
+            "00:11:22:33:44:5",
+            "00:11:22:33:44:5g",
+            "dev_00:11:22:33:44:55x",
+            "00_11_22_33_44_55",
+            "fe80::0011:2233:4455:6677",
+            "\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}",
+        ] {
+            assert_eq!(0, find_addresses(text).count(), "{:?}", text);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use core::fmt;
+
+use super::{
+    Address, BdAddr, NonResolvablePrivateAddress, PublicDeviceAddress, RandomDeviceAddress,
+    ResolvablePrivateAddress, StaticDeviceAddress,
+};
+
+/// 12 hex digits and 5 separators of up to 4 bytes.
+const MAX_LEN: usize = 12 + 5 * 4;
+
+/// [`fmt::Display`] adapter of [`BdAddr::format_with`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatWith {
+    addr: BdAddr,
+    sep: Option<char>,
+    uppercase: bool,
+}
+
+impl fmt::Display for FormatWith {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = if self.uppercase {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+        let mut buf = [0; MAX_LEN];
+        let mut len = 0;
+        for (i, v) in self.addr.0.iter().rev().enumerate() {
+            if let (Some(sep), true) = (self.sep, i > 0) {
+                len += sep.encode_utf8(&mut buf[len..]).len();
+            }
+            buf[len] = digits[(v >> 4) as usize];
+            buf[len + 1] = digits[(v & 0xf) as usize];
+            len += 2;
+        }
+        f.pad(core::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)?)
+    }
+}
+
+impl BdAddr {
+    /// Display with the separator and the case. (e.g. `55-44-33-22-11-00`, `554433221100`)
+    ///
+    /// `None` separator is 12 contiguous hex digits. Width, fill and alignment are honored as [`fmt::Display`].
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+    /// assert_eq!("001a7dda7113", addr.format_with(None, false).to_string());
+    /// assert_eq!("00_1A_7D_DA_71_13", addr.format_with(Some('_'), true).to_string());
+    /// assert_eq!("00:1A:7D:DA:71:13", format!("{}", addr.format_with(Some(':'), true)));
+    /// ```
+    pub fn format_with(&self, sep: Option<char>, uppercase: bool) -> FormatWith {
+        FormatWith {
+            addr: *self,
+            sep,
+            uppercase,
+        }
+    }
+}
+
+/// Delegate `format_with` to inner [`BdAddr`].
+macro_rules! impl_format_with {
+    ($($ty:ty),*) => {
+        $(
+            impl $ty {
+                /// Display with the separator and the case. (same as [`BdAddr::format_with`])
+                pub fn format_with(&self, sep: Option<char>, uppercase: bool) -> FormatWith {
+                    AsRef::<BdAddr>::as_ref(self).format_with(sep, uppercase)
+                }
+            }
+        )*
+    };
+}
+
+impl_format_with!(
+    PublicDeviceAddress,
+    NonResolvablePrivateAddress,
+    ResolvablePrivateAddress,
+    StaticDeviceAddress,
+    RandomDeviceAddress,
+    Address
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_with() {
+        let addr = BdAddr::from([0x0f, 0xa0, 0xdd, 0xcc, 0xbb, 0x0a]);
+        for (sep, uppercase, expect) in [
+            (None, false, "0abbccdda00f"),
+            (None, true, "0ABBCCDDA00F"),
+            (Some(':'), false, "0a:bb:cc:dd:a0:0f"),
+            (Some(':'), true, "0A:BB:CC:DD:A0:0F"),
+            (Some('-'), false, "0a-bb-cc-dd-a0-0f"),
+            (Some('_'), true, "0A_BB_CC_DD_A0_0F"),
+            (Some('·'), false, "0a·bb·cc·dd·a0·0f"),
+            (Some('\u{1f4e1}'), true, "0A📡BB📡CC📡DD📡A0📡0F"),
+        ] {
+            assert_eq!(
+                expect,
+                addr.format_with(sep, uppercase).to_string(),
+                "{:?}",
+                sep
+            );
+        }
+        assert_eq!(
+            addr.to_string(),
+            addr.format_with(Some(':'), false).to_string()
+        );
+        assert_eq!(
+            "  0abbccdda00f",
+            format!("{:>14}", addr.format_with(None, false))
+        );
+    }
+
+    #[test]
+    fn test_delegate() {
+        let b = [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5];
+        for addr in [
+            Address::bredr_from(b),
+            Address::le_public_from(b),
+            Address::le_random_from(b),
+        ] {
+            assert_eq!(
+                "F5-44-33-22-11-00",
+                addr.format_with(Some('-'), true).to_string()
+            );
+        }
+        let addr = StaticDeviceAddress::new(b).unwrap();
+        assert_eq!("f54433221100", addr.format_with(None, false).to_string());
+        assert_eq!(
+            "f5_44_33_22_11_00",
+            RandomDeviceAddress::Static(addr)
+                .format_with(Some('_'), false)
+                .to_string()
+        );
+        assert_eq!(
+            "F54433221100",
+            PublicDeviceAddress::new(b)
+                .format_with(None, true)
+                .to_string()
+        );
+    }
+}
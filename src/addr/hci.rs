@@ -0,0 +1,532 @@
+//! HCI command and event parameters bearing addresses.
+//!
+//! ```
+//! use bdaddr::{hci, Address};
+//!
+//! let peer = Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]);
+//! assert_eq!(
+//!     [0x01, 0x00, 0x11, 0x22, 0x33, 0x44, 0xf5],
+//!     hci::peer_address_params(&peer).unwrap()
+//! );
+//! ```
+
+use core::convert::TryFrom;
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::{
+    Address, AddressType, BdAddr, InvalidBitsForAddressType, RandomDeviceAddress,
+    ResolvablePrivateAddress, UnknownAddressType, UnsupportedAddressType,
+};
+
+pub mod classic;
+pub mod vendor;
+
+/// Error for parsing HCI event parameters.
+#[derive(Debug)]
+pub enum ParseError {
+    /// Unexpected event code.
+    UnexpectedEvent(u8),
+
+    /// Unexpected LE Meta event subevent code.
+    UnexpectedSubevent(u8),
+
+    /// Parameters too short. (offset of the truncated field, report or response)
+    Truncated(usize),
+
+    /// Unknown address type code.
+    UnknownAddressType(UnknownAddressType),
+
+    /// Address does not match address type code.
+    InvalidBitsForAddressType(InvalidBitsForAddressType),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEvent(code) => write!(f, "Unexpected event code. (0x{:02x})", code),
+            Self::UnexpectedSubevent(code) => {
+                write!(f, "Unexpected subevent code. (0x{:02x})", code)
+            }
+            Self::Truncated(offset) => {
+                write!(f, "Truncated event parameters. (at offset {})", offset)
+            }
+            Self::UnknownAddressType(e) => e.fmt(f),
+            Self::InvalidBitsForAddressType(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+impl From<UnknownAddressType> for ParseError {
+    fn from(e: UnknownAddressType) -> Self {
+        Self::UnknownAddressType(e)
+    }
+}
+
+impl From<InvalidBitsForAddressType> for ParseError {
+    fn from(e: InvalidBitsForAddressType) -> Self {
+        Self::InvalidBitsForAddressType(e)
+    }
+}
+
+/// Error for [`set_random_address_params`].
+#[derive(Debug)]
+pub enum SetRandomAddressError {
+    /// Not a LE Random Device Address.
+    UnsupportedAddressType(UnsupportedAddressType),
+
+    /// Most significant two bits are reserved. (`0b10`)
+    ReservedBits(BdAddr),
+}
+
+impl fmt::Display for SetRandomAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedAddressType(e) => e.fmt(f),
+            Self::ReservedBits(addr) => write!(f, "Reserved bits for random address. ({})", addr),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SetRandomAddressError {}
+
+impl From<UnsupportedAddressType> for SetRandomAddressError {
+    fn from(e: UnsupportedAddressType) -> Self {
+        Self::UnsupportedAddressType(e)
+    }
+}
+
+/// `Random_Address` parameter for HCI_LE_Set_Random_Address.
+///
+/// | Octet | Parameter                              |
+/// |-------|----------------------------------------|
+/// | 0..6  | `Random_Address` (little-endian order) |
+///
+/// Rejects addresses other than Static Device Address, Resolvable Private Address and
+/// Non-Resolvable Private Address.
+///
+/// ref BLUETOOTH CORE SPECIFICATION | Vol 4, Part E | 7.8.4 LE Set Random Address command
+pub fn set_random_address_params(addr: &Address) -> Result<[u8; 6], SetRandomAddressError> {
+    match addr {
+        Address::LeRandom(RandomDeviceAddress::Unknown(addr)) => {
+            Err(SetRandomAddressError::ReservedBits(*addr))
+        }
+        Address::LeRandom(..) => Ok(addr.into_bd_addr().into()),
+        _ => Err(UnsupportedAddressType(addr.address_type()).into()),
+    }
+}
+
+/// `Peer_Address_Type` and `Peer_Address` parameters.
+///
+/// | Octet | Parameter                                          |
+/// |-------|----------------------------------------------------|
+/// | 0     | `Peer_Address_Type` (`0x00` Public, `0x01` Random) |
+/// | 1..7  | `Peer_Address` (little-endian order)               |
+///
+/// Rejects BR/EDR Address.
+///
+/// ref BLUETOOTH CORE SPECIFICATION | Vol 4, Part E | 7.8.5 LE Set Advertising Parameters command
+///
+/// ref BLUETOOTH CORE SPECIFICATION | Vol 4, Part E | 7.8.12 LE Create Connection command
+pub fn peer_address_params(addr: &Address) -> Result<[u8; 7], UnsupportedAddressType> {
+    let ty = match addr.address_type() {
+        AddressType::LePublic => 0x00,
+        AddressType::LeRandom => 0x01,
+        ty @ AddressType::BrEdr => return Err(UnsupportedAddressType(ty)),
+    };
+    let b = <[u8; 6]>::from(addr.into_bd_addr());
+    Ok([ty, b[0], b[1], b[2], b[3], b[4], b[5]])
+}
+
+impl Address {
+    /// Encode as `Peer_Address_Type` and `Peer_Address` parameters. (same as [`peer_address_params`])
+    ///
+    /// | Octet | Parameter                                          |
+    /// |-------|----------------------------------------------------|
+    /// | 0     | `Peer_Address_Type` (`0x00` Public, `0x01` Random) |
+    /// | 1..7  | `Peer_Address` (little-endian order)               |
+    ///
+    /// BR/EDR Address is [`UnsupportedAddressType`], LE commands have no code for it.
+    ///
+    /// ```
+    /// use bdaddr::Address;
+    ///
+    /// let addr = Address::le_random_from_str("f5:44:33:22:11:00").unwrap();
+    /// let b = addr.to_hci_bytes().unwrap();
+    /// assert_eq!([0x01, 0x00, 0x11, 0x22, 0x33, 0x44, 0xf5], b);
+    /// assert_eq!(addr, Address::from_hci_bytes(b).unwrap());
+    /// ```
+    pub fn to_hci_bytes(&self) -> Result<[u8; 7], UnsupportedAddressType> {
+        peer_address_params(self)
+    }
+
+    /// Decode `Peer_Address_Type` and `Peer_Address` parameters.
+    ///
+    /// | Code   | Address                                     |
+    /// |--------|---------------------------------------------|
+    /// | `0x00` | LE Public Device Address                    |
+    /// | `0x01` | LE Random Device Address                    |
+    /// | `0x02` | Public Identity Address, as `0x00`          |
+    /// | `0x03` | Random (static) Identity Address, as `0x01` |
+    ///
+    /// LE Random Device Address is classified by the tag bits. Other codes (reserved, or event only
+    /// `0xFE`) are [`UnknownAddressType`].
+    ///
+    /// ref BLUETOOTH CORE SPECIFICATION | Vol 4, Part E | 7.8.12 LE Create Connection command
+    pub fn from_hci_bytes(b: [u8; 7]) -> Result<Self, UnknownAddressType> {
+        let [ty, b0, b1, b2, b3, b4, b5] = b;
+        let addr = [b0, b1, b2, b3, b4, b5];
+        match ty {
+            0x00 | 0x02 => Ok(Self::le_public_from(addr)),
+            0x01 | 0x03 => Ok(Self::le_random_from(addr)),
+            x => Err(UnknownAddressType(x)),
+        }
+    }
+}
+
+/// Address from HCI event `Address_Type` and `Address` parameters.
+///
+/// | Code   | Address                                                                    |
+/// |--------|----------------------------------------------------------------------------|
+/// | `0x00` | Public Device Address                                                      |
+/// | `0x01` | Random Device Address                                                      |
+/// | `0x02` | Public Identity Address (resolved by controller)                           |
+/// | `0x03` | Random (static) Identity Address (resolved by controller)                  |
+/// | `0xFE` | Resolvable Private Address, controller unable to resolve (direct address only) |
+///
+/// `0x02` and `0x03` are mapped to their identity address, `0xFE` to [`RandomDeviceAddress::Resolvable`].
+/// `0xFE` with other than Resolvable Private Address bits is rejected.
+///
+/// ref BLUETOOTH CORE SPECIFICATION | Vol 4, Part E | 7.7.65.11 LE Directed Advertising Report event
+pub fn address_from_hci(addr_type: u8, addr: [u8; 6]) -> Result<Address, ParseError> {
+    match addr_type {
+        0x00 | 0x02 => Ok(Address::le_public_from(addr)),
+        0x01 | 0x03 => Ok(Address::le_random_from(addr)),
+        0xFE => Ok(RandomDeviceAddress::from(ResolvablePrivateAddress::try_from(addr)?).into()),
+        x => Err(UnknownAddressType(x).into()),
+    }
+}
+
+/// Addresses in a report of LE Directed Advertising Report event.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DirectedReportAddresses {
+    /// `Address_Type` and `Address`.
+    pub advertiser: Address,
+
+    /// `Direct_Address_Type` and `Direct_Address`.
+    pub direct: Address,
+}
+
+/// Addresses from LE Directed Advertising Report event parameters. (starts with `Subevent_Code` `0x0B`)
+///
+/// Each report is 16 octets.
+///
+/// | Octet  | Parameter             |
+/// |--------|-----------------------|
+/// | 0      | `Event_Type`          |
+/// | 1      | `Address_Type`        |
+/// | 2..8   | `Address`             |
+/// | 8      | `Direct_Address_Type` |
+/// | 9..15  | `Direct_Address`      |
+/// | 15     | `RSSI`                |
+///
+/// Address types are mapped by [`address_from_hci`].
+///
+/// ref BLUETOOTH CORE SPECIFICATION | Vol 4, Part E | 7.7.65.11 LE Directed Advertising Report event
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn parse_directed_report_addresses(
+    params: &[u8],
+) -> Result<Vec<DirectedReportAddresses>, ParseError> {
+    const SUBEVENT: u8 = 0x0B;
+    const REPORT_LEN: usize = 16;
+
+    let (subevent, num_reports) = match params {
+        [subevent, num_reports, ..] => (*subevent, *num_reports as usize),
+        [] => return Err(ParseError::Truncated(0)),
+        [_] => return Err(ParseError::Truncated(1)),
+    };
+    if subevent != SUBEVENT {
+        return Err(ParseError::UnexpectedSubevent(subevent));
+    }
+
+    let mut reports = Vec::with_capacity(num_reports);
+    for i in 0..num_reports {
+        let offset = 2 + i * REPORT_LEN;
+        let r = params
+            .get(offset..offset + REPORT_LEN)
+            .and_then(|r| <[u8; REPORT_LEN]>::try_from(r).ok())
+            .ok_or(ParseError::Truncated(offset))?;
+        let [_, ty, a0, a1, a2, a3, a4, a5, direct_ty, d0, d1, d2, d3, d4, d5, _] = r;
+        reports.push(DirectedReportAddresses {
+            advertiser: address_from_hci(ty, [a0, a1, a2, a3, a4, a5])?,
+            direct: address_from_hci(direct_ty, [d0, d1, d2, d3, d4, d5])?,
+        });
+    }
+    Ok(reports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_random_address_params() {
+        // < HCI Command: LE Set Random Address (0x08|0x0005) plen 6
+        //         Address: 53:03:8C:BC:BD:82 (Resolvable)
+        let cmd = [0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53];
+        let addr = Address::le_random_from_str("53:03:8C:BC:BD:82").unwrap();
+        assert_eq!(cmd, set_random_address_params(&addr).unwrap());
+
+        // < HCI Command: LE Set Random Address (0x08|0x0005) plen 6
+        //         Address: F5:44:33:22:11:00 (Static)
+        let cmd = [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5];
+        let addr = Address::le_random_from_str("F5:44:33:22:11:00").unwrap();
+        assert_eq!(cmd, set_random_address_params(&addr).unwrap());
+
+        let addr = Address::le_public_from_str("53:03:8C:BC:BD:82").unwrap();
+        let err = set_random_address_params(&addr).unwrap_err();
+        assert_eq!("Unsupported address type. (LePublic)", &err.to_string());
+
+        let addr = Address::le_random_from_str("B5:44:33:22:11:00").unwrap();
+        let err = set_random_address_params(&addr).unwrap_err();
+        assert_eq!(
+            "Reserved bits for random address. (b5:44:33:22:11:00)",
+            &err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_peer_address_params() {
+        // < HCI Command: LE Create Connection (0x08|0x000d) plen 25
+        //         Scan interval: 60.000 msec (0x0060)
+        //         Scan window: 60.000 msec (0x0060)
+        //         Filter policy: White list is not used (0x00)
+        //         Peer address type: Random (0x01)
+        //         Peer address: F5:44:33:22:11:00 (Static)
+        //         Own address type: Public (0x00)
+        //         ...
+        let cmd = [
+            0x60, 0x00, 0x60, 0x00, 0x00, 0x01, 0x00, 0x11, 0x22, 0x33, 0x44, 0xf5, 0x00,
+        ];
+        let addr = Address::le_random_from_str("F5:44:33:22:11:00").unwrap();
+        assert_eq!(cmd[5..12], peer_address_params(&addr).unwrap());
+
+        // < HCI Command: LE Set Advertising Parameters (0x08|0x0006) plen 15
+        //         Min advertising interval: 1280.000 msec (0x0800)
+        //         Max advertising interval: 1280.000 msec (0x0800)
+        //         Type: Connectable directed - ADV_DIRECT_IND (high duty cycle) (0x01)
+        //         Own address type: Public (0x00)
+        //         Direct address type: Public (0x00)
+        //         Direct address: 00:1A:7D:DA:71:13 (OUI 00-1A-7D)
+        //         ...
+        let cmd = [
+            0x00, 0x08, 0x00, 0x08, 0x01, 0x00, 0x00, 0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00,
+        ];
+        let addr = Address::le_public_from_str("00:1A:7D:DA:71:13").unwrap();
+        assert_eq!(cmd[6..13], peer_address_params(&addr).unwrap());
+
+        let addr = Address::bredr_from_str("00:1A:7D:DA:71:13").unwrap();
+        let err = peer_address_params(&addr).unwrap_err();
+        assert_eq!("Unsupported address type. (BrEdr)", &err.to_string());
+    }
+
+    #[test]
+    fn test_hci_bytes() {
+        for (addr, ty) in [
+            (
+                Address::le_public_from_str("00:1a:7d:da:71:13").unwrap(),
+                0x00,
+            ),
+            (
+                Address::le_random_from_str("35:44:33:22:11:00").unwrap(),
+                0x01,
+            ),
+            (
+                Address::le_random_from_str("53:03:8c:bc:bd:82").unwrap(),
+                0x01,
+            ),
+            (
+                Address::le_random_from_str("f5:44:33:22:11:00").unwrap(),
+                0x01,
+            ),
+            (
+                Address::le_random_from_str("b5:44:33:22:11:00").unwrap(),
+                0x01,
+            ),
+        ] {
+            let b = addr.to_hci_bytes().unwrap();
+            assert_eq!(ty, b[0]);
+            assert_eq!(<[u8; 6]>::from(addr.into_bd_addr()), b[1..]);
+            assert_eq!(addr, Address::from_hci_bytes(b).unwrap());
+        }
+
+        let b = [0x03, 0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53];
+        assert!(matches!(
+            Address::from_hci_bytes(b).unwrap(),
+            Address::LeRandom(RandomDeviceAddress::Resolvable(..))
+        ));
+        let b = [0x02, 0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00];
+        assert_eq!(
+            Address::le_public_from_str("00:1a:7d:da:71:13").unwrap(),
+            Address::from_hci_bytes(b).unwrap()
+        );
+
+        for ty in [0x04, 0x7f, 0xfe, 0xff] {
+            let err = Address::from_hci_bytes([ty, 0, 0, 0, 0, 0, 0]).unwrap_err();
+            assert_eq!(
+                format!("Unknown address type. (0x{:02x})", ty),
+                err.to_string()
+            );
+        }
+
+        let err = Address::bredr_from([0; 6]).to_hci_bytes().unwrap_err();
+        assert_eq!("Unsupported address type. (BrEdr)", &err.to_string());
+    }
+
+    #[test]
+    fn test_address_from_hci() {
+        let b = [0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53];
+        assert_eq!(
+            Address::le_public_from(b),
+            address_from_hci(0x00, b).unwrap()
+        );
+        assert_eq!(
+            Address::le_random_from(b),
+            address_from_hci(0x01, b).unwrap()
+        );
+        assert_eq!(
+            Address::le_public_from(b),
+            address_from_hci(0x02, b).unwrap()
+        );
+        assert_eq!(
+            Address::le_random_from(b),
+            address_from_hci(0x03, b).unwrap()
+        );
+        assert_eq!(
+            Address::le_random_from(b),
+            address_from_hci(0xfe, b).unwrap()
+        );
+
+        let err = address_from_hci(0x04, b).unwrap_err();
+        assert_eq!("Unknown address type. (0x04)", &err.to_string());
+
+        let err = address_from_hci(0xfe, [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidBitsForAddressType(..)));
+    }
+
+    #[test]
+    fn test_parse_directed_report_addresses() {
+        // > HCI Event: LE Meta Event (0x3e) plen 34
+        //       LE Direct Advertising Report (0x0b)
+        //         Num reports: 2
+        //         Event type: Connectable directed - ADV_DIRECT_IND (0x01)
+        //         Address type: Public (0x00)
+        //         Address: 00:1A:7D:DA:71:13 (OUI 00-1A-7D)
+        //         Direct address type: Random (0x01)
+        //         Direct address: F5:44:33:22:11:00 (Static)
+        //         RSSI: -60 dBm (0xc4)
+        //         Event type: Connectable directed - ADV_DIRECT_IND (0x01)
+        //         Address type: Random (0x01)
+        //         Address: 75:44:33:22:11:00 (Resolvable)
+        //         Direct address type: Reserved (0xfe)
+        //         Direct address: 53:03:8C:BC:BD:82 (Resolvable)
+        //         RSSI: -70 dBm (0xba)
+        let params = [
+            0x0b, 0x02, //
+            0x01, 0x00, 0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00, //
+            0x01, 0x00, 0x11, 0x22, 0x33, 0x44, 0xf5, 0xc4, //
+            0x01, 0x01, 0x00, 0x11, 0x22, 0x33, 0x44, 0x75, //
+            0xfe, 0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53, 0xba, //
+        ];
+        let reports = parse_directed_report_addresses(&params).unwrap();
+        assert_eq!(
+            vec![
+                DirectedReportAddresses {
+                    advertiser: Address::le_public_from_str("00:1A:7D:DA:71:13").unwrap(),
+                    direct: Address::le_random_from_str("F5:44:33:22:11:00").unwrap(),
+                },
+                DirectedReportAddresses {
+                    advertiser: Address::le_random_from_str("75:44:33:22:11:00").unwrap(),
+                    direct: Address::le_random_from_str("53:03:8C:BC:BD:82").unwrap(),
+                },
+            ],
+            reports
+        );
+        assert!(matches!(
+            reports[1].direct,
+            Address::LeRandom(RandomDeviceAddress::Resolvable(..))
+        ));
+
+        let err = parse_directed_report_addresses(&params[..33]).unwrap_err();
+        assert_eq!(
+            "Truncated event parameters. (at offset 18)",
+            &err.to_string()
+        );
+        let err = parse_directed_report_addresses(&params[..1]).unwrap_err();
+        assert!(matches!(err, ParseError::Truncated(1)));
+
+        let mut params = params;
+        params[0] = 0x02;
+        let err = parse_directed_report_addresses(&params).unwrap_err();
+        assert_eq!("Unexpected subevent code. (0x02)", &err.to_string());
+    }
+
+    #[test]
+    fn test_parse_directed_report_addresses_adversarial() {
+        // deterministic pseudo random bytes
+        let mut seed = 0x2545_f491_u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) as u8
+        };
+        for len in 0..=64 {
+            for _ in 0..64 {
+                let mut params = (0..len).map(|_| next()).collect::<Vec<_>>();
+                if let Some(v) = params.first_mut() {
+                    *v = 0x0B;
+                }
+                if let Some(v) = params.get_mut(1) {
+                    *v %= 4;
+                }
+                let required = 2 + params.get(1).map_or(0, |n| *n as usize * 16);
+                match parse_directed_report_addresses(&params) {
+                    Ok(reports) => {
+                        assert_eq!(params[1] as usize, reports.len(), "{:02x?}", params);
+                        assert!(len >= required, "{:02x?}", params);
+                    }
+                    Err(ParseError::Truncated(..)) => assert!(len < required, "{:02x?}", params),
+                    Err(
+                        ParseError::UnknownAddressType(..)
+                        | ParseError::InvalidBitsForAddressType(..),
+                    ) => assert!(len >= 18, "{:02x?}", params),
+                    Err(err) => panic!("{:?} {:02x?}", err, params),
+                }
+            }
+        }
+        assert!(matches!(
+            parse_directed_report_addresses(&[0x0B, 0xFF]),
+            Err(ParseError::Truncated(..))
+        ));
+        for addr_type in 0..=0xFF {
+            let result = address_from_hci(addr_type, [0xFF; 6]);
+            match addr_type {
+                0x00..=0x03 => assert!(result.is_ok()),
+                0xFE => assert!(matches!(
+                    result,
+                    Err(ParseError::InvalidBitsForAddressType(..))
+                )),
+                x => assert!(matches!(
+                    result,
+                    Err(ParseError::UnknownAddressType(UnknownAddressType(y))) if x == y
+                )),
+            }
+        }
+    }
+}
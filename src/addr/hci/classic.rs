@@ -0,0 +1,221 @@
+//! BR/EDR HCI event parameters.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use super::ParseError;
+#[cfg(feature = "alloc")]
+use crate::addr::{Address, BdAddr};
+
+/// HCI_Inquiry_Result event code.
+pub const INQUIRY_RESULT: u8 = 0x02;
+
+/// HCI_Inquiry_Result_with_RSSI event code.
+pub const INQUIRY_RESULT_WITH_RSSI: u8 = 0x22;
+
+/// HCI_Extended_Inquiry_Result event code.
+pub const EXTENDED_INQUIRY_RESULT: u8 = 0x2F;
+
+/// Addresses from Inquiry Result, Inquiry Result with RSSI or Extended Inquiry Result event
+/// parameters. (after `Num_Responses`, without event code and length)
+///
+/// Each response starts with `BD_ADDR` followed by fields of the event.
+///
+/// | Event                      | Octets per response | Fields after `BD_ADDR`                                                 |
+/// |----------------------------|---------------------|------------------------------------------------------------------------|
+/// | Inquiry Result (`0x02`)    | 14                  | `Page_Scan_Repetition_Mode`, `Reserved` (2), `Class_Of_Device`, `Clock_Offset` |
+/// | with RSSI (`0x22`)         | 14                  | `Page_Scan_Repetition_Mode`, `Reserved`, `Class_Of_Device`, `Clock_Offset`, `RSSI` |
+/// | Extended (`0x2F`)          | 254                 | as with RSSI, and `Extended_Inquiry_Response` (240)                    |
+///
+/// Responses are laid out one after another as controllers do. Extended Inquiry Result always
+/// has a single response.
+///
+/// ref BLUETOOTH CORE SPECIFICATION | Vol 4, Part E | 7.7.2 Inquiry Result event
+///
+/// ref BLUETOOTH CORE SPECIFICATION | Vol 4, Part E | 7.7.33 Inquiry Result with RSSI event
+///
+/// ref BLUETOOTH CORE SPECIFICATION | Vol 4, Part E | 7.7.38 Extended Inquiry Result event
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn parse_inquiry_result_addresses(
+    event_code: u8,
+    payload: &[u8],
+) -> Result<Vec<Address>, ParseError> {
+    let response_len = match event_code {
+        INQUIRY_RESULT | INQUIRY_RESULT_WITH_RSSI => 14,
+        EXTENDED_INQUIRY_RESULT => 254,
+        x => return Err(ParseError::UnexpectedEvent(x)),
+    };
+
+    let num_responses = *payload.first().ok_or(ParseError::Truncated(0))? as usize;
+    let mut addrs = Vec::with_capacity(num_responses);
+    for i in 0..num_responses {
+        let offset = 1 + i * response_len;
+        let addr = match payload.get(offset..offset + response_len) {
+            Some([a0, a1, a2, a3, a4, a5, ..]) => [*a0, *a1, *a2, *a3, *a4, *a5],
+            _ => return Err(ParseError::Truncated(offset)),
+        };
+        addrs.push(BdAddr(addr).to_br_edr_addr());
+    }
+    Ok(addrs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addrs(v: &[&str]) -> Vec<Address> {
+        v.iter()
+            .map(|s| Address::bredr_from_str(s).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_inquiry_result() {
+        // > HCI Event: Inquiry Result (0x02) plen 29
+        //         Num responses: 2
+        //         Address: 00:1A:7D:DA:71:13 (OUI 00-1A-7D)
+        //         Page scan repetition mode: R1 (0x01)
+        //         Page period mode: P2 (0x02)
+        //         Page scan mode: Mandatory (0x00)
+        //         Class: 0x5a020c
+        //         Clock offset: 0x4e6b
+        //         Address: 00:11:22:33:44:55 (OUI 00-11-22)
+        //         Page scan repetition mode: R2 (0x02)
+        //         Page period mode: P0 (0x00)
+        //         Page scan mode: Mandatory (0x00)
+        //         Class: 0x240404
+        //         Clock offset: 0x1234
+        let payload = [
+            0x02, //
+            0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00, 0x01, 0x02, 0x00, 0x0c, 0x02, 0x5a, 0x6b,
+            0x4e, //
+            0x55, 0x44, 0x33, 0x22, 0x11, 0x00, 0x02, 0x00, 0x00, 0x04, 0x04, 0x24, 0x34,
+            0x12, //
+        ];
+        assert_eq!(
+            addrs(&["00:1A:7D:DA:71:13", "00:11:22:33:44:55"]),
+            parse_inquiry_result_addresses(INQUIRY_RESULT, &payload).unwrap()
+        );
+
+        let err = parse_inquiry_result_addresses(INQUIRY_RESULT, &payload[..28]).unwrap_err();
+        assert_eq!(
+            "Truncated event parameters. (at offset 15)",
+            &err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_inquiry_result_with_rssi() {
+        // > HCI Event: Inquiry Result with RSSI (0x22) plen 29
+        //         Num responses: 2
+        //         Address: 00:1A:7D:DA:71:13 (OUI 00-1A-7D)
+        //         Page scan repetition mode: R1 (0x01)
+        //         Page period mode: P2 (0x02)
+        //         Class: 0x5a020c
+        //         Clock offset: 0x4e6b
+        //         RSSI: -62 dBm (0xc2)
+        //         Address: 00:11:22:33:44:55 (OUI 00-11-22)
+        //         Page scan repetition mode: R2 (0x02)
+        //         Page period mode: P0 (0x00)
+        //         Class: 0x240404
+        //         Clock offset: 0x1234
+        //         RSSI: -80 dBm (0xb0)
+        let payload = [
+            0x02, //
+            0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00, 0x01, 0x02, 0x0c, 0x02, 0x5a, 0x6b, 0x4e,
+            0xc2, //
+            0x55, 0x44, 0x33, 0x22, 0x11, 0x00, 0x02, 0x00, 0x04, 0x04, 0x24, 0x34, 0x12,
+            0xb0, //
+        ];
+        assert_eq!(
+            addrs(&["00:1A:7D:DA:71:13", "00:11:22:33:44:55"]),
+            parse_inquiry_result_addresses(INQUIRY_RESULT_WITH_RSSI, &payload).unwrap()
+        );
+
+        let err =
+            parse_inquiry_result_addresses(INQUIRY_RESULT_WITH_RSSI, &payload[..20]).unwrap_err();
+        assert!(matches!(err, ParseError::Truncated(15)));
+    }
+
+    #[test]
+    fn test_extended_inquiry_result() {
+        // > HCI Event: Extended Inquiry Result (0x2f) plen 255
+        //         Num responses: 1
+        //         Address: 00:1A:7D:DA:71:13 (OUI 00-1A-7D)
+        //         Page scan repetition mode: R1 (0x01)
+        //         Page period mode: P2 (0x02)
+        //         Class: 0x5a020c
+        //         Clock offset: 0x4e6b
+        //         RSSI: -62 dBm (0xc2)
+        //         Name (complete): phone
+        let mut payload = vec![
+            0x01, //
+            0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00, 0x01, 0x02, 0x0c, 0x02, 0x5a, 0x6b, 0x4e,
+            0xc2, //
+            0x06, 0x09, b'p', b'h', b'o', b'n', b'e',
+        ];
+        payload.resize(255, 0x00);
+        assert_eq!(
+            addrs(&["00:1A:7D:DA:71:13"]),
+            parse_inquiry_result_addresses(EXTENDED_INQUIRY_RESULT, &payload).unwrap()
+        );
+
+        let err =
+            parse_inquiry_result_addresses(EXTENDED_INQUIRY_RESULT, &payload[..21]).unwrap_err();
+        assert!(matches!(err, ParseError::Truncated(1)));
+    }
+
+    #[test]
+    fn test_unexpected() {
+        let err = parse_inquiry_result_addresses(0x3e, &[0x00]).unwrap_err();
+        assert_eq!("Unexpected event code. (0x3e)", &err.to_string());
+
+        let err = parse_inquiry_result_addresses(INQUIRY_RESULT, &[]).unwrap_err();
+        assert!(matches!(err, ParseError::Truncated(0)));
+
+        assert_eq!(
+            Vec::<Address>::new(),
+            parse_inquiry_result_addresses(INQUIRY_RESULT, &[0x00]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_adversarial() {
+        for (event_code, response_len) in [
+            (INQUIRY_RESULT, 14),
+            (INQUIRY_RESULT_WITH_RSSI, 14),
+            (EXTENDED_INQUIRY_RESULT, 254),
+        ] {
+            for len in 0..=300 {
+                let mut payload = vec![0xFF; len];
+                for num_responses in [0, 1, 2, 0xFF] {
+                    if let Some(v) = payload.first_mut() {
+                        *v = num_responses;
+                    }
+                    let required = 1 + num_responses as usize * response_len;
+                    match parse_inquiry_result_addresses(event_code, &payload) {
+                        Ok(addrs) => {
+                            assert!(len >= required, "{} {}", len, num_responses);
+                            assert_eq!(num_responses as usize, addrs.len());
+                        }
+                        Err(ParseError::Truncated(..)) => {
+                            assert!(len < required, "{} {}", len, num_responses)
+                        }
+                        Err(err) => panic!("{:?} {} {}", err, len, num_responses),
+                    }
+                }
+            }
+        }
+        for event_code in 0..=0xFF {
+            let result = parse_inquiry_result_addresses(event_code, &[0x00]);
+            match event_code {
+                INQUIRY_RESULT | INQUIRY_RESULT_WITH_RSSI | EXTENDED_INQUIRY_RESULT => {
+                    assert!(result.unwrap().is_empty())
+                }
+                x => assert!(matches!(result, Err(ParseError::UnexpectedEvent(y)) if x == y)),
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Vendor specific HCI commands writing controller's public BD_ADDR.
+//!
+//! Each function returns opcode (OGF `0x3F`) and parameters. Byte order differs by vendor, so
+//! check the function for the controller.
+
+use crate::addr::BdAddr;
+
+fn reversed(addr: &BdAddr) -> [u8; 6] {
+    let mut b = addr.0;
+    b.reverse();
+    b
+}
+
+/// Broadcom / Cypress `BCM_Write_BD_ADDR`. (opcode `0xFC01`)
+///
+/// Parameter is in little-endian (wire) order, the same as standard HCI commands.
+/// Also used by Zeevo controllers.
+pub fn broadcom_write_bd_addr(addr: &BdAddr) -> (u16, [u8; 6]) {
+    (0xFC01, addr.0)
+}
+
+/// Texas Instruments `HCI_VS_Write_BD_ADDR`. (opcode `0xFC06`)
+///
+/// Parameter is in **big-endian** (display) order, reversed from standard HCI commands.
+pub fn ti_write_bd_addr(addr: &BdAddr) -> (u16, [u8; 6]) {
+    (0xFC06, reversed(addr))
+}
+
+/// Zephyr `BT_HCI_OP_VS_WRITE_BD_ADDR`. (opcode `0xFC06`)
+///
+/// Parameter is in little-endian (wire) order.
+/// Shares opcode with [`ti_write_bd_addr`] but not byte order.
+pub fn zephyr_write_bd_addr(addr: &BdAddr) -> (u16, [u8; 6]) {
+    (0xFC06, addr.0)
+}
+
+/// Intel `Intel_Write_BD_ADDR`. (opcode `0xFC31`)
+///
+/// Parameter is in little-endian (wire) order.
+pub fn intel_write_bd_addr(addr: &BdAddr) -> (u16, [u8; 6]) {
+    (0xFC31, addr.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr() -> BdAddr {
+        "00:1A:7D:DA:71:13".parse().unwrap()
+    }
+
+    #[test]
+    fn test_broadcom() {
+        assert_eq!(
+            (0xfc01, [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]),
+            broadcom_write_bd_addr(&addr())
+        );
+    }
+
+    #[test]
+    fn test_ti() {
+        assert_eq!(
+            (0xfc06, [0x00, 0x1a, 0x7d, 0xda, 0x71, 0x13]),
+            ti_write_bd_addr(&addr())
+        );
+    }
+
+    #[test]
+    fn test_zephyr() {
+        assert_eq!(
+            (0xfc06, [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]),
+            zephyr_write_bd_addr(&addr())
+        );
+    }
+
+    #[test]
+    fn test_intel() {
+        assert_eq!(
+            (0xfc31, [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]),
+            intel_write_bd_addr(&addr())
+        );
+    }
+}
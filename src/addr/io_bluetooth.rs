@@ -0,0 +1,110 @@
+//! macOS IOBluetooth `BluetoothDeviceAddress` holds the octets in display order,
+//! that is reversed relative to [`BdAddr`].
+//!
+//! [`BluetoothDeviceAddress`] is defined here with the same layout as IOBluetooth headers.
+//! (see [platform integrations](crate#platform-integrations))
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+use super::{hex_digit, parse_groups, AddressParseError, BdAddr};
+
+/// `BluetoothDeviceAddress` (IOBluetooth/Bluetooth.h)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BluetoothDeviceAddress {
+    pub data: [u8; 6],
+}
+
+impl From<BdAddr> for BluetoothDeviceAddress {
+    fn from(v: BdAddr) -> Self {
+        let mut data = v.0;
+        data.reverse();
+        Self { data }
+    }
+}
+
+impl From<BluetoothDeviceAddress> for BdAddr {
+    fn from(v: BluetoothDeviceAddress) -> Self {
+        let mut b = v.data;
+        b.reverse();
+        Self(b)
+    }
+}
+
+impl BdAddr {
+    /// Parse IOBluetooth hyphenated address string. (e.g. `00-1a-7d-da-71-13`)
+    ///
+    /// This is the form of `-[IOBluetoothDevice addressString]` and `kIOBluetoothDeviceAddressKey`.
+    pub fn from_io_bluetooth_str(s: &str) -> Result<Self, AddressParseError> {
+        parse_groups(s.as_bytes(), 0, b'-', hex_digit)
+    }
+
+    /// Format as IOBluetooth hyphenated address string. (e.g. `00-1a-7d-da-71-13`)
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_io_bluetooth_string(&self) -> String {
+        self.format_with(Some('-'), false).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_order() {
+        let addr = BdAddr::from_io_bluetooth_str("00-1a-7d-da-71-13").unwrap();
+        assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+        assert_eq!([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00], <[u8; 6]>::from(addr));
+
+        let raw = BluetoothDeviceAddress::from(addr);
+        assert_eq!([0x00, 0x1a, 0x7d, 0xda, 0x71, 0x13], raw.data);
+        assert_eq!(addr, BdAddr::from(raw));
+        assert_eq!("00-1a-7d-da-71-13", addr.to_io_bluetooth_string());
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(6, std::mem::size_of::<BluetoothDeviceAddress>());
+        assert_eq!(1, std::mem::align_of::<BluetoothDeviceAddress>());
+    }
+
+    #[test]
+    fn test_parse() {
+        let addr = BdAddr::from_io_bluetooth_str("F5-44-33-22-11-00").unwrap();
+        assert_eq!("f5:44:33:22:11:00", addr.to_string());
+
+        for (s, message) in [
+            ("", "empty group 0 at offset 0"),
+            (
+                "00-1a-7d-da-71",
+                "wrong group count (expected 6, found 5) at offset 14",
+            ),
+            (
+                "00-1a-7d-da-71-13-00",
+                "wrong group count (expected 6, found 7) at offset 17",
+            ),
+            (
+                "00:1a:7d:da:71:13",
+                "invalid hex digit in group 0 at offset 2",
+            ),
+            ("00-1a-7d-da-71-1", "group 5 too short at offset 16"),
+            (
+                "00-1a-7d-da-71-zz",
+                "invalid hex digit in group 5 at offset 15",
+            ),
+            (
+                "+1-1a-7d-da-71-13",
+                "invalid hex digit in group 0 at offset 0",
+            ),
+            (
+                "00-+a-7d-da-71-13",
+                "invalid hex digit in group 1 at offset 3",
+            ),
+        ] {
+            let err = BdAddr::from_io_bluetooth_str(s).unwrap_err();
+            assert_eq!(message, err.to_string(), "{:?}", s);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Lenient parsing of the address forms seen in logs and vendor tools.
+
+use super::{hex_digit, parse_groups_of, Address, AddressParseError, BdAddr};
+
+/// Separators of [`BdAddr::parse_lenient`].
+const SEPARATORS: &[u8] = b":-.";
+
+impl BdAddr {
+    /// Parse address string in any of the forms below. (case-insensitive)
+    ///
+    /// | Form | Example |
+    /// |------|---------|
+    /// | Colon separated | `55:44:33:22:11:00` |
+    /// | Dash separated (e.g. Windows) | `55-44-33-22-11-00` |
+    /// | Dot grouped (e.g. Cisco) | `5544.3322.1100` |
+    /// | No separator | `554433221100` |
+    ///
+    /// Each group has the fixed number of hex digits of the form, and the separator must be the same
+    /// throughout. [`BdAddr::from_str`](core::str::FromStr::from_str) only accepts colon separated.
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = "55:44:33:22:11:00".parse::<BdAddr>().unwrap();
+    /// assert_eq!(addr, BdAddr::parse_lenient("55-44-33-22-11-00").unwrap());
+    /// assert_eq!(addr, BdAddr::parse_lenient("5544.3322.1100").unwrap());
+    /// assert_eq!(addr, BdAddr::parse_lenient("554433221100").unwrap());
+    /// assert!(BdAddr::parse_lenient("55:44-33:22-11:00").is_err());
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self, AddressParseError> {
+        let s = s.as_bytes();
+        let sep = s.iter().copied().find(|c| !c.is_ascii_hexdigit());
+        let (widths, sep): (&[usize], _) = match sep {
+            Some(c @ b':') | Some(c @ b'-') => (&[2; 6], c),
+            Some(b'.') => (&[4; 3], b'.'),
+            // any non-digit is invalid without separator, and none of separators comes first
+            _ => (&[12], b':'),
+        };
+        parse_groups_of(s, 0, widths, sep, SEPARATORS, hex_digit)
+    }
+}
+
+impl Address {
+    /// Construct Classic BR/EDR Address from str in any form of [`BdAddr::parse_lenient`].
+    pub fn bredr_from_str_lenient(s: &str) -> Result<Self, AddressParseError> {
+        Ok(Self::BrEdr(BdAddr::parse_lenient(s)?))
+    }
+
+    /// Construct LE Public Device Address from str in any form of [`BdAddr::parse_lenient`].
+    pub fn le_public_from_str_lenient(s: &str) -> Result<Self, AddressParseError> {
+        Ok(BdAddr::parse_lenient(s)?.to_le_public_addr())
+    }
+
+    /// Construct LE Random Device Address from str in any form of [`BdAddr::parse_lenient`].
+    pub fn le_random_from_str_lenient(s: &str) -> Result<Self, AddressParseError> {
+        Ok(BdAddr::parse_lenient(s)?.to_le_random_addr())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AddressParseErrorKind, RandomDeviceAddress};
+
+    #[test]
+    fn test_parse_lenient() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        for s in [
+            "55:44:33:22:11:00",
+            "55-44-33-22-11-00",
+            "5544.3322.1100",
+            "554433221100",
+        ] {
+            assert_eq!(addr, BdAddr::parse_lenient(s).unwrap(), "{:?}", s);
+        }
+
+        let addr = BdAddr::from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+        for s in [
+            "00:1a:7d:da:71:13",
+            "00:1A:7D:DA:71:13",
+            "00-1A-7d-Da-71-13",
+            "001a.7dda.7113",
+            "001A.7DDA.7113",
+            "001a7dda7113",
+            "001A7DDA7113",
+        ] {
+            assert_eq!(addr, BdAddr::parse_lenient(s).unwrap(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_parse_lenient_err() {
+        use AddressParseErrorKind::*;
+
+        for (s, kind, group, position, message) in [
+            ("", Empty, 0, 0, "empty group 0 at offset 0"),
+            (
+                "55:44-33:22-11:00",
+                MixedSeparators,
+                1,
+                5,
+                "mixed separator after group 1 at offset 5",
+            ),
+            (
+                "55-44-33-22-11:00",
+                MixedSeparators,
+                4,
+                14,
+                "mixed separator after group 4 at offset 14",
+            ),
+            (
+                "5544.3322-1100",
+                MixedSeparators,
+                1,
+                9,
+                "mixed separator after group 1 at offset 9",
+            ),
+            (
+                "55443322110",
+                GroupTooShort,
+                0,
+                11,
+                "group 0 too short at offset 11",
+            ),
+            (
+                "5544332211000",
+                GroupTooLong,
+                0,
+                12,
+                "group 0 too long at offset 12",
+            ),
+            (
+                "5:44:33:22:11:00",
+                GroupTooShort,
+                0,
+                1,
+                "group 0 too short at offset 1",
+            ),
+            (
+                "55:44:33:22:11:000",
+                GroupTooLong,
+                5,
+                17,
+                "group 5 too long at offset 17",
+            ),
+            (
+                "5544:3322:1100",
+                GroupTooLong,
+                0,
+                2,
+                "group 0 too long at offset 2",
+            ),
+            (
+                "55.44.33.22.11.00",
+                GroupTooShort,
+                0,
+                2,
+                "group 0 too short at offset 2",
+            ),
+            ("55--44-33-22-11", Empty, 1, 3, "empty group 1 at offset 3"),
+            (
+                "55 44 33 22 11 00",
+                InvalidDigit,
+                0,
+                2,
+                "invalid hex digit in group 0 at offset 2",
+            ),
+            (
+                "55:44:33:2Z:11:00",
+                InvalidDigit,
+                3,
+                10,
+                "invalid hex digit in group 3 at offset 10",
+            ),
+            (
+                "+55:44:33:22:11:00",
+                InvalidDigit,
+                0,
+                0,
+                "invalid hex digit in group 0 at offset 0",
+            ),
+            (
+                "5544.3322",
+                WrongGroupCount {
+                    expected: 3,
+                    found: 2,
+                },
+                1,
+                9,
+                "wrong group count (expected 3, found 2) at offset 9",
+            ),
+            (
+                "55-44-33-22-11-00-ff",
+                WrongGroupCount {
+                    expected: 6,
+                    found: 7,
+                },
+                5,
+                17,
+                "wrong group count (expected 6, found 7) at offset 17",
+            ),
+        ] {
+            let err = BdAddr::parse_lenient(s).unwrap_err();
+            assert_eq!(kind, err.kind(), "{:?}", s);
+            assert_eq!(group, err.group(), "{:?}", s);
+            assert_eq!(position, err.position(), "{:?}", s);
+            assert_eq!(message, err.to_string(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_address_lenient() {
+        assert_eq!(
+            Address::bredr_from_str("55:44:33:22:11:00").unwrap(),
+            Address::bredr_from_str_lenient("5544.3322.1100").unwrap()
+        );
+        assert_eq!(
+            Address::le_public_from_str("55:44:33:22:11:00").unwrap(),
+            Address::le_public_from_str_lenient("55-44-33-22-11-00").unwrap()
+        );
+        let addr = Address::le_random_from_str_lenient("53038cbcbd82").unwrap();
+        assert_eq!(
+            Address::le_random_from_str("53:03:8c:bc:bd:82").unwrap(),
+            addr
+        );
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Resolvable(..))
+        ));
+
+        assert!(Address::bredr_from_str_lenient("55:44-33:22-11:00").is_err());
+        assert!(Address::le_public_from_str_lenient("55443322110").is_err());
+        assert!(Address::le_random_from_str_lenient("ZZ:ZZ:ZZ:ZZ:ZZ:ZZ").is_err());
+    }
+}
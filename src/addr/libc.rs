@@ -0,0 +1,156 @@
+//! Linux `AF_BLUETOOTH` socket address structures.
+//!
+//! These are not defined in `libc` crate, so defined here as same layout as BlueZ headers.
+//! `bdaddr_t` holds the octets in the same (wire) order as [`BdAddr`].
+
+#![allow(non_camel_case_types)]
+
+use core::mem;
+
+use super::{AddressType, BdAddr};
+
+const BDADDR_BREDR: u8 = 0x00;
+const BDADDR_LE_PUBLIC: u8 = 0x01;
+const BDADDR_LE_RANDOM: u8 = 0x02;
+
+/// `bdaddr_t` (bluetooth/bluetooth.h)
+#[repr(C, packed)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct bdaddr_t {
+    pub b: [u8; 6],
+}
+
+/// `struct sockaddr_rc` (bluetooth/rfcomm.h)
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct sockaddr_rc {
+    pub rc_family: libc::sa_family_t,
+    pub rc_bdaddr: bdaddr_t,
+    pub rc_channel: u8,
+}
+
+/// `struct sockaddr_l2` (bluetooth/l2cap.h)
+///
+/// `l2_psm` and `l2_cid` are little-endian.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct sockaddr_l2 {
+    pub l2_family: libc::sa_family_t,
+    pub l2_psm: u16,
+    pub l2_bdaddr: bdaddr_t,
+    pub l2_cid: u16,
+    pub l2_bdaddr_type: u8,
+}
+
+impl From<BdAddr> for bdaddr_t {
+    fn from(v: BdAddr) -> Self {
+        Self { b: v.0 }
+    }
+}
+
+impl From<bdaddr_t> for BdAddr {
+    fn from(v: bdaddr_t) -> Self {
+        Self(v.b)
+    }
+}
+
+impl BdAddr {
+    /// Convert to `bdaddr_t`.
+    pub fn to_bdaddr_t(&self) -> bdaddr_t {
+        bdaddr_t { b: self.0 }
+    }
+
+    /// Construct `struct sockaddr_rc` for `bind` / `connect`.
+    pub fn to_sockaddr_rc(&self, channel: u8) -> sockaddr_rc {
+        // zeroed to clear padding
+        let mut addr = unsafe { mem::zeroed::<sockaddr_rc>() };
+        addr.rc_family = libc::AF_BLUETOOTH as libc::sa_family_t;
+        addr.rc_bdaddr = self.to_bdaddr_t();
+        addr.rc_channel = channel;
+        addr
+    }
+
+    /// Construct `struct sockaddr_l2` for `bind` / `connect`.
+    ///
+    /// `psm` and `cid` are in host byte order.
+    pub fn to_sockaddr_l2(&self, psm: u16, cid: u16, addr_type: AddressType) -> sockaddr_l2 {
+        // zeroed to clear padding
+        let mut addr = unsafe { mem::zeroed::<sockaddr_l2>() };
+        addr.l2_family = libc::AF_BLUETOOTH as libc::sa_family_t;
+        addr.l2_psm = psm.to_le();
+        addr.l2_bdaddr = self.to_bdaddr_t();
+        addr.l2_cid = cid.to_le();
+        addr.l2_bdaddr_type = match addr_type {
+            AddressType::BrEdr => BDADDR_BREDR,
+            AddressType::LePublic => BDADDR_LE_PUBLIC,
+            AddressType::LeRandom => BDADDR_LE_RANDOM,
+        };
+        addr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::slice;
+
+    fn bytes<T>(v: &T, len: usize) -> &[u8] {
+        assert!(len <= mem::size_of::<T>());
+        unsafe { slice::from_raw_parts(v as *const T as *const u8, len) }
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(6, mem::size_of::<bdaddr_t>());
+        assert_eq!(1, mem::align_of::<bdaddr_t>());
+        assert_eq!(10, mem::size_of::<sockaddr_rc>());
+        assert_eq!(14, mem::size_of::<sockaddr_l2>());
+    }
+
+    #[test]
+    fn test_bdaddr_t() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        let b = addr.to_bdaddr_t();
+        assert_eq!(&[0x00, 0x11, 0x22, 0x33, 0x44, 0x55], bytes(&b, 6));
+        assert_eq!(b, bdaddr_t::from(addr));
+        assert_eq!(addr, BdAddr::from(b));
+    }
+
+    #[test]
+    fn test_sockaddr_rc() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        let sa = addr.to_sockaddr_rc(3);
+        let family = (libc::AF_BLUETOOTH as u16).to_ne_bytes();
+        assert_eq!(
+            &[family[0], family[1], 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x03, 0x00],
+            bytes(&sa, 10),
+        );
+    }
+
+    #[test]
+    fn test_sockaddr_l2() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        let family = (libc::AF_BLUETOOTH as u16).to_ne_bytes();
+
+        let sa = addr.to_sockaddr_l2(0x1001, 0, AddressType::BrEdr);
+        assert_eq!(
+            &[
+                family[0], family[1], 0x01, 0x10, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x00, 0x00,
+                0x00, 0x00
+            ],
+            bytes(&sa, 14),
+        );
+
+        let sa = addr.to_sockaddr_l2(0, 0x0004, AddressType::LePublic);
+        assert_eq!(
+            &[
+                family[0], family[1], 0x00, 0x00, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x04, 0x00,
+                0x01, 0x00
+            ],
+            bytes(&sa, 14),
+        );
+
+        let sa = addr.to_sockaddr_l2(0, 0x0004, AddressType::LeRandom);
+        assert_eq!(0x02, sa.l2_bdaddr_type);
+    }
+}
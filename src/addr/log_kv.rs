@@ -0,0 +1,99 @@
+//! Structured key-values of `log` crate.
+//!
+//! Values are recorded as display string. Use [`Address::address_type`] for the type as a separate key.
+//!
+//! ```
+//! use bdaddr::Address;
+//!
+//! let addr = Address::le_public_from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+//! log::info!(addr = addr, addr_type = addr.address_type(); "connected");
+//! // addr=00:1a:7d:da:71:13 addr_type=public
+//! ```
+
+use log::kv::{ToValue, Value};
+
+use super::{Address, AddressType, BdAddr, RandomDeviceAddressKind};
+
+impl ToValue for BdAddr {
+    fn to_value(&self) -> Value<'_> {
+        Value::from_display(self)
+    }
+}
+
+impl ToValue for Address {
+    fn to_value(&self) -> Value<'_> {
+        Value::from_display(self)
+    }
+}
+
+/// `bredr`, `public` or `random`.
+impl ToValue for AddressType {
+    fn to_value(&self) -> Value<'_> {
+        Value::from(self.as_ref())
+    }
+}
+
+/// `non-resolvable`, `resolvable`, `static` or `unknown`.
+impl ToValue for RandomDeviceAddressKind {
+    fn to_value(&self) -> Value<'_> {
+        Value::from(self.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::kv::{Error, Key, VisitSource};
+    use log::{Level, Log, Metadata, Record};
+    use std::sync::Mutex;
+
+    static RECORDS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+    struct Capture;
+
+    impl<'kvs> VisitSource<'kvs> for Capture {
+        fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+            RECORDS
+                .lock()
+                .unwrap()
+                .push((key.to_string(), value.to_string()));
+            Ok(())
+        }
+    }
+
+    impl Log for Capture {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            record.key_values().visit(&mut Capture).unwrap();
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn test_key_values() {
+        log::set_logger(&Capture).unwrap();
+        log::set_max_level(Level::Trace.to_level_filter());
+
+        let bd_addr = BdAddr::from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+        let addr = Address::le_random_from_str("53:03:8C:BC:BD:82").unwrap();
+        let kind = match &addr {
+            Address::LeRandom(v) => v.kind(),
+            _ => unreachable!(),
+        };
+        log::info!(bd_addr = bd_addr, addr = addr, addr_type = addr.address_type(), kind = kind; "connected");
+
+        assert_eq!(
+            vec![
+                ("bd_addr".to_string(), "00:1a:7d:da:71:13".to_string()),
+                ("addr".to_string(), "53:03:8c:bc:bd:82".to_string()),
+                ("addr_type".to_string(), "random".to_string()),
+                ("kind".to_string(), "resolvable".to_string()),
+            ],
+            *RECORDS.lock().unwrap()
+        );
+    }
+}
@@ -0,0 +1,52 @@
+//! `mac_address::MacAddress` holds the octets in display order. (see [byte order](BdAddr#byte-order) of [`BdAddr`])
+
+use mac_address::MacAddress;
+
+use super::BdAddr;
+
+impl BdAddr {
+    /// Test equality with `mac_address::MacAddress`.
+    pub fn eq_mac(&self, mac: &MacAddress) -> bool {
+        self.0.iter().rev().eq(mac.bytes().iter())
+    }
+}
+
+impl From<BdAddr> for MacAddress {
+    fn from(v: BdAddr) -> Self {
+        let mut b = v.0;
+        b.reverse();
+        Self::new(b)
+    }
+}
+
+impl From<MacAddress> for BdAddr {
+    fn from(v: MacAddress) -> Self {
+        let mut b = v.bytes();
+        b.reverse();
+        Self(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]);
+        let mac = MacAddress::from(addr);
+        assert_eq!([0xf5, 0x44, 0x33, 0x22, 0x11, 0x00], mac.bytes());
+        assert_eq!(addr.to_string(), mac.to_string().to_lowercase());
+        assert_eq!(addr, BdAddr::from(mac));
+
+        let mac = "F5:44:33:22:11:00".parse::<MacAddress>().unwrap();
+        assert_eq!(addr, BdAddr::from(mac));
+    }
+
+    #[test]
+    fn test_eq_mac() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]);
+        assert!(addr.eq_mac(&MacAddress::new([0xf5, 0x44, 0x33, 0x22, 0x11, 0x00])));
+        assert!(!addr.eq_mac(&MacAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5])));
+    }
+}
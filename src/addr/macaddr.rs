@@ -0,0 +1,43 @@
+//! `macaddr::MacAddr6` holds the octets in display order. (see [byte order](BdAddr#byte-order) of [`BdAddr`])
+
+use macaddr::MacAddr6;
+
+use super::BdAddr;
+
+impl From<BdAddr> for MacAddr6 {
+    fn from(v: BdAddr) -> Self {
+        let mut b = v.0;
+        b.reverse();
+        b.into()
+    }
+}
+
+impl From<MacAddr6> for BdAddr {
+    fn from(v: MacAddr6) -> Self {
+        let mut b = v.into_array();
+        b.reverse();
+        Self(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let addr = BdAddr::from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]);
+        let mac = MacAddr6::from(addr);
+        assert_eq!(MacAddr6::new(0xf5, 0x44, 0x33, 0x22, 0x11, 0x00), mac);
+        assert_eq!(addr.to_string(), mac.to_string().to_lowercase());
+        assert_eq!(addr, BdAddr::from(mac));
+    }
+
+    #[test]
+    fn test_parse() {
+        let mac = "F5:44:33:22:11:00".parse::<MacAddr6>().unwrap();
+        let addr = "f5:44:33:22:11:00".parse::<BdAddr>().unwrap();
+        assert_eq!(addr, BdAddr::from(mac));
+        assert_eq!(mac, MacAddr6::from(addr));
+    }
+}
@@ -1,22 +1,123 @@
-use aes::cipher::generic_array::GenericArray;
-use aes::{Aes128, BlockEncrypt, NewBlockCipher};
+//! Random address hash function `ah` (Vol 3, Part H | 2.2.2) on AES-128.
+//!
+//! The RustCrypto `aes` crate is used by `matches` feature, and the system OpenSSL by `matches-openssl` feature.
+//! If both are enabled, OpenSSL is used.
+
+use core::convert::TryFrom;
+use core::fmt;
 
 use super::ResolvablePrivateAddress;
 
+// kept for differential tests when OpenSSL is selected
+#[cfg(all(feature = "matches", any(test, not(feature = "matches-openssl"))))]
+mod aes_backend {
+    use aes::cipher::generic_array::GenericArray;
+    use aes::{Aes128, BlockEncrypt, NewBlockCipher};
+
+    /// Security function e. (both most significant octet first)
+    pub(super) fn e(k: &[u8; 16], r: [u8; 16]) -> Option<[u8; 16]> {
+        let cipher = Aes128::new(&GenericArray::from(*k));
+        let mut data = GenericArray::from(r);
+        cipher.encrypt_block(&mut data);
+        Some(data.into())
+    }
+}
+
+#[cfg(feature = "matches-openssl")]
+mod openssl_backend {
+    use openssl::symm::{Cipher, Crypter, Mode};
+
+    /// Security function e. (both most significant octet first)
+    pub(super) fn e(k: &[u8; 16], r: [u8; 16]) -> Option<[u8; 16]> {
+        let mut crypter = Crypter::new(Cipher::aes_128_ecb(), Mode::Encrypt, k, None).ok()?;
+        crypter.pad(false);
+        let mut out = [0; 32];
+        let n = crypter.update(&r, &mut out).ok()?;
+        let n = n + crypter.finalize(&mut out[n..]).ok()?;
+        if n != 16 {
+            return None;
+        }
+        let mut data = [0; 16];
+        data.copy_from_slice(&out[..16]);
+        Some(data)
+    }
+}
+
+#[cfg(not(feature = "matches-openssl"))]
+use self::aes_backend::e;
+#[cfg(feature = "matches-openssl")]
+use self::openssl_backend::e;
+
+/// ah(k, r) with `irk` and `prand` in wire order (least significant octet first), returns hash in wire order.
+fn ah_with(
+    e: fn(&[u8; 16], [u8; 16]) -> Option<[u8; 16]>,
+    irk: &[u8; 16],
+    prand: [u8; 3],
+) -> Option<[u8; 3]> {
+    // ah(k, r'): r' is padding (13 octets) || prand (3 octets), both most significant octet first
+    let mut k = *irk;
+    k.reverse();
+    let mut r = [0; 16];
+    r[13] = prand[2];
+    r[14] = prand[1];
+    r[15] = prand[0];
+
+    let hash = e(&k, r)?;
+    Some([hash[15], hash[14], hash[13]])
+}
+
+/// ah(k, r) on the selected backend.
+#[cfg(all(feature = "testing", feature = "rand"))]
+pub(super) fn ah(irk: &[u8; 16], prand: [u8; 3]) -> Option<[u8; 3]> {
+    ah_with(e, irk, prand)
+}
+
+/// IRK slice length is not 16. (for bindings taking IRK as bytes)
+#[derive(Debug)]
+pub(crate) struct InvalidIrkLength(pub(crate) usize);
+
+impl fmt::Display for InvalidIrkLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid IRK length. (expect: 16, but {})", self.0)
+    }
+}
+
+#[cfg_attr(
+    not(any(feature = "python", feature = "uniffi", feature = "wasm")),
+    allow(dead_code)
+)]
+pub(crate) fn irk_from_slice(irk: &[u8]) -> Result<&[u8; 16], InvalidIrkLength> {
+    <&[u8; 16]>::try_from(irk).map_err(|_| InvalidIrkLength(irk.len()))
+}
+
+/// Error of the AES backend. (only by `matches-openssl` feature)
+#[derive(Debug)]
+pub struct AesBackendError;
+
+impl fmt::Display for AesBackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AES backend failed.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AesBackendError {}
+
 impl ResolvablePrivateAddress {
     /// Test matches Identity Resolving Key.
+    ///
+    /// Returns `false` if the OpenSSL backend fails. Use [`ResolvablePrivateAddress::try_matches`] to tell it from a mismatch.
     pub fn matches(&self, irk: &[u8; 16]) -> bool {
-        let k = GenericArray::from_exact_iter(irk.iter().cloned().rev()).unwrap();
-        let r = self.0 .0[3..].iter().chain([0; 13].iter()).cloned().rev();
-        let r = GenericArray::from_exact_iter(r).unwrap();
-
-        let cipher = Aes128::new(&k);
-        let mut hash = r.clone();
-        cipher.encrypt_block(&mut hash);
-        let hash = &mut hash[13..];
-        hash.reverse();
+        self.try_matches(irk).unwrap_or(false)
+    }
 
-        hash == &self.0 .0[..3] // TODO Not sure if this is a good way to compare.
+    /// Test matches Identity Resolving Key, and fail if the AES backend fails.
+    ///
+    /// The RustCrypto `aes` backend never fails.
+    pub fn try_matches(&self, irk: &[u8; 16]) -> Result<bool, AesBackendError> {
+        let b = self.0 .0;
+        let hash = ah_with(e, irk, [b[3], b[4], b[5]]).ok_or(AesBackendError)?;
+        Ok(hash == [b[0], b[1], b[2]])
     }
 }
 
@@ -39,4 +140,87 @@ mod tests {
         ];
         assert!(!addr.matches(&irk));
     }
+
+    // Vol 3, Part H | D.7 ah: IRK ec0234a357c8ad05341010a60a397d9b, prand 708194, hash 0dfbaa
+    const SPEC_IRK: [u8; 16] = [
+        0x9b, 0x7d, 0x39, 0x0a, 0xa6, 0x10, 0x10, 0x34, 0x05, 0xad, 0xc8, 0x57, 0xa3, 0x34, 0x02,
+        0xec,
+    ];
+
+    #[test]
+    fn test_spec() {
+        let addr =
+            ResolvablePrivateAddress::try_from([0xaa, 0xfb, 0x0d, 0x94, 0x81, 0x70]).unwrap();
+        assert!(addr.matches(&SPEC_IRK));
+        assert!(addr.try_matches(&SPEC_IRK).unwrap());
+        let mut irk = SPEC_IRK;
+        irk[0] ^= 0x01;
+        assert!(!addr.try_matches(&irk).unwrap());
+        assert_eq!("AES backend failed.", AesBackendError.to_string());
+        assert_eq!(
+            Some([0xaa, 0xfb, 0x0d]),
+            ah_with(e, &SPEC_IRK, [0x94, 0x81, 0x70])
+        );
+        #[cfg(feature = "matches")]
+        assert_eq!(
+            Some([0xaa, 0xfb, 0x0d]),
+            ah_with(aes_backend::e, &SPEC_IRK, [0x94, 0x81, 0x70])
+        );
+        #[cfg(feature = "matches-openssl")]
+        assert_eq!(
+            Some([0xaa, 0xfb, 0x0d]),
+            ah_with(openssl_backend::e, &SPEC_IRK, [0x94, 0x81, 0x70])
+        );
+    }
+
+    #[cfg(all(feature = "matches", feature = "matches-openssl"))]
+    #[test]
+    fn test_backends_agree() {
+        // xorshift64, deterministic
+        let mut x = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = || {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            x
+        };
+        for _ in 0..1000 {
+            let mut irk = [0; 16];
+            irk[..8].copy_from_slice(&next().to_le_bytes());
+            irk[8..].copy_from_slice(&next().to_le_bytes());
+            let v = next().to_le_bytes();
+            let prand = [v[0], v[1], v[2] & 0x3f | 0x40];
+            let expect = ah_with(aes_backend::e, &irk, prand);
+            assert!(expect.is_some());
+            assert_eq!(
+                expect,
+                ah_with(openssl_backend::e, &irk, prand),
+                "{:02x?}",
+                irk
+            );
+        }
+    }
+
+    #[test]
+    fn test_adversarial() {
+        for prand in [[0x00, 0x00, 0x40], [0xFF, 0xFF, 0x7F]] {
+            for irk in [[0x00; 16], [0xFF; 16]] {
+                let [h0, h1, h2] = ah_with(e, &irk, prand).unwrap();
+                let addr =
+                    ResolvablePrivateAddress::try_from([h0, h1, h2, prand[0], prand[1], prand[2]])
+                        .unwrap();
+                assert!(addr.matches(&irk), "{:02x?} {:02x?}", prand, irk);
+                let addr = ResolvablePrivateAddress::try_from([
+                    h0 ^ 0x01,
+                    h1,
+                    h2,
+                    prand[0],
+                    prand[1],
+                    prand[2],
+                ])
+                .unwrap();
+                assert!(!addr.matches(&irk), "{:02x?} {:02x?}", prand, irk);
+            }
+        }
+    }
 }
@@ -0,0 +1,213 @@
+//! Node.js bindings.
+//!
+//! Build as native addon with `napi build` of `@napi-rs/cli` (`--features napi`).
+//!
+//! ```js
+//! const { parse, classify, rpaMatches, generateStatic } = require("bdaddr");
+//!
+//! parse("00:1a:7d:da:71:13"); // <Buffer 13 71 da 7d 1a 00>
+//! classify("random/53:03:8c:bc:bd:82"); // { type: "random", kind: "resolvable" }
+//! classify("public/00:1a:7d:da:71:13"); // { type: "public" }
+//! rpaMatches("53:03:8c:bc:bd:82", "1978a2afdd757bedfc9dc69e95d733b3"); // true
+//! generateStatic(); // e.g. "f5:44:33:22:11:00"
+//! ```
+//!
+//! Invalid arguments are thrown as `TypeError`.
+
+use napi_crate as napi;
+use napi_crate::bindgen_prelude::Buffer;
+use napi_crate::{Env, Error, Result, Status};
+use napi_derive::napi;
+
+use super::{
+    hex_digit, Address, AddressFromStrError, BdAddr, RandomDeviceAddress, StaticDeviceAddress,
+    RANDOM_PART_MASK,
+};
+
+fn type_error<T>(env: &Env, reason: &str) -> Result<T> {
+    env.throw_type_error(reason, None)?;
+    Err(Error::from_status(Status::PendingException))
+}
+
+fn parse_bd_addr(s: &str) -> std::result::Result<BdAddr, String> {
+    s.parse().map_err(|e| format!("{} ({:?})", e, s))
+}
+
+fn parse_address(s: &str) -> std::result::Result<Address, String> {
+    s.parse().map_err(|e| match e {
+        AddressFromStrError::UnknownAddressType(e) => e.to_string(),
+        e => format!("{} ({:?})", e, s),
+    })
+}
+
+fn parse_irk(s: &str) -> std::result::Result<[u8; 16], String> {
+    let err = || format!("Invalid IRK. (expect: 32 hex digits, but {:?})", s);
+    let digits = s.as_bytes();
+    if digits.len() != 32 {
+        return Err(err());
+    }
+    let mut irk = [0; 16];
+    for (b, d) in irk.iter_mut().zip(digits.chunks(2)) {
+        let (hi, lo) = hex_digit(d[0]).zip(hex_digit(d[1])).ok_or_else(err)?;
+        *b = hi << 4 | lo;
+    }
+    Ok(irk)
+}
+
+fn rpa_matches_hex(addr: &str, irk_hex: &str) -> std::result::Result<bool, String> {
+    let addr = parse_bd_addr(addr)?;
+    let irk = parse_irk(irk_hex)?;
+    match RandomDeviceAddress::new(addr) {
+        RandomDeviceAddress::Resolvable(addr) => Ok(addr.matches(&irk)),
+        _ => Ok(false),
+    }
+}
+
+/// Result of [`classify`].
+#[napi(object)]
+pub struct Classification {
+    /// `bredr`, `public` or `random`.
+    #[napi(js_name = "type")]
+    pub address_type: String,
+
+    /// `static`, `resolvable`, `non-resolvable` or `unknown` for LE Random Device Address, otherwise `undefined`.
+    pub kind: Option<String>,
+}
+
+/// Parse address into 6 bytes in little-endian (wire) order.
+#[napi]
+pub fn parse(env: Env, addr: String) -> Result<Buffer> {
+    match parse_bd_addr(&addr) {
+        Ok(addr) => Ok(<[u8; 6]>::from(addr).to_vec().into()),
+        Err(e) => type_error(&env, &e),
+    }
+}
+
+/// Classify `<address type>/<address>`. (e.g. `public/00:1a:7d:da:71:13`)
+///
+/// Bare address is rejected. Prefix `random/` for the addresses from Web Bluetooth or scanners
+/// not exposing address type.
+#[napi]
+pub fn classify(env: Env, addr: String) -> Result<Classification> {
+    let addr = match parse_address(&addr) {
+        Ok(addr) => addr,
+        Err(e) => return type_error(&env, &e),
+    };
+    let kind = match &addr {
+        Address::LeRandom(addr) => Some(addr.kind().to_string()),
+        _ => None,
+    };
+    Ok(Classification {
+        address_type: addr.address_type().to_string(),
+        kind,
+    })
+}
+
+/// Test address is a Resolvable Private Address generated from Identity Resolving Key. (32 hex digits)
+///
+/// The IRK is in the same order as [`super::ResolvablePrivateAddress::matches`].
+#[napi]
+pub fn rpa_matches(env: Env, addr: String, irk_hex: String) -> Result<bool> {
+    match rpa_matches_hex(&addr, &irk_hex) {
+        Ok(matches) => Ok(matches),
+        Err(e) => type_error(&env, &e),
+    }
+}
+
+/// Generate LE Static Device Address from the OS random source.
+#[napi]
+pub fn generate_static() -> Result<String> {
+    loop {
+        let mut b = [0; 8];
+        getrandom::fill(&mut b).map_err(|e| Error::from_reason(e.to_string()))?;
+        // all zeros or all ones is rejected, retry
+        if let Ok(addr) =
+            StaticDeviceAddress::from_random_part(u64::from_le_bytes(b) & RANDOM_PART_MASK)
+        {
+            return Ok(addr.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AddressType;
+
+    #[test]
+    fn test_parse_address() {
+        for (s, ty, addr) in [
+            (
+                "public/00:1a:7d:da:71:13",
+                AddressType::LePublic,
+                "00:1a:7d:da:71:13",
+            ),
+            (
+                "bredr/00:1a:7d:da:71:13",
+                AddressType::BrEdr,
+                "00:1a:7d:da:71:13",
+            ),
+            (
+                "random/53:03:8c:bc:bd:82",
+                AddressType::LeRandom,
+                "53:03:8c:bc:bd:82",
+            ),
+        ] {
+            let a = parse_address(s).unwrap();
+            assert_eq!(ty, a.address_type());
+            assert_eq!(addr, a.into_bd_addr().to_string());
+        }
+
+        assert_eq!(
+            "Unknown address type. (\"le\")",
+            parse_address("le/53:03:8c:bc:bd:82").unwrap_err()
+        );
+        assert_eq!(
+            "wrong group count (expected 6, found 3) at offset 15 (\"random/53:03:8c\")",
+            parse_address("random/53:03:8c").unwrap_err()
+        );
+        assert_eq!(
+            "expect <address type>/<address>. (\"53:03:8c:bc:bd:82\")",
+            parse_address("53:03:8c:bc:bd:82").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_irk() {
+        assert_eq!(
+            [
+                0x19, 0x78, 0xa2, 0xaf, 0xdd, 0x75, 0x7b, 0xed, 0xfc, 0x9d, 0xc6, 0x9e, 0x95, 0xd7,
+                0x33, 0xb3,
+            ],
+            parse_irk("1978a2afdd757bedfc9dc69e95d733b3").unwrap()
+        );
+        assert!(parse_irk("1978a2afdd757bedfc9dc69e95d733").is_err());
+        assert!(parse_irk("1978a2afdd757bedfc9dc69e95d733zz").is_err());
+        assert!(parse_irk("1978a2afdd757bedfc9dc69e95d733\u{e9}").is_err());
+        // sign accepted by `u8::from_str_radix`
+        assert!(parse_irk("+978a2afdd757bedfc9dc69e95d733b3").is_err());
+        assert!(parse_irk("1978a2afdd757bedfc9dc69e95d733+b").is_err());
+        assert_eq!(
+            parse_irk("1978a2afdd757bedfc9dc69e95d733b3"),
+            parse_irk("1978A2AFDD757BEDFC9DC69E95D733B3")
+        );
+    }
+
+    #[test]
+    fn test_rpa_matches() {
+        assert_eq!(
+            Ok(true),
+            rpa_matches_hex("53:03:8c:bc:bd:82", "1978a2afdd757bedfc9dc69e95d733b3")
+        );
+        assert_eq!(
+            Ok(false),
+            rpa_matches_hex("53:03:8c:bc:bd:82", "1a78a2afdd757bedfc9dc69e95d733b3")
+        );
+        assert_eq!(
+            Ok(false),
+            rpa_matches_hex("00:1a:7d:da:71:13", "1978a2afdd757bedfc9dc69e95d733b3")
+        );
+        assert!(rpa_matches_hex("53:03:8c:bc:bd", "1978a2afdd757bedfc9dc69e95d733b3").is_err());
+        assert!(rpa_matches_hex("53:03:8c:bc:bd:82", "1978a2af").is_err());
+    }
+}
@@ -0,0 +1,395 @@
+//! Bluetooth OOB data of NFC Connection Handover (NDEF record payloads) bearing addresses.
+//!
+//! ```
+//! use bdaddr::{ndef, Address};
+//!
+//! let addr = Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]);
+//! let payload = ndef::encode_le_oob(&addr, ndef::LeRole::PeripheralOnly, &[]).unwrap();
+//! assert_eq!(
+//!     (addr, ndef::LeRole::PeripheralOnly),
+//!     ndef::decode_le_oob(&payload).unwrap()
+//! );
+//! ```
+//!
+//! ref NFC Forum | Bluetooth Secure Simple Pairing Using NFC
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+
+use super::{Address, BdAddr};
+#[cfg(feature = "alloc")]
+use super::{AddressType, UnsupportedAddressType};
+
+/// MIME type of BR/EDR OOB record.
+pub const EP_OOB_MIME_TYPE: &str = "application/vnd.bluetooth.ep.oob";
+
+/// MIME type of LE OOB record.
+pub const LE_OOB_MIME_TYPE: &str = "application/vnd.bluetooth.le.oob";
+
+/// AD type of LE Bluetooth Device Address.
+pub const LE_BLUETOOTH_DEVICE_ADDRESS: u8 = 0x1B;
+
+/// AD type of LE Role.
+pub const LE_ROLE: u8 = 0x1C;
+
+/// `OOB Data Length` and `BD_ADDR` of BR/EDR OOB.
+const EP_HEADER_LEN: usize = 8;
+
+/// LE Role.
+///
+/// ref Core Specification Supplement | Part A | 1.17 LE ROLE
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LeRole {
+    /// Only Peripheral Role supported. (`0x00`)
+    PeripheralOnly,
+
+    /// Only Central Role supported. (`0x01`)
+    CentralOnly,
+
+    /// Peripheral and Central Role supported, Peripheral Role preferred. (`0x02`)
+    PeripheralPreferred,
+
+    /// Peripheral and Central Role supported, Central Role preferred. (`0x03`)
+    CentralPreferred,
+}
+
+impl LeRole {
+    #[cfg(feature = "alloc")]
+    fn code(&self) -> u8 {
+        match self {
+            Self::PeripheralOnly => 0x00,
+            Self::CentralOnly => 0x01,
+            Self::PeripheralPreferred => 0x02,
+            Self::CentralPreferred => 0x03,
+        }
+    }
+
+    fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0x00 => Some(Self::PeripheralOnly),
+            0x01 => Some(Self::CentralOnly),
+            0x02 => Some(Self::PeripheralPreferred),
+            0x03 => Some(Self::CentralPreferred),
+            _ => None,
+        }
+    }
+}
+
+/// Error for [`decode_le_oob`] and [`decode_ep_oob`].
+#[derive(Debug)]
+pub enum ParseError {
+    /// Payload too short. (offset of the truncated field or AD structure)
+    Truncated(usize),
+
+    /// `OOB Data Length` is less than 8.
+    InvalidOobDataLength(usize),
+
+    /// Unexpected length of AD structure data.
+    InvalidAdLength(u8, usize),
+
+    /// Required AD structure is missing.
+    MissingAdType(u8),
+
+    /// Unknown LE Role code.
+    UnknownLeRole(u8),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated(offset) => write!(f, "Truncated OOB data. (at offset {})", offset),
+            Self::InvalidOobDataLength(len) => write!(
+                f,
+                "Invalid OOB data length. (expect: at least 8, but {})",
+                len
+            ),
+            Self::InvalidAdLength(ad_type, len) => write!(
+                f,
+                "Invalid AD structure length. (AD type: 0x{:02x}, length: {})",
+                ad_type, len
+            ),
+            Self::MissingAdType(ad_type) => write!(f, "Missing AD type. (0x{:02x})", ad_type),
+            Self::UnknownLeRole(code) => write!(f, "Unknown LE Role. (0x{:02x})", code),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Error for [`encode_ep_oob`].
+#[derive(Debug)]
+pub struct PayloadTooLong(usize);
+
+impl fmt::Display for PayloadTooLong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "OOB data too long. (expect: up to 65535, but {})",
+            self.0
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PayloadTooLong {}
+
+/// Encode payload of `application/vnd.bluetooth.le.oob` record.
+///
+/// LE Bluetooth Device Address and LE Role AD structures, followed by `extra_ad` as is.
+/// (e.g. Security Manager TK Value, LE Secure Connections Confirmation Value or Local Name)
+/// Rejects BR/EDR Address.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode_le_oob(
+    addr: &Address,
+    role: LeRole,
+    extra_ad: &[u8],
+) -> Result<Vec<u8>, UnsupportedAddressType> {
+    let flag = match addr.address_type() {
+        AddressType::LePublic => 0x00,
+        AddressType::LeRandom => 0x01,
+        ty @ AddressType::BrEdr => return Err(UnsupportedAddressType(ty)),
+    };
+    let mut data = Vec::with_capacity(12 + extra_ad.len());
+    data.extend_from_slice(&[8, LE_BLUETOOTH_DEVICE_ADDRESS]);
+    data.extend_from_slice(&<[u8; 6]>::from(*addr.as_ref()));
+    data.push(flag);
+    data.extend_from_slice(&[2, LE_ROLE, role.code()]);
+    data.extend_from_slice(extra_ad);
+    Ok(data)
+}
+
+/// Decode address and role from payload of `application/vnd.bluetooth.le.oob` record.
+///
+/// Other AD structures are skipped, and the first one is used if repeated.
+/// Reserved bits of address type are ignored.
+pub fn decode_le_oob(data: &[u8]) -> Result<(Address, LeRole), ParseError> {
+    let mut addr = None;
+    let mut role = None;
+    let mut offset = 0;
+    while let Some(&len) = data.get(offset) {
+        // zero length terminates significant part
+        if len == 0 {
+            break;
+        }
+        let ad = data
+            .get(offset + 1..offset + 1 + len as usize)
+            .ok_or(ParseError::Truncated(offset))?;
+        match ad {
+            [LE_BLUETOOTH_DEVICE_ADDRESS, b0, b1, b2, b3, b4, b5, flag] => {
+                let b = [*b0, *b1, *b2, *b3, *b4, *b5];
+                addr.get_or_insert(if flag & 0x01 == 0 {
+                    Address::le_public_from(b)
+                } else {
+                    Address::le_random_from(b)
+                });
+            }
+            [LE_ROLE, code] if role.is_none() => {
+                role = Some(LeRole::from_code(*code).ok_or(ParseError::UnknownLeRole(*code))?);
+            }
+            [LE_ROLE, _] => {}
+            [ad_type @ (LE_BLUETOOTH_DEVICE_ADDRESS | LE_ROLE), ..] => {
+                return Err(ParseError::InvalidAdLength(*ad_type, len as usize - 1));
+            }
+            _ => {}
+        }
+        offset += 1 + len as usize;
+    }
+    let addr = addr.ok_or(ParseError::MissingAdType(LE_BLUETOOTH_DEVICE_ADDRESS))?;
+    let role = role.ok_or(ParseError::MissingAdType(LE_ROLE))?;
+    Ok((addr, role))
+}
+
+/// Encode payload of `application/vnd.bluetooth.ep.oob` record.
+///
+/// `OOB Data Length` (little-endian, including itself), `BD_ADDR` and `eir` (optional EIR data) as is.
+/// Fails if the whole payload exceeds 65535 bytes.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode_ep_oob(addr: &BdAddr, eir: &[u8]) -> Result<Vec<u8>, PayloadTooLong> {
+    let len = EP_HEADER_LEN + eir.len();
+    let len16 = u16::try_from(len).map_err(|_| PayloadTooLong(len))?;
+    let mut data = Vec::with_capacity(len);
+    data.extend_from_slice(&len16.to_le_bytes());
+    data.extend_from_slice(&<[u8; 6]>::from(*addr));
+    data.extend_from_slice(eir);
+    Ok(data)
+}
+
+/// Decode `BD_ADDR` and optional EIR data from payload of `application/vnd.bluetooth.ep.oob` record.
+///
+/// Bytes beyond `OOB Data Length` are ignored.
+pub fn decode_ep_oob(data: &[u8]) -> Result<(BdAddr, &[u8]), ParseError> {
+    let (len, b) = match data {
+        [l0, l1, b0, b1, b2, b3, b4, b5, ..] => (
+            u16::from_le_bytes([*l0, *l1]) as usize,
+            [*b0, *b1, *b2, *b3, *b4, *b5],
+        ),
+        [_, _, ..] => return Err(ParseError::Truncated(2)),
+        _ => return Err(ParseError::Truncated(0)),
+    };
+    if len < EP_HEADER_LEN {
+        return Err(ParseError::InvalidOobDataLength(len));
+    }
+    let eir = data
+        .get(EP_HEADER_LEN..len)
+        .ok_or(ParseError::Truncated(EP_HEADER_LEN))?;
+    Ok((b.into(), eir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Payloads of the examples in NFC Forum | Bluetooth Secure Simple Pairing Using NFC
+    const ADDR: [u8; 6] = [0x06, 0x05, 0x04, 0x03, 0x02, 0x01];
+
+    const LE_OOB: [u8; 24] = [
+        0x08, 0x1b, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x00, // LE Bluetooth Device Address
+        0x02, 0x1c, 0x02, // LE Role
+        // Complete Local Name
+        0x0b, 0x09, b'D', b'e', b'v', b'i', b'c', b'e', b'N', b'a', b'm', b'e', //
+    ];
+
+    const EP_OOB: [u8; 31] = [
+        0x1f, 0x00, // OOB Data Length
+        0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // BD_ADDR
+        // Complete Local Name
+        0x0b, 0x09, b'D', b'e', b'v', b'i', b'c', b'e', b'N', b'a', b'm', b'e', //
+        0x04, 0x0d, 0x20, 0x06, 0x08, // Class of Device
+        0x05, 0x03, 0x06, 0x11, 0x20, 0x11, // Complete List of 16-bit Service Class UUIDs
+    ];
+
+    #[test]
+    fn test_encode_le_oob() {
+        let addr = Address::le_public_from(ADDR);
+        let data = encode_le_oob(&addr, LeRole::PeripheralPreferred, &LE_OOB[12..]).unwrap();
+        assert_eq!(&LE_OOB[..], &data[..]);
+
+        let addr = Address::le_random_from_str("53:03:8C:BC:BD:82").unwrap();
+        assert_eq!(
+            vec![
+                0x08, 0x1b, 0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53, 0x01, //
+                0x02, 0x1c, 0x01, //
+            ],
+            encode_le_oob(&addr, LeRole::CentralOnly, &[]).unwrap()
+        );
+
+        let addr = Address::bredr_from(ADDR);
+        let err = encode_le_oob(&addr, LeRole::PeripheralOnly, &[]).unwrap_err();
+        assert_eq!("Unsupported address type. (BrEdr)", &err.to_string());
+    }
+
+    #[test]
+    fn test_decode_le_oob() {
+        let (addr, role) = decode_le_oob(&LE_OOB).unwrap();
+        assert_eq!(Address::le_public_from(ADDR), addr);
+        assert_eq!(LeRole::PeripheralPreferred, role);
+
+        // role first, reserved bits, zero length terminator and padding
+        let data = [
+            0x02, 0x1c, 0x03, //
+            0x08, 0x1b, 0x00, 0x11, 0x22, 0x33, 0x44, 0xf5, 0xfd, //
+            0x00, 0xff, 0xff,
+        ];
+        let (addr, role) = decode_le_oob(&data).unwrap();
+        assert_eq!(
+            Address::le_random_from_str("F5:44:33:22:11:00").unwrap(),
+            addr
+        );
+        assert_eq!(LeRole::CentralPreferred, role);
+
+        let err = decode_le_oob(&LE_OOB[9..]).unwrap_err();
+        assert_eq!("Missing AD type. (0x1b)", &err.to_string());
+        let err = decode_le_oob(&LE_OOB[..9]).unwrap_err();
+        assert_eq!("Missing AD type. (0x1c)", &err.to_string());
+        let err = decode_le_oob(&LE_OOB[..15]).unwrap_err();
+        assert_eq!("Truncated OOB data. (at offset 12)", &err.to_string());
+        let err = decode_le_oob(&[0x02, 0x1c, 0x04]).unwrap_err();
+        assert_eq!("Unknown LE Role. (0x04)", &err.to_string());
+        let err = decode_le_oob(&[0x07, 0x1b, 0, 0, 0, 0, 0, 0]).unwrap_err();
+        assert_eq!(
+            "Invalid AD structure length. (AD type: 0x1b, length: 6)",
+            &err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_ep_oob() {
+        let addr = BdAddr::from(ADDR);
+        assert_eq!("01:02:03:04:05:06", addr.to_string());
+        assert_eq!(
+            &EP_OOB[..],
+            &encode_ep_oob(&addr, &EP_OOB[8..]).unwrap()[..]
+        );
+        assert_eq!(
+            vec![0x08, 0x00, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01],
+            encode_ep_oob(&addr, &[]).unwrap()
+        );
+        let err = encode_ep_oob(&addr, &[0; 65528]).unwrap_err();
+        assert_eq!(
+            "OOB data too long. (expect: up to 65535, but 65536)",
+            &err.to_string()
+        );
+
+        let (decoded, eir) = decode_ep_oob(&EP_OOB).unwrap();
+        assert_eq!(addr, decoded);
+        assert_eq!(&EP_OOB[8..], eir);
+        let mut data = EP_OOB.to_vec();
+        data.push(0xff);
+        assert_eq!(&EP_OOB[8..], decode_ep_oob(&data).unwrap().1);
+
+        let err = decode_ep_oob(&EP_OOB[..7]).unwrap_err();
+        assert_eq!("Truncated OOB data. (at offset 2)", &err.to_string());
+        let err = decode_ep_oob(&EP_OOB[..11]).unwrap_err();
+        assert_eq!("Truncated OOB data. (at offset 8)", &err.to_string());
+        let err = decode_ep_oob(&[0x07, 0x00, 0, 0, 0, 0, 0, 0]).unwrap_err();
+        assert_eq!(
+            "Invalid OOB data length. (expect: at least 8, but 7)",
+            &err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_decode_adversarial() {
+        let data = [0xFF; 32];
+        for len in 0..=data.len() {
+            let err = decode_le_oob(&data[..len]).unwrap_err();
+            if len == 0 {
+                assert!(matches!(
+                    err,
+                    ParseError::MissingAdType(LE_BLUETOOTH_DEVICE_ADDRESS)
+                ));
+            } else {
+                assert!(matches!(err, ParseError::Truncated(..)), "{}", len);
+            }
+            let err = decode_ep_oob(&data[..len]).unwrap_err();
+            assert!(matches!(err, ParseError::Truncated(..)), "{}", len);
+        }
+        for b in 0..=0xFF {
+            let data = [b, LE_BLUETOOTH_DEVICE_ADDRESS, b, b, b, b, b, b, b, b];
+            let err = decode_le_oob(&data).unwrap_err();
+            match b {
+                0 => assert!(matches!(
+                    err,
+                    ParseError::MissingAdType(LE_BLUETOOTH_DEVICE_ADDRESS)
+                )),
+                // address, and then a truncated AD structure
+                8 | 10.. => assert!(matches!(err, ParseError::Truncated(..)), "{}", b),
+                _ => assert!(
+                    matches!(
+                        err,
+                        ParseError::InvalidAdLength(LE_BLUETOOTH_DEVICE_ADDRESS, n) if n == b as usize - 1
+                    ),
+                    "{}",
+                    b
+                ),
+            }
+        }
+    }
+}
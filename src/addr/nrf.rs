@@ -0,0 +1,175 @@
+//! nRF SoftDevice `ble_gap_addr_t` holds `addr_type` and `addr` in the same (wire) order as [`BdAddr`].
+//!
+//! Conversions take the raw `(addr_type, addr)` pair. (see [platform integrations](crate#platform-integrations))
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use super::{
+    Address, BdAddr, InvalidBitsForAddressType, NonResolvablePrivateAddress, PublicDeviceAddress,
+    RandomDeviceAddress, ResolvablePrivateAddress, StaticDeviceAddress, UnknownAddressType,
+    UnsupportedAddressType,
+};
+
+const BLE_GAP_ADDR_TYPE_PUBLIC: u8 = 0x00;
+const BLE_GAP_ADDR_TYPE_RANDOM_STATIC: u8 = 0x01;
+const BLE_GAP_ADDR_TYPE_RANDOM_PRIVATE_RESOLVABLE: u8 = 0x02;
+const BLE_GAP_ADDR_TYPE_RANDOM_PRIVATE_NON_RESOLVABLE: u8 = 0x03;
+
+/// Error for [`Address::from_nrf`]
+#[derive(Debug)]
+pub enum NrfAddressError {
+    /// Unknown `addr_type`.
+    UnknownAddressType(UnknownAddressType),
+
+    /// `addr_type` does not match the tag bits.
+    InvalidBitsForAddressType(InvalidBitsForAddressType),
+}
+
+impl fmt::Display for NrfAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownAddressType(e) => e.fmt(f),
+            Self::InvalidBitsForAddressType(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NrfAddressError {}
+
+impl From<UnknownAddressType> for NrfAddressError {
+    fn from(e: UnknownAddressType) -> Self {
+        Self::UnknownAddressType(e)
+    }
+}
+
+impl From<InvalidBitsForAddressType> for NrfAddressError {
+    fn from(e: InvalidBitsForAddressType) -> Self {
+        Self::InvalidBitsForAddressType(e)
+    }
+}
+
+impl Address {
+    /// Construct from nRF SoftDevice `ble_gap_addr_t` (`addr_type`, `addr`).
+    ///
+    /// | `addr_type` | Address |
+    /// |-------------|---------|
+    /// | 0x00 `BLE_GAP_ADDR_TYPE_PUBLIC` | LE Public Device Address |
+    /// | 0x01 `BLE_GAP_ADDR_TYPE_RANDOM_STATIC` | LE Static Device Address |
+    /// | 0x02 `BLE_GAP_ADDR_TYPE_RANDOM_PRIVATE_RESOLVABLE` | LE Resolvable Private Address |
+    /// | 0x03 `BLE_GAP_ADDR_TYPE_RANDOM_PRIVATE_NON_RESOLVABLE` | LE Non-Resolvable Private Address |
+    ///
+    /// The tag bits of the random address are validated against `addr_type`.
+    pub fn from_nrf(addr_type: u8, addr: [u8; 6]) -> Result<Self, NrfAddressError> {
+        let random = match addr_type {
+            BLE_GAP_ADDR_TYPE_PUBLIC => return Ok(Self::LePublic(addr.into())),
+            BLE_GAP_ADDR_TYPE_RANDOM_STATIC => StaticDeviceAddress::try_from(addr)?.into(),
+            BLE_GAP_ADDR_TYPE_RANDOM_PRIVATE_RESOLVABLE => {
+                ResolvablePrivateAddress::try_from(addr)?.into()
+            }
+            BLE_GAP_ADDR_TYPE_RANDOM_PRIVATE_NON_RESOLVABLE => {
+                NonResolvablePrivateAddress::try_from(addr)?.into()
+            }
+            x => return Err(UnknownAddressType(x).into()),
+        };
+        Ok(Self::LeRandom(random))
+    }
+
+    /// Convert to nRF SoftDevice `ble_gap_addr_t` (`addr_type`, `addr`).
+    ///
+    /// BR/EDR Address and LE Random Device Address of unknown type are not supported.
+    pub fn to_nrf(&self) -> Result<(u8, [u8; 6]), UnsupportedAddressType> {
+        let (addr_type, BdAddr(addr)) = match self {
+            Self::LePublic(PublicDeviceAddress(addr)) => (BLE_GAP_ADDR_TYPE_PUBLIC, addr),
+            Self::LeRandom(RandomDeviceAddress::Static(StaticDeviceAddress(addr))) => {
+                (BLE_GAP_ADDR_TYPE_RANDOM_STATIC, addr)
+            }
+            Self::LeRandom(RandomDeviceAddress::Resolvable(ResolvablePrivateAddress(addr))) => {
+                (BLE_GAP_ADDR_TYPE_RANDOM_PRIVATE_RESOLVABLE, addr)
+            }
+            Self::LeRandom(RandomDeviceAddress::NonResolvable(NonResolvablePrivateAddress(
+                addr,
+            ))) => (BLE_GAP_ADDR_TYPE_RANDOM_PRIVATE_NON_RESOLVABLE, addr),
+            _ => return Err(UnsupportedAddressType(self.address_type())),
+        };
+        Ok((addr_type, *addr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        for (addr_type, addr, expect) in [
+            (
+                0x00,
+                [0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+                "LePublic(PublicDeviceAddress(55:44:33:22:11:00))",
+            ),
+            (
+                0x01,
+                [0x00, 0x11, 0x22, 0x33, 0x44, 0xF5],
+                "LeRandom(Static(StaticDeviceAddress(f5:44:33:22:11:00)))",
+            ),
+            (
+                0x02,
+                [0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+                "LeRandom(Resolvable(ResolvablePrivateAddress(55:44:33:22:11:00)))",
+            ),
+            (
+                0x03,
+                [0x00, 0x11, 0x22, 0x33, 0x44, 0x35],
+                "LeRandom(NonResolvable(NonResolvablePrivateAddress(35:44:33:22:11:00)))",
+            ),
+        ] {
+            let a = Address::from_nrf(addr_type, addr).unwrap();
+            assert_eq!(expect, &format!("{:?}", a));
+            assert_eq!((addr_type, addr), a.to_nrf().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_from_nrf_err() {
+        let err = Address::from_nrf(0x01, [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]).unwrap_err();
+        assert_eq!(
+            "Invalid bits for StaticDeviceAddress. (address: 55:44:33:22:11:00, expect: 0b11, but 0b01)",
+            &format!("{}", err)
+        );
+        assert!(matches!(
+            err,
+            NrfAddressError::InvalidBitsForAddressType(..)
+        ));
+
+        let err = Address::from_nrf(0x02, [0x00, 0x11, 0x22, 0x33, 0x44, 0xF5]).unwrap_err();
+        assert!(matches!(
+            err,
+            NrfAddressError::InvalidBitsForAddressType(..)
+        ));
+
+        let err = Address::from_nrf(0x03, [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]).unwrap_err();
+        assert!(matches!(
+            err,
+            NrfAddressError::InvalidBitsForAddressType(..)
+        ));
+
+        let err = Address::from_nrf(0x7F, [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]).unwrap_err();
+        assert_eq!("Unknown address type. (0x7f)", &format!("{}", err));
+        assert!(matches!(err, NrfAddressError::UnknownAddressType(..)));
+    }
+
+    #[test]
+    fn test_to_nrf_err() {
+        let err = Address::bredr_from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55])
+            .to_nrf()
+            .unwrap_err();
+        assert_eq!("Unsupported address type. (BrEdr)", &format!("{}", err));
+
+        let err = Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xB5])
+            .to_nrf()
+            .unwrap_err();
+        assert_eq!("Unsupported address type. (LeRandom)", &format!("{}", err));
+    }
+}
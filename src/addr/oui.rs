@@ -0,0 +1,181 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+use super::{AddressParseError, BdAddr};
+
+/// Set of OUI prefixes, for testing address vendor.
+///
+/// Prefixes are 24 bits (MA-L), or any multiple of 4 bits up to 48 when parsed from str
+/// (e.g. 28 bits MA-M `70:B3:D5:0`, 36 bits MA-S `70:B3:D5:04:2`).
+/// Duplicated prefixes are removed on construction.
+///
+/// ```
+/// use bdaddr::{BdAddr, OuiSet};
+///
+/// let set = OuiSet::from_strs(["A4:C1:38", "70:B3:D5:0"]).unwrap();
+/// assert!(set.contains(&"a4:c1:38:00:11:22".parse::<BdAddr>().unwrap()));
+/// assert!(set.contains(&"70:b3:d5:01:23:45".parse::<BdAddr>().unwrap()));
+/// assert!(!set.contains(&"70:b3:d5:11:23:45".parse::<BdAddr>().unwrap()));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OuiSet {
+    /// Sorted prefixes grouped by prefix length in bits. (ascending)
+    groups: Vec<(u32, Vec<u64>)>,
+}
+
+impl OuiSet {
+    /// Construct empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn from_prefixes(mut prefixes: Vec<(u32, u64)>) -> Self {
+        prefixes.sort_unstable();
+        prefixes.dedup();
+
+        let mut groups: Vec<(u32, Vec<u64>)> = vec![];
+        for (bits, prefix) in prefixes {
+            match groups.last_mut() {
+                Some((b, v)) if *b == bits => v.push(prefix),
+                _ => groups.push((bits, vec![prefix])),
+            }
+        }
+        Self { groups }
+    }
+
+    /// Parse prefixes from hex digits separated by `:` or `-`. (e.g. `A4:C1:38`)
+    pub fn from_strs<I, S>(iter: I) -> Result<Self, AddressParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let prefixes = iter
+            .into_iter()
+            .map(|s| parse_prefix(s.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_prefixes(prefixes))
+    }
+
+    /// Test address starts with any prefix.
+    pub fn contains(&self, addr: &BdAddr) -> bool {
+        let b = addr.0;
+        let v = u64::from_be_bytes([0, 0, b[5], b[4], b[3], b[2], b[1], b[0]]);
+        self.groups
+            .iter()
+            .any(|(bits, prefixes)| prefixes.binary_search(&(v >> (48 - bits))).is_ok())
+    }
+
+    /// Number of prefixes.
+    pub fn len(&self) -> usize {
+        self.groups.iter().map(|(_, v)| v.len()).sum()
+    }
+
+    /// Test empty.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
+
+fn parse_prefix(s: &str) -> Result<(u32, u64), AddressParseError> {
+    let mut bits = 0;
+    let mut prefix = 0;
+    for c in s.chars().filter(|c| *c != ':' && *c != '-') {
+        let d = c.to_digit(16).ok_or(AddressParseError::INVALID)?;
+        if bits == 48 {
+            return Err(AddressParseError::INVALID);
+        }
+        bits += 4;
+        prefix = (prefix << 4) | d as u64;
+    }
+    if bits == 0 {
+        return Err(AddressParseError::INVALID);
+    }
+    Ok((bits, prefix))
+}
+
+/// 24 bits prefixes in display order. (e.g. `[0xA4, 0xC1, 0x38]`)
+impl FromIterator<[u8; 3]> for OuiSet {
+    fn from_iter<T: IntoIterator<Item = [u8; 3]>>(iter: T) -> Self {
+        let prefixes = iter
+            .into_iter()
+            .map(|[a, b, c]| (24, u64::from_be_bytes([0, 0, 0, 0, 0, a, b, c])))
+            .collect();
+        Self::from_prefixes(prefixes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AddressParseErrorKind;
+
+    fn addr(s: &str) -> BdAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = [[0xa4, 0xc1, 0x38], [0xa4, 0xc1, 0x39], [0xa4, 0xc1, 0x38]]
+            .into_iter()
+            .collect::<OuiSet>();
+        assert_eq!(2, set.len());
+
+        assert!(set.contains(&addr("a4:c1:38:00:00:00")));
+        assert!(set.contains(&addr("a4:c1:38:ff:ff:ff")));
+        assert!(set.contains(&addr("a4:c1:39:00:00:00")));
+        assert!(set.contains(&addr("a4:c1:39:ff:ff:ff")));
+        assert!(!set.contains(&addr("a4:c1:37:ff:ff:ff")));
+        assert!(!set.contains(&addr("a4:c1:3a:00:00:00")));
+        assert!(!set.contains(&addr("a4:c2:38:00:00:00")));
+        assert!(!set.contains(&addr("38:c1:a4:00:00:00")));
+
+        assert!(OuiSet::new().is_empty());
+        assert!(!OuiSet::new().contains(&addr("a4:c1:38:00:00:00")));
+    }
+
+    #[test]
+    fn test_from_strs() {
+        let set =
+            OuiSet::from_strs(["A4:C1:38", "a4-c1-38", "70:B3:D5:0", "70:B3:D5:04:2"]).unwrap();
+        assert_eq!(3, set.len());
+
+        assert!(set.contains(&addr("a4:c1:38:12:34:56")));
+        assert!(set.contains(&addr("70:b3:d5:00:00:00")));
+        assert!(set.contains(&addr("70:b3:d5:0f:ff:ff")));
+        assert!(!set.contains(&addr("70:b3:d5:10:00:00")));
+        assert!(set.contains(&addr("70:b3:d5:04:20:00")));
+        assert!(set.contains(&addr("70:b3:d5:04:2f:ff")));
+        assert!(!set.contains(&addr("70:b3:d5:14:20:00")));
+        assert!(!set.contains(&addr("70:b3:d4:ff:ff:ff")));
+
+        let set = OuiSet::from_strs(["00:1A:7D:DA:71:13"]).unwrap();
+        assert!(set.contains(&addr("00:1a:7d:da:71:13")));
+        assert!(!set.contains(&addr("00:1a:7d:da:71:14")));
+
+        assert!(OuiSet::from_strs([""]).is_err());
+        assert!(OuiSet::from_strs(["A4:C1:3G"]).is_err());
+        assert!(OuiSet::from_strs(["00:1A:7D:DA:71:13:0"]).is_err());
+    }
+
+    #[test]
+    fn test_from_strs_adversarial() {
+        let long = "f".repeat(100_000);
+        for s in [
+            "",
+            ":",
+            "::--::",
+            "a4:c1:38:00:11:22:3",
+            "\u{ff10}",
+            "a4\u{0}c1",
+            long.as_str(),
+        ] {
+            let err = OuiSet::from_strs([s]).unwrap_err();
+            assert_eq!(AddressParseErrorKind::Invalid, err.kind(), "{:?}", s);
+        }
+        let set = OuiSet::from_strs(["a4:c1:38:00:11:22", "a"]).unwrap();
+        assert!(set.contains(&addr("a4:c1:38:00:11:22")));
+        assert!(set.contains(&addr("ab:cd:ef:00:11:22")));
+    }
+}
@@ -0,0 +1,101 @@
+//! `postgres-types` column representation.
+//!
+//! [`BdAddr`] is encoded as lowercase `TEXT` / `VARCHAR` (e.g. `00:1a:7d:da:71:13`),
+//! or `BYTEA` of 6 bytes in the same (wire) order as [`BdAddr`].
+
+use std::convert::TryFrom;
+use std::error::Error;
+
+use bytes::{BufMut, BytesMut};
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use super::BdAddr;
+
+impl ToSql for BdAddr {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        if *ty == Type::BYTEA {
+            out.put_slice(&self.0);
+        } else {
+            out.put_slice(self.to_string().as_bytes());
+        }
+        Ok(IsNull::No)
+    }
+
+    accepts!(TEXT, VARCHAR, BYTEA);
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for BdAddr {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        if *ty == Type::BYTEA {
+            return Ok(Self::try_from(raw)?);
+        }
+        let s = <&str as FromSql>::from_sql(ty, raw)?;
+        s.parse().map_err(|e| format!("{} ({:?})", e, s).into())
+    }
+
+    accepts!(TEXT, VARCHAR, BYTEA);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts() {
+        for ty in [Type::TEXT, Type::VARCHAR, Type::BYTEA] {
+            assert!(<BdAddr as ToSql>::accepts(&ty), "{}", ty);
+            assert!(<BdAddr as FromSql>::accepts(&ty), "{}", ty);
+        }
+        assert!(!<BdAddr as ToSql>::accepts(&Type::INT8));
+        assert!(!<BdAddr as FromSql>::accepts(&Type::INT8));
+    }
+
+    #[test]
+    fn test_to_sql() {
+        let addr = "00:1A:7D:DA:71:13".parse::<BdAddr>().unwrap();
+        for ty in [Type::TEXT, Type::VARCHAR] {
+            let mut buf = BytesMut::new();
+            addr.to_sql_checked(&ty, &mut buf).unwrap();
+            assert_eq!(b"00:1a:7d:da:71:13", &buf[..]);
+        }
+
+        let mut buf = BytesMut::new();
+        addr.to_sql_checked(&Type::BYTEA, &mut buf).unwrap();
+        assert_eq!([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00], &buf[..]);
+
+        let mut buf = BytesMut::new();
+        assert!(addr.to_sql_checked(&Type::INT8, &mut buf).is_err());
+    }
+
+    #[test]
+    fn test_from_sql() {
+        let addr = BdAddr::from_sql(&Type::TEXT, b"00:1A:7D:DA:71:13").unwrap();
+        assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+
+        let addr = BdAddr::from_sql(&Type::BYTEA, &[0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]).unwrap();
+        assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+    }
+
+    #[test]
+    fn test_from_sql_err() {
+        let err = BdAddr::from_sql(&Type::VARCHAR, b"00:1a:7d:da:71").unwrap_err();
+        assert_eq!(
+            "wrong group count (expected 6, found 5) at offset 14 (\"00:1a:7d:da:71\")",
+            &format!("{}", err)
+        );
+
+        let err = BdAddr::from_sql(&Type::BYTEA, &[0x13, 0x71, 0xda, 0x7d, 0x1a]).unwrap_err();
+        assert_eq!(
+            "Invalid address length. (expect: 6, but 5)",
+            &format!("{}", err)
+        );
+
+        assert!(BdAddr::from_sql(&Type::TEXT, &[0xff; 17]).is_err());
+    }
+}
@@ -0,0 +1,226 @@
+//! Python bindings.
+//!
+//! Build as extension module `bdaddr` with `maturin` (`--features python`).
+//!
+//! ```python
+//! from bdaddr import Address, BdAddr
+//!
+//! addr = Address("53:03:8c:bc:bd:82", "random")
+//! assert addr.kind == "resolvable"
+//! assert addr.matches(bytes.fromhex("1978a2afdd757bedfc9dc69e95d733b3"))
+//! assert BdAddr(bytes([0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53])) == addr.bd_addr
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::PyBytes;
+
+use super::matches::irk_from_slice;
+use super::{Address, AddressType, BdAddr, RandomDeviceAddress};
+
+fn extract_bd_addr(v: &Bound<'_, PyAny>) -> PyResult<BdAddr> {
+    if let Ok(b) = v.cast::<PyBytes>() {
+        let b = b.as_bytes();
+        return BdAddr::try_from(b).map_err(|e| PyValueError::new_err(e.to_string()));
+    }
+    let s = v.extract::<String>()?;
+    s.parse()
+        .map_err(|e| PyValueError::new_err(format!("{} ({:?})", e, s)))
+}
+
+fn richcmp<T: PartialEq>(a: &T, b: &T, op: CompareOp) -> PyResult<bool> {
+    match op {
+        CompareOp::Eq => Ok(a == b),
+        CompareOp::Ne => Ok(a != b),
+        _ => Err(PyTypeError::new_err("ordering is not supported")),
+    }
+}
+
+fn hash<T: Hash>(v: &T) -> u64 {
+    let mut h = DefaultHasher::new();
+    v.hash(&mut h);
+    h.finish()
+}
+
+/// Bluetooth Device Address without Address type.
+///
+/// Construct from str (`00:1a:7d:da:71:13`) or 6 bytes in little-endian (wire) order.
+#[pyclass(name = "BdAddr", module = "bdaddr", frozen)]
+pub struct PyBdAddr(BdAddr);
+
+#[pymethods]
+impl PyBdAddr {
+    #[new]
+    fn new(v: &Bound<'_, PyAny>) -> PyResult<Self> {
+        extract_bd_addr(v).map(Self)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("BdAddr('{}')", self.0)
+    }
+
+    fn __bytes__<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0 .0)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<bool> {
+        richcmp(&self.0, &other.0, op)
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash(&self.0)
+    }
+}
+
+/// Bluetooth Device Address.
+///
+/// Construct from address (str or bytes) and address type (`bredr`, `public` or `random`).
+#[pyclass(name = "Address", module = "bdaddr", frozen)]
+pub struct PyAddress(Address);
+
+#[pymethods]
+impl PyAddress {
+    #[new]
+    fn new(addr: &Bound<'_, PyAny>, address_type: &str) -> PyResult<Self> {
+        let addr = extract_bd_addr(addr)?;
+        let ty = address_type
+            .parse::<AddressType>()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(Self(addr.to_addr(ty)))
+    }
+
+    /// Address type. (`bredr`, `public` or `random`)
+    #[getter]
+    fn address_type(&self) -> &'static str {
+        self.0.address_type().as_str()
+    }
+
+    /// Kind of LE Random Device Address.
+    /// (`static`, `resolvable`, `non-resolvable` or `unknown`, or `None` for others)
+    #[getter]
+    fn kind(&self) -> Option<String> {
+        match &self.0 {
+            Address::LeRandom(addr) => Some(addr.kind().to_string()),
+            _ => None,
+        }
+    }
+
+    /// Address without address type.
+    #[getter]
+    fn bd_addr(&self) -> PyBdAddr {
+        PyBdAddr(self.0.into_bd_addr())
+    }
+
+    /// Test Resolvable Private Address matches Identity Resolving Key. (16 bytes)
+    ///
+    /// Always `False` for other addresses.
+    fn matches(&self, irk: &[u8]) -> PyResult<bool> {
+        let irk = irk_from_slice(irk).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        match &self.0 {
+            Address::LeRandom(RandomDeviceAddress::Resolvable(addr)) => Ok(addr.matches(irk)),
+            _ => Ok(false),
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Address('{}', '{}')", self.0, self.address_type())
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<bool> {
+        richcmp(&self.0, &other.0, op)
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash(&self.0)
+    }
+}
+
+/// `bdaddr` extension module.
+#[pymodule(name = "bdaddr")]
+pub fn py_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyBdAddr>()?;
+    m.add_class::<PyAddress>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(code: &str) {
+        Python::initialize();
+        Python::attach(|py| {
+            let m = PyModule::new(py, "bdaddr").unwrap();
+            py_module(&m).unwrap();
+            let locals = m.dict();
+            pyo3::py_run!(py, *locals, code);
+        });
+    }
+
+    #[test]
+    fn test_bd_addr() {
+        run(r#"
+            addr = BdAddr("00:1A:7D:DA:71:13")
+            assert str(addr) == "00:1a:7d:da:71:13"
+            assert repr(addr) == "BdAddr('00:1a:7d:da:71:13')"
+            assert bytes(addr) == bytes([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00])
+            assert addr == BdAddr(bytes(addr))
+            assert addr != BdAddr("00:1a:7d:da:71:14")
+            assert len({addr, BdAddr(bytes(addr))}) == 1
+        "#);
+    }
+
+    #[test]
+    fn test_address() {
+        run(r#"
+            addr = Address("00:1a:7d:da:71:13", "public")
+            assert addr.address_type == "public"
+            assert addr.kind is None
+            assert addr != Address("00:1a:7d:da:71:13", "bredr")
+            assert addr.bd_addr == Address("00:1a:7d:da:71:13", "bredr").bd_addr
+            assert Address("f5:44:33:22:11:00", "random").kind == "static"
+            assert Address("35:44:33:22:11:00", "random").kind == "non-resolvable"
+        "#);
+    }
+
+    #[test]
+    fn test_matches() {
+        run(r#"
+            addr = Address("53:03:8c:bc:bd:82", "random")
+            assert addr.kind == "resolvable"
+            assert addr.matches(bytes([25, 120, 162, 175, 221, 117, 123, 237, 252, 157, 198, 158, 149, 215, 51, 179]))
+            assert not addr.matches(bytes([26, 120, 162, 175, 221, 117, 123, 237, 252, 157, 198, 158, 149, 215, 51, 179]))
+            assert not Address("53:03:8c:bc:bd:82", "public").matches(bytes(16))
+        "#);
+    }
+
+    #[test]
+    fn test_err() {
+        run(r#"
+            def error(f, *args):
+                try:
+                    f(*args)
+                except ValueError as e:
+                    return str(e)
+                raise AssertionError("no error")
+
+            assert error(BdAddr, "00:1a:7d:da:71") == 'wrong group count (expected 6, found 5) at offset 14 ("00:1a:7d:da:71")'
+            assert error(BdAddr, bytes(5)) == "Invalid address length. (expect: 6, but 5)"
+            assert error(Address, "00:1a:7d:da:71:13", "le") == 'Unknown address type. ("le")'
+            assert error(Address("53:03:8c:bc:bd:82", "random").matches, bytes(15)) == "Invalid IRK length. (expect: 16, but 15)"
+        "#);
+    }
+}
@@ -0,0 +1,186 @@
+//! Redis value representation.
+//!
+//! [`BdAddr`] is written as lowercase string. (e.g. `00:1a:7d:da:71:13`)
+//! [`Address`] is written as string with address type prefix `bredr/`, `public/` or `random/`.
+//! (e.g. `random/f5:44:33:22:11:00`)
+//!
+//! Compact binary forms are also accepted on read.
+//!
+//! | Type | Binary |
+//! |------|--------|
+//! | [`BdAddr`] | 6 bytes in the same (wire) order as [`BdAddr`] |
+//! | [`Address`] | 7 bytes of [`Address::to_tagged_bytes`] (0x00 BR/EDR, 0x01 LE Public, 0x02 LE Random) |
+//!
+//! The binary form of [`Address`] is not the HCI form of [`Address::to_hci_bytes`] (0x00 Public, 0x01 Random)
+//! and cannot be distinguished from it. Do not store `to_hci_bytes` blobs, or an LE Public Device Address
+//! is read back as BR/EDR.
+
+use std::convert::TryFrom;
+use std::str;
+
+use redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, Value};
+
+use super::{Address, AddressFromStrError, BdAddr};
+
+fn value_bytes(v: &Value) -> Result<&[u8], ParsingError> {
+    match v {
+        Value::BulkString(b) => Ok(b),
+        Value::SimpleString(s) => Ok(s.as_bytes()),
+        Value::VerbatimString { text, .. } => Ok(text.as_bytes()),
+        v => Err(format!("Response type not convertible to address. ({:?})", v).into()),
+    }
+}
+
+fn parse_bd_addr(b: &[u8]) -> Result<BdAddr, ParsingError> {
+    if let Ok(b) = <[u8; 6]>::try_from(b) {
+        return Ok(BdAddr(b));
+    }
+    let s = str::from_utf8(b)?;
+    s.parse().map_err(|e| format!("{} ({:?})", e, s).into())
+}
+
+impl ToRedisArgs for BdAddr {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg_fmt(self)
+    }
+}
+
+impl FromRedisValue for BdAddr {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        Self::from_redis_value_ref(&v)
+    }
+
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        parse_bd_addr(value_bytes(v)?)
+    }
+}
+
+impl ToRedisArgs for Address {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg_fmt(format_args!("{}/{}", self.address_type(), self))
+    }
+}
+
+impl FromRedisValue for Address {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        Self::from_redis_value_ref(&v)
+    }
+
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        let b = value_bytes(v)?;
+        if let Ok(b) = <[u8; 7]>::try_from(b) {
+            return Self::from_tagged_bytes(b).map_err(|e| e.to_string().into());
+        }
+        let s = str::from_utf8(b)?;
+        s.parse().map_err(|e| match e {
+            AddressFromStrError::UnknownAddressType(e) => e.to_string().into(),
+            e => format!("{} ({:?})", e, s).into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bd_addr() {
+        let addr = "00:1A:7D:DA:71:13".parse::<BdAddr>().unwrap();
+        assert_eq!(vec![b"00:1a:7d:da:71:13".to_vec()], addr.to_redis_args());
+
+        let v = Value::BulkString(b"00:1a:7d:da:71:13".to_vec());
+        assert_eq!(addr, BdAddr::from_redis_value(v).unwrap());
+
+        let v = Value::SimpleString("00:1a:7d:da:71:13".into());
+        assert_eq!(addr, BdAddr::from_redis_value(v).unwrap());
+
+        let v = Value::BulkString(vec![0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+        assert_eq!(addr, BdAddr::from_redis_value(v).unwrap());
+    }
+
+    #[test]
+    fn test_address() {
+        for (s, addr) in [
+            (
+                "bredr/00:1a:7d:da:71:13",
+                Address::bredr_from_str("00:1a:7d:da:71:13").unwrap(),
+            ),
+            (
+                "public/00:1a:7d:da:71:13",
+                Address::le_public_from_str("00:1a:7d:da:71:13").unwrap(),
+            ),
+            (
+                "random/f5:44:33:22:11:00",
+                Address::le_random_from_str("f5:44:33:22:11:00").unwrap(),
+            ),
+        ] {
+            assert_eq!(vec![s.as_bytes().to_vec()], addr.to_redis_args());
+            let v = Value::BulkString(addr.to_redis_args().remove(0));
+            assert_eq!(addr, Address::from_redis_value(v).unwrap());
+        }
+
+        let v = Value::BulkString(vec![0x02, 0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]);
+        assert_eq!(
+            Address::le_random_from_str("f5:44:33:22:11:00").unwrap(),
+            Address::from_redis_value(v).unwrap()
+        );
+        // 0x01 is LE Public, unlike HCI `Peer_Address_Type` (0x00)
+        let addr = Address::le_public_from_str("00:1a:7d:da:71:13").unwrap();
+        let v = Value::BulkString(vec![0x01, 0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+        assert_eq!(addr, Address::from_redis_value(v).unwrap());
+        let v = Value::BulkString(addr.to_hci_bytes().unwrap().to_vec());
+        assert_eq!(
+            Address::bredr_from_str("00:1a:7d:da:71:13").unwrap(),
+            Address::from_redis_value(v).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_err() {
+        let err =
+            BdAddr::from_redis_value(Value::BulkString(b"00:1a:7d:da:71".to_vec())).unwrap_err();
+        assert_eq!(
+            "Incompatible type - wrong group count (expected 6, found 5) at offset 14 (\"00:1a:7d:da:71\")",
+            &format!("{}", err)
+        );
+
+        let err = BdAddr::from_redis_value(Value::Int(1)).unwrap_err();
+        assert_eq!(
+            "Incompatible type - Response type not convertible to address. (int(1))",
+            &format!("{}", err)
+        );
+
+        let err = Address::from_redis_value(Value::BulkString(b"00:1a:7d:da:71:13".to_vec()))
+            .unwrap_err();
+        assert_eq!(
+            "Incompatible type - expect <address type>/<address>. (\"00:1a:7d:da:71:13\")",
+            &format!("{}", err)
+        );
+
+        let err = Address::from_redis_value(Value::BulkString(b"le/00:1a:7d:da:71:13".to_vec()))
+            .unwrap_err();
+        assert_eq!(
+            "Incompatible type - Unknown address type. (\"le\")",
+            &format!("{}", err)
+        );
+
+        let err = Address::from_redis_value(Value::BulkString(b"public/00:1a:7d:da:71".to_vec()))
+            .unwrap_err();
+        assert_eq!(
+            "Incompatible type - wrong group count (expected 6, found 5) at offset 21 (\"public/00:1a:7d:da:71\")",
+            &format!("{}", err)
+        );
+
+        let err = Address::from_redis_value(Value::BulkString(vec![0x03; 7])).unwrap_err();
+        assert_eq!(
+            "Incompatible type - Unknown address type. (0x03)",
+            &format!("{}", err)
+        );
+    }
+}
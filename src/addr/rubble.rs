@@ -0,0 +1,80 @@
+//! `rubble::link::DeviceAddress` holds the octets in the same (wire) order as [`BdAddr`].
+
+use core::convert::TryFrom;
+
+use rubble::link::{AddressKind, DeviceAddress};
+
+use super::{Address, AddressType, BdAddr, UnsupportedAddressType};
+
+/// `AddressKind::Random` is classified by the tag bits.
+impl From<DeviceAddress> for Address {
+    fn from(v: DeviceAddress) -> Self {
+        let addr = BdAddr(*v.raw());
+        match v.kind() {
+            AddressKind::Public => addr.to_le_public_addr(),
+            AddressKind::Random => addr.to_le_random_addr(),
+        }
+    }
+}
+
+impl TryFrom<Address> for DeviceAddress {
+    type Error = UnsupportedAddressType;
+
+    fn try_from(v: Address) -> Result<Self, Self::Error> {
+        let kind = match v.address_type() {
+            AddressType::LePublic => AddressKind::Public,
+            AddressType::LeRandom => AddressKind::Random,
+            ty @ AddressType::BrEdr => return Err(UnsupportedAddressType(ty)),
+        };
+        Ok(Self::new(v.into_bd_addr().0, kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RandomDeviceAddress;
+
+    #[test]
+    fn test_roundtrip() {
+        let v = DeviceAddress::new([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00], AddressKind::Public);
+        let addr = Address::from(v);
+        assert_eq!(
+            Address::le_public_from_str("00:1a:7d:da:71:13").unwrap(),
+            addr
+        );
+        let back = DeviceAddress::try_from(addr).unwrap();
+        assert!(back == v);
+
+        for (b, kind) in [
+            ([0x00, 0x11, 0x22, 0x33, 0x44, 0x35], "NonResolvable"),
+            ([0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53], "Resolvable"),
+            ([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5], "Static"),
+            ([0x00, 0x11, 0x22, 0x33, 0x44, 0xb5], "Unknown"),
+        ] {
+            let v = DeviceAddress::new(b, AddressKind::Random);
+            let addr = Address::from(v);
+            match &addr {
+                Address::LeRandom(r) => assert_eq!(kind, format!("{:?}", r.kind())),
+                x => panic!("{:?}", x),
+            }
+            let back = DeviceAddress::try_from(addr).unwrap();
+            assert!(back.is_random());
+            assert_eq!(&b, back.raw());
+        }
+        assert!(matches!(
+            Address::from(DeviceAddress::new(
+                [0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53],
+                AddressKind::Random
+            )),
+            Address::LeRandom(RandomDeviceAddress::Resolvable(..))
+        ));
+    }
+
+    #[test]
+    fn test_try_from_err() {
+        let addr = Address::bredr_from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]);
+        let err = DeviceAddress::try_from(addr).unwrap_err();
+        assert_eq!("Unsupported address type. (BrEdr)", &err.to_string());
+    }
+}
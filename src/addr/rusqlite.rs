@@ -0,0 +1,196 @@
+//! SQLite column representation.
+//!
+//! [`BdAddr`] is stored as lowercase TEXT. (e.g. `00:1a:7d:da:71:13`)
+//! BLOB of 6 bytes in the same (wire) order as [`BdAddr`] is also accepted on read.
+//!
+//! [`Address`] is stored as two columns, address and [`AddressType`].
+//!
+//! ```ignore
+//! conn.execute(
+//!     "CREATE TABLE device (addr TEXT NOT NULL, addr_type TEXT NOT NULL, PRIMARY KEY (addr, addr_type))",
+//!     [],
+//! )?;
+//! let (a, t) = (addr.clone().into_bd_addr(), addr.address_type());
+//! conn.execute("INSERT INTO device VALUES (?1, ?2)", (a, t))?;
+//! let addr = conn.query_row("SELECT addr, addr_type FROM device", [], |row| {
+//!     Address::from_row(row, 0, 1)
+//! })?;
+//! ```
+
+use std::convert::TryFrom;
+
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use rusqlite::{Row, RowIndex};
+
+use super::{Address, AddressType, BdAddr};
+
+impl ToSql for BdAddr {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+impl FromSql for BdAddr {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Text(..) => {
+                let s = value.as_str()?;
+                s.parse()
+                    .map_err(|e| FromSqlError::Other(format!("{} ({:?})", e, s).into()))
+            }
+            ValueRef::Blob(b) => {
+                <[u8; 6]>::try_from(b)
+                    .map(Self)
+                    .map_err(|_| FromSqlError::InvalidBlobSize {
+                        expected_size: 6,
+                        blob_size: b.len(),
+                    })
+            }
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+/// Stored as TEXT `bredr`, `public` or `random`.
+impl ToSql for AddressType {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.as_str()))
+    }
+}
+
+impl FromSql for AddressType {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let s = value.as_str()?;
+        s.parse().map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
+
+impl Address {
+    /// Read from address column and [`AddressType`] column of row.
+    pub fn from_row<I: RowIndex>(row: &Row<'_>, addr: I, ty: I) -> rusqlite::Result<Self> {
+        let addr = row.get::<_, BdAddr>(addr)?;
+        Ok(addr.to_addr(row.get(ty)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    fn setup() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute(
+            "CREATE TABLE device (addr TEXT NOT NULL, addr_type TEXT NOT NULL, PRIMARY KEY (addr, addr_type))",
+            [],
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_bd_addr() {
+        let conn = setup();
+        let addr = "00:1A:7D:DA:71:13".parse::<BdAddr>().unwrap();
+        conn.execute(
+            "INSERT INTO device VALUES (?1, ?2)",
+            (&addr, AddressType::BrEdr),
+        )
+        .unwrap();
+
+        let s = conn
+            .query_row("SELECT addr FROM device", [], |row| row.get::<_, String>(0))
+            .unwrap();
+        assert_eq!("00:1a:7d:da:71:13", s);
+
+        let found = conn
+            .query_row("SELECT addr FROM device WHERE addr = ?1", [&addr], |row| {
+                row.get::<_, BdAddr>(0)
+            })
+            .unwrap();
+        assert_eq!(addr, found);
+    }
+
+    #[test]
+    fn test_blob() {
+        let conn = Connection::open_in_memory().unwrap();
+        let addr = conn
+            .query_row("SELECT x'1371da7d1a00'", [], |row| row.get::<_, BdAddr>(0))
+            .unwrap();
+        assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+
+        let err = conn
+            .query_row("SELECT x'1371da7d1a'", [], |row| row.get::<_, BdAddr>(0))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            rusqlite::Error::FromSqlConversionFailure(0, _, _)
+        ));
+    }
+
+    #[test]
+    fn test_address() {
+        let conn = setup();
+        let addrs = [
+            Address::bredr_from_str("00:1a:7d:da:71:13").unwrap(),
+            Address::le_public_from_str("00:1a:7d:da:71:13").unwrap(),
+            Address::le_random_from_str("f5:44:33:22:11:00").unwrap(),
+        ];
+        for addr in &addrs {
+            conn.execute(
+                "INSERT INTO device VALUES (?1, ?2)",
+                (addr.into_bd_addr(), addr.address_type()),
+            )
+            .unwrap();
+        }
+
+        let mut stmt = conn
+            .prepare("SELECT addr, addr_type FROM device ORDER BY rowid")
+            .unwrap();
+        let found = stmt
+            .query_map([], |row| Address::from_row(row, 0, 1))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(&addrs[..], &found[..]);
+
+        let n = conn
+            .query_row(
+                "SELECT count(*) FROM device WHERE addr = ?1",
+                ["00:1a:7d:da:71:13".parse::<BdAddr>().unwrap()],
+                |row| row.get::<_, u32>(0),
+            )
+            .unwrap();
+        assert_eq!(2, n);
+    }
+
+    #[test]
+    fn test_err() {
+        let conn = Connection::open_in_memory().unwrap();
+        let err = conn
+            .query_row("SELECT '00:1a:7d:da:71'", [], |row| row.get::<_, BdAddr>(0))
+            .unwrap_err();
+        assert_eq!(
+            "Conversion error from type Text at index: 0, wrong group count (expected 6, found 5) at offset 14 (\"00:1a:7d:da:71\")",
+            &format!("{}", err)
+        );
+
+        let err = conn
+            .query_row("SELECT '00:1a:7d:da:71:13', 'le'", [], |row| {
+                Address::from_row(row, 0, 1)
+            })
+            .unwrap_err();
+        assert_eq!(
+            "Conversion error from type Text at index: 1, Unknown address type. (\"le\")",
+            &format!("{}", err)
+        );
+
+        let err = conn
+            .query_row("SELECT 1", [], |row| row.get::<_, BdAddr>(0))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            rusqlite::Error::InvalidColumnType(0, _, rusqlite::types::Type::Integer)
+        ));
+    }
+}
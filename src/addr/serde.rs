@@ -0,0 +1,1059 @@
+//! Helper modules for `#[serde(with = ...)]`, regardless of the format is human-readable or not.
+//!
+//! | Type        | String                                         | Bytes                                                             |
+//! |-------------|------------------------------------------------|-------------------------------------------------------------------|
+//! | [`BdAddr`]  | `00:1a:7d:da:71:13`                            | 6 bytes in little-endian (wire) order                             |
+//! | [`Address`] | `bredr/…`, `public/…` or `random/…` prefixed   | 7 bytes of [`Address::to_tagged_bytes`] (not HCI form)             |
+//!
+//! `_opt` modules are for `Option<_>` (`null` as `None`), and `_vec` modules are for `Vec<_>`.
+//! [`split`] is for BlueZ style two adjacent fields of [`Address`].
+//!
+//! [`BdAddr`] and the typed addresses (e.g. [`StaticDeviceAddress`]) also implement `Serialize` / `Deserialize`,
+//! as string for human-readable formats (e.g. JSON), otherwise as 6 bytes tuple in little-endian (wire) order (e.g. bincode).
+//! [`Address`] is the same as the table above, see its `Serialize` impl.
+//!
+//! ```
+//! use bdaddr::{Address, BdAddr};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Device {
+//!     #[serde(with = "bdaddr::serde::string")]
+//!     addr: BdAddr,
+//!     #[serde(default, with = "bdaddr::serde::string_opt")]
+//!     peer: Option<Address>,
+//!     #[serde(with = "bdaddr::serde::bytes_vec")]
+//!     bonded: Vec<BdAddr>,
+//! }
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, SeqAccess, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    Address, AddressFromStrError, AddressParseError, AddressType, BdAddr,
+    NonResolvablePrivateAddress, PublicDeviceAddress, ResolvablePrivateAddress,
+    StaticDeviceAddress,
+};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::BdAddr {}
+    impl Sealed for super::Address {}
+}
+
+/// [`BdAddr`] or [`Address`], for use with helper modules.
+pub trait SerdeAddress: Sized + sealed::Sealed {
+    #[doc(hidden)]
+    fn to_repr_string(&self) -> String;
+
+    #[doc(hidden)]
+    fn from_repr_str(s: &str) -> Result<Self, String>;
+
+    #[doc(hidden)]
+    fn to_repr_bytes(&self) -> Vec<u8>;
+
+    #[doc(hidden)]
+    fn from_repr_bytes(b: &[u8]) -> Result<Self, String>;
+}
+
+fn bd_addr_from_bytes(b: &[u8]) -> Result<BdAddr, String> {
+    BdAddr::try_from(b).map_err(|e| e.to_string())
+}
+
+impl SerdeAddress for BdAddr {
+    fn to_repr_string(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_repr_str(s: &str) -> Result<Self, String> {
+        s.parse().map_err(|e| format!("{} ({:?})", e, s))
+    }
+
+    fn to_repr_bytes(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+
+    fn from_repr_bytes(b: &[u8]) -> Result<Self, String> {
+        bd_addr_from_bytes(b)
+    }
+}
+
+impl SerdeAddress for Address {
+    fn to_repr_string(&self) -> String {
+        format!("{}/{}", self.address_type(), self)
+    }
+
+    fn from_repr_str(s: &str) -> Result<Self, String> {
+        s.parse().map_err(|e| match e {
+            // already names the input
+            AddressFromStrError::UnknownAddressType(e) => e.to_string(),
+            e => format!("{} ({:?})", e, s),
+        })
+    }
+
+    fn to_repr_bytes(&self) -> Vec<u8> {
+        self.to_tagged_bytes().to_vec()
+    }
+
+    fn from_repr_bytes(b: &[u8]) -> Result<Self, String> {
+        let b = <[u8; 7]>::try_from(b)
+            .map_err(|_| format!("Invalid address length. (expect: 7, but {})", b.len()))?;
+        Self::from_tagged_bytes(b).map_err(|e| e.to_string())
+    }
+}
+
+/// String (e.g. `55:44:33:22:11:00`) for human-readable formats, otherwise 6 bytes tuple in little-endian (wire) order.
+impl Serialize for BdAddr {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        if s.is_human_readable() {
+            s.collect_str(self)
+        } else {
+            self.0.serialize(s)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BdAddr {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        if d.is_human_readable() {
+            RawStr::deserialize(d)?.0.parse().map_err(de::Error::custom)
+        } else {
+            <[u8; 6]>::deserialize(d).map(Self)
+        }
+    }
+}
+
+/// String with address type prefix for human-readable formats, otherwise 7 bytes tuple of address type and address.
+///
+/// This format is stable across versions.
+///
+/// | Variant    | String                     | Bytes                                        |
+/// |------------|----------------------------|----------------------------------------------|
+/// | `BrEdr`    | `bredr/55:44:33:22:11:00`  | `0x00` + 6 bytes in little-endian (wire) order |
+/// | `LePublic` | `public/55:44:33:22:11:00` | `0x01` + 6 bytes in little-endian (wire) order |
+/// | `LeRandom` | `random/f5:44:33:22:11:00` | `0x02` + 6 bytes in little-endian (wire) order |
+///
+/// The bytes are [`Address::to_tagged_bytes`], not [`Address::to_hci_bytes`].
+/// The kind of random address is classified by the tag bits on deserialize.
+impl Serialize for Address {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        if s.is_human_readable() {
+            s.serialize_str(&self.to_repr_string())
+        } else {
+            let [ty, b0, b1, b2, b3, b4, b5] = self.to_tagged_bytes();
+            (ty, [b0, b1, b2, b3, b4, b5]).serialize(s)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        if d.is_human_readable() {
+            RawStr::deserialize(d)?.0.parse().map_err(de::Error::custom)
+        } else {
+            let (ty, [b0, b1, b2, b3, b4, b5]) = <(u8, [u8; 6])>::deserialize(d)?;
+            Self::from_tagged_bytes([ty, b0, b1, b2, b3, b4, b5]).map_err(de::Error::custom)
+        }
+    }
+}
+
+macro_rules! impl_for_typed {
+    ($($ty:ident),*) => {
+        $(
+            /// Same as [`BdAddr`]. Tag bits are validated on deserialize.
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                    self.0.serialize(s)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                    let addr = BdAddr::deserialize(d)?;
+                    Self::try_from(addr.0).map_err(de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_typed!(
+    PublicDeviceAddress,
+    NonResolvablePrivateAddress,
+    ResolvablePrivateAddress,
+    StaticDeviceAddress
+);
+
+struct Str<'a, T>(&'a T);
+
+impl<T: SerdeAddress> Serialize for Str<'_, T> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&self.0.to_repr_string())
+    }
+}
+
+struct Bytes<'a, T>(&'a T);
+
+impl<T: SerdeAddress> Serialize for Bytes<'_, T> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_bytes(&self.0.to_repr_bytes())
+    }
+}
+
+/// Raw string or bytes, not parsed yet.
+enum Raw {
+    Str(String),
+    Bytes(Vec<u8>),
+}
+
+impl Raw {
+    fn parse<T: SerdeAddress>(&self) -> Result<T, String> {
+        match self {
+            Self::Str(s) => T::from_repr_str(s),
+            Self::Bytes(b) => T::from_repr_bytes(b),
+        }
+    }
+}
+
+struct StrVisitor;
+
+impl<'de> Visitor<'de> for StrVisitor {
+    type Value = Raw;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Raw::Str(v.to_string()))
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Raw;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Raw::Bytes(v.to_vec()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut b = vec![];
+        while let Some(v) = seq.next_element()? {
+            b.push(v);
+        }
+        Ok(Raw::Bytes(b))
+    }
+}
+
+struct RawStr(Raw);
+
+impl<'de> Deserialize<'de> for RawStr {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_str(StrVisitor).map(Self)
+    }
+}
+
+struct RawBytes(Raw);
+
+impl<'de> Deserialize<'de> for RawBytes {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_bytes(BytesVisitor).map(Self)
+    }
+}
+
+struct VecVisitor<T, R>(PhantomData<(T, R)>);
+
+impl<'de, T, R> Visitor<'de> for VecVisitor<T, R>
+where
+    T: SerdeAddress,
+    R: Deserialize<'de> + Into<Raw>,
+{
+    type Value = Vec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("sequence of addresses")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(raw) = seq.next_element::<R>()? {
+            let addr = raw
+                .into()
+                .parse()
+                .map_err(|e| de::Error::custom(format!("{} at index {}", e, v.len())))?;
+            v.push(addr);
+        }
+        Ok(v)
+    }
+}
+
+impl From<RawStr> for Raw {
+    fn from(v: RawStr) -> Self {
+        v.0
+    }
+}
+
+impl From<RawBytes> for Raw {
+    fn from(v: RawBytes) -> Self {
+        v.0
+    }
+}
+
+/// String representation.
+pub mod string {
+    use super::*;
+
+    /// Serialize as string.
+    pub fn serialize<T: SerdeAddress, S: Serializer>(v: &T, s: S) -> Result<S::Ok, S::Error> {
+        Str(v).serialize(s)
+    }
+
+    /// Deserialize from string.
+    pub fn deserialize<'de, T: SerdeAddress, D: Deserializer<'de>>(d: D) -> Result<T, D::Error> {
+        RawStr::deserialize(d)?.0.parse().map_err(de::Error::custom)
+    }
+}
+
+/// String representation for `Option<_>`.
+pub mod string_opt {
+    use super::*;
+
+    /// Serialize as string or none.
+    pub fn serialize<T: SerdeAddress, S: Serializer>(
+        v: &Option<T>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        v.as_ref().map(Str).serialize(s)
+    }
+
+    /// Deserialize from string or none.
+    pub fn deserialize<'de, T: SerdeAddress, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Option<T>, D::Error> {
+        Option::<RawStr>::deserialize(d)?
+            .map(|v| v.0.parse().map_err(de::Error::custom))
+            .transpose()
+    }
+}
+
+/// String representation for `Vec<_>`.
+pub mod string_vec {
+    use super::*;
+
+    /// Serialize as sequence of strings.
+    pub fn serialize<T: SerdeAddress, S: Serializer>(v: &[T], s: S) -> Result<S::Ok, S::Error> {
+        s.collect_seq(v.iter().map(Str))
+    }
+
+    /// Deserialize from sequence of strings.
+    ///
+    /// Error message includes index of the malformed element.
+    pub fn deserialize<'de, T: SerdeAddress, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Vec<T>, D::Error> {
+        d.deserialize_seq(VecVisitor::<T, RawStr>(PhantomData))
+    }
+}
+
+/// Bytes representation.
+pub mod bytes {
+    use super::*;
+
+    /// Serialize as bytes.
+    pub fn serialize<T: SerdeAddress, S: Serializer>(v: &T, s: S) -> Result<S::Ok, S::Error> {
+        Bytes(v).serialize(s)
+    }
+
+    /// Deserialize from bytes or sequence of `u8`.
+    pub fn deserialize<'de, T: SerdeAddress, D: Deserializer<'de>>(d: D) -> Result<T, D::Error> {
+        RawBytes::deserialize(d)?
+            .0
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Bytes representation for `Option<_>`.
+pub mod bytes_opt {
+    use super::*;
+
+    /// Serialize as bytes or none.
+    pub fn serialize<T: SerdeAddress, S: Serializer>(
+        v: &Option<T>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        v.as_ref().map(Bytes).serialize(s)
+    }
+
+    /// Deserialize from bytes (or sequence of `u8`) or none.
+    pub fn deserialize<'de, T: SerdeAddress, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Option<T>, D::Error> {
+        Option::<RawBytes>::deserialize(d)?
+            .map(|v| v.0.parse().map_err(de::Error::custom))
+            .transpose()
+    }
+}
+
+/// Bytes representation for `Vec<_>`.
+pub mod bytes_vec {
+    use super::*;
+
+    /// Serialize as sequence of bytes.
+    pub fn serialize<T: SerdeAddress, S: Serializer>(v: &[T], s: S) -> Result<S::Ok, S::Error> {
+        s.collect_seq(v.iter().map(Bytes))
+    }
+
+    /// Deserialize from sequence of bytes (or sequence of `u8`).
+    ///
+    /// Error message includes index of the malformed element.
+    pub fn deserialize<'de, T: SerdeAddress, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Vec<T>, D::Error> {
+        d.deserialize_seq(VecVisitor::<T, RawBytes>(PhantomData))
+    }
+}
+
+/// Parse [`BdAddr::parse_lenient`] form with `sep` only.
+fn parse_with(s: &str, sep: Option<char>) -> Result<BdAddr, String> {
+    let err = |e| format!("{} ({:?})", e, s);
+    let addr = BdAddr::parse_lenient(s).map_err(err)?;
+    if s.chars().find(|c| !c.is_ascii_hexdigit()) != sep {
+        return Err(err(AddressParseError::INVALID));
+    }
+    Ok(addr)
+}
+
+macro_rules! string_format {
+    ($(#[$m:meta])* $name:ident, $sep:expr, $upper:expr, $example:expr) => {
+        $(#[$m])*
+        pub mod $name {
+            use super::*;
+
+            /// Serialize as string.
+            #[doc = concat!("(e.g. `", $example, "`)")]
+            pub fn serialize<S: Serializer>(v: &BdAddr, s: S) -> Result<S::Ok, S::Error> {
+                s.collect_str(&v.format_with($sep, $upper))
+            }
+
+            /// Deserialize from string, either case is accepted.
+            pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<BdAddr, D::Error> {
+                let s = String::deserialize(d)?;
+                parse_with(&s, $sep).map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+string_format!(
+    /// Uppercase colon separated string of [`BdAddr`], as Android. (e.g. `00:1A:7D:DA:71:13`)
+    upper_colon,
+    Some(':'),
+    true,
+    "00:1A:7D:DA:71:13"
+);
+
+string_format!(
+    /// Lowercase 12 hex digits of [`BdAddr`] without separators. (e.g. `001a7dda7113`)
+    lower_colonless,
+    None,
+    false,
+    "001a7dda7113"
+);
+
+string_format!(
+    /// Lowercase dash separated string of [`BdAddr`]. (e.g. `00-1a-7d-da-71-13`)
+    dash_separated,
+    Some('-'),
+    false,
+    "00-1a-7d-da-71-13"
+);
+
+/// String representation of [`BdAddr`], also accepting `u64` on deserialize.
+///
+/// The `u64` form is the display order value, the same as Windows `BLUETOOTH_ADDRESS`.
+/// (e.g. `55:44:33:22:11:00` is `0x5544_3322_1100`)
+/// Only up to `2^48 - 1` is accepted, and floating point numbers are rejected.
+///
+/// Requires self-describing format, such as JSON.
+pub mod lenient {
+    use ::serde::de::Unexpected;
+
+    use super::*;
+
+    const MAX: u64 = 0xFFFF_FFFF_FFFF;
+    const EXPECTING: &str = "address string or integer up to 2^48-1";
+
+    struct LenientVisitor;
+
+    impl<'de> Visitor<'de> for LenientVisitor {
+        type Value = BdAddr;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str(EXPECTING)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            BdAddr::from_repr_str(v).map_err(E::custom)
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            if v > MAX {
+                return Err(E::invalid_value(Unexpected::Unsigned(v), &EXPECTING));
+            }
+            let b = v.to_le_bytes();
+            Ok(BdAddr([b[0], b[1], b[2], b[3], b[4], b[5]]))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            u64::try_from(v)
+                .map_err(|_| E::invalid_value(Unexpected::Signed(v), &EXPECTING))
+                .and_then(|v| self.visit_u64(v))
+        }
+    }
+
+    /// Serialize as string.
+    pub fn serialize<S: Serializer>(v: &BdAddr, s: S) -> Result<S::Ok, S::Error> {
+        Str(v).serialize(s)
+    }
+
+    /// Deserialize from string or `u64`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<BdAddr, D::Error> {
+        d.deserialize_any(LenientVisitor)
+    }
+}
+
+/// Two adjacent fields `address` and `address_type` of [`Address`], for use with `#[serde(flatten)]`.
+///
+/// `address_type` is `bredr`, `public` or `random`, and the random sub-kind is derived from the address on deserialize.
+/// Random address with the reserved tag bits (`0b10`) is [`RandomDeviceAddress::Unknown`](crate::RandomDeviceAddress::Unknown),
+/// as with the other forms of [`Address`].
+///
+/// ```
+/// use bdaddr::{Address, RandomDeviceAddress};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Device {
+///     name: String,
+///     #[serde(flatten, with = "bdaddr::serde::split")]
+///     addr: Address,
+/// }
+///
+/// let json = r#"{ "name": "LE-Keyboard", "address": "53:03:8C:BC:BD:82", "address_type": "random" }"#;
+/// let device = serde_json::from_str::<Device>(json).unwrap();
+/// assert!(matches!(device.addr, Address::LeRandom(RandomDeviceAddress::Resolvable(..))));
+/// assert_eq!(
+///     r#"{"name":"LE-Keyboard","address":"53:03:8c:bc:bd:82","address_type":"random"}"#,
+///     serde_json::to_string(&device).unwrap()
+/// );
+/// ```
+pub mod split {
+    use ::serde::de::{IgnoredAny, MapAccess};
+    use ::serde::ser::SerializeStruct;
+
+    use super::*;
+
+    const ADDRESS: &str = "address";
+    const ADDRESS_TYPE: &str = "address_type";
+    const FIELDS: &[&str] = &[ADDRESS, ADDRESS_TYPE];
+
+    struct SplitVisitor;
+
+    impl<'de> Visitor<'de> for SplitVisitor {
+        type Value = Address;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("address and address_type fields")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut address = None;
+            let mut address_type = None;
+            while let Some(key) = map.next_key::<String>()? {
+                let slot = match key.as_str() {
+                    ADDRESS => &mut address,
+                    ADDRESS_TYPE => &mut address_type,
+                    _ => {
+                        map.next_value::<IgnoredAny>()?;
+                        continue;
+                    }
+                };
+                if slot.is_some() {
+                    return Err(de::Error::custom(format!("duplicate field `{}`", key)));
+                }
+                *slot = Some(map.next_value::<String>()?);
+            }
+            let address = address.ok_or_else(|| de::Error::missing_field(ADDRESS))?;
+            let address_type =
+                address_type.ok_or_else(|| de::Error::missing_field(ADDRESS_TYPE))?;
+
+            let addr = BdAddr::from_repr_str(&address).map_err(de::Error::custom)?;
+            let ty = address_type
+                .parse::<AddressType>()
+                .map_err(de::Error::custom)?;
+            Ok(addr.to_addr(ty))
+        }
+    }
+
+    /// Serialize as `address` and `address_type` fields.
+    pub fn serialize<S: Serializer>(v: &Address, s: S) -> Result<S::Ok, S::Error> {
+        let mut s = s.serialize_struct("Address", 2)?;
+        s.serialize_field(ADDRESS, &v.to_string())?;
+        s.serialize_field(ADDRESS_TYPE, v.address_type().as_str())?;
+        s.end()
+    }
+
+    /// Deserialize from `address` and `address_type` fields.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Address, D::Error> {
+        d.deserialize_struct("Address", FIELDS, SplitVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::SerdeAddress;
+    use crate::addr::{Address, BdAddr, RandomDeviceAddress};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Device {
+        #[serde(with = "super::string")]
+        addr: BdAddr,
+        #[serde(default, with = "super::string_opt")]
+        peer: Option<Address>,
+        #[serde(default, with = "super::string_vec")]
+        bonded: Vec<BdAddr>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Raw {
+        #[serde(with = "super::bytes")]
+        addr: Address,
+        #[serde(default, with = "super::bytes_opt")]
+        peer: Option<BdAddr>,
+        #[serde(default, with = "super::bytes_vec")]
+        bonded: Vec<Address>,
+    }
+
+    #[test]
+    fn test_string() {
+        let device = Device {
+            addr: "00:1a:7d:da:71:13".parse().unwrap(),
+            peer: Some(Address::le_random_from_str("f5:44:33:22:11:00").unwrap()),
+            bonded: vec![
+                "00:11:22:33:44:55".parse().unwrap(),
+                "66:77:88:99:aa:bb".parse().unwrap(),
+            ],
+        };
+        let v = json!({
+            "addr": "00:1a:7d:da:71:13",
+            "peer": "random/f5:44:33:22:11:00",
+            "bonded": ["00:11:22:33:44:55", "66:77:88:99:aa:bb"],
+        });
+        assert_eq!(v, serde_json::to_value(&device).unwrap());
+        assert_eq!(device, serde_json::from_value(v).unwrap());
+
+        let device = Device {
+            addr: "00:1a:7d:da:71:13".parse().unwrap(),
+            peer: None,
+            bonded: vec![],
+        };
+        let v = json!({ "addr": "00:1a:7d:da:71:13", "peer": null, "bonded": [] });
+        assert_eq!(v, serde_json::to_value(&device).unwrap());
+        assert_eq!(device, serde_json::from_value(v).unwrap());
+        let v = json!({ "addr": "00:1a:7d:da:71:13" });
+        assert_eq!(device, serde_json::from_value(v).unwrap());
+
+        for (peer, msg) in [
+            ("bredr/00:1a:7d:da:71:13", None),
+            ("public/00:1a:7d:da:71:13", None),
+            (
+                "le/00:1a:7d:da:71:13",
+                Some("Unknown address type. (\"le\")"),
+            ),
+            (
+                "00:1a:7d:da:71:13",
+                Some("expect <address type>/<address>. (\"00:1a:7d:da:71:13\")"),
+            ),
+        ] {
+            let v = json!({ "addr": "00:1a:7d:da:71:13", "peer": peer });
+            let result = serde_json::from_value::<Device>(v);
+            match msg {
+                None => assert_eq!(peer, &result.unwrap().peer.unwrap().to_repr_string()),
+                Some(msg) => assert_eq!(msg, &result.unwrap_err().to_string()),
+            }
+        }
+
+        let v = json!({
+            "addr": "00:1a:7d:da:71:13",
+            "bonded": ["00:11:22:33:44:55", "66:77:88:99:aa", "66:77:88:99:aa:bb"],
+        });
+        let err = serde_json::from_value::<Device>(v).unwrap_err();
+        assert_eq!(
+            "wrong group count (expected 6, found 5) at offset 14 (\"66:77:88:99:aa\") at index 1",
+            &err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_bytes() {
+        let raw = Raw {
+            addr: Address::le_public_from([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]),
+            peer: Some(BdAddr([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5])),
+            bonded: vec![
+                Address::bredr_from([0x55, 0x44, 0x33, 0x22, 0x11, 0x00]),
+                Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]),
+            ],
+        };
+        let v = json!({
+            "addr": [0x01, 0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00],
+            "peer": [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5],
+            "bonded": [
+                [0x00, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00],
+                [0x02, 0x00, 0x11, 0x22, 0x33, 0x44, 0xf5],
+            ],
+        });
+        assert_eq!(v, serde_json::to_value(&raw).unwrap());
+        assert_eq!(raw, serde_json::from_value(v).unwrap());
+
+        let v = json!({ "addr": [0x01, 0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00], "peer": null });
+        let raw = serde_json::from_value::<Raw>(v).unwrap();
+        assert_eq!(None, raw.peer);
+        assert!(raw.bonded.is_empty());
+
+        let v = json!({ "addr": [0x03, 0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00] });
+        let err = serde_json::from_value::<Raw>(v).unwrap_err();
+        assert_eq!("Unknown address type. (0x03)", &err.to_string());
+
+        let v = json!({
+            "addr": [0x01, 0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00],
+            "bonded": [[0x00, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00], [0x00, 0x55]],
+        });
+        let err = serde_json::from_value::<Raw>(v).unwrap_err();
+        assert_eq!(
+            "Invalid address length. (expect: 7, but 2) at index 1",
+            &err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_lenient() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Export {
+            #[serde(with = "super::lenient")]
+            a: BdAddr,
+            #[serde(with = "super::lenient")]
+            b: BdAddr,
+        }
+
+        let export =
+            serde_json::from_str::<Export>(r#"{ "a": "55:44:33:22:11:00", "b": 93751404007680 }"#)
+                .unwrap();
+        let addr = "55:44:33:22:11:00".parse::<BdAddr>().unwrap();
+        assert_eq!(Export { a: addr, b: addr }, export);
+        assert_eq!(
+            json!({ "a": "55:44:33:22:11:00", "b": "55:44:33:22:11:00" }),
+            serde_json::to_value(&export).unwrap()
+        );
+
+        let export = serde_json::from_str::<Export>(r#"{ "a": 0, "b": 281474976710655 }"#).unwrap();
+        assert_eq!("00:00:00:00:00:00", &export.a.to_string());
+        assert_eq!("ff:ff:ff:ff:ff:ff", &export.b.to_string());
+
+        for (json, msg) in [
+            (
+                r#"{ "a": 0, "b": 281474976710656 }"#,
+                "invalid value: integer `281474976710656`, expected address string or integer up to 2^48-1",
+            ),
+            (
+                r#"{ "a": 0, "b": -1 }"#,
+                "invalid value: integer `-1`, expected address string or integer up to 2^48-1",
+            ),
+            (
+                r#"{ "a": 0, "b": 1.5 }"#,
+                "invalid type: floating point `1.5`, expected address string or integer up to 2^48-1",
+            ),
+            (
+                r#"{ "a": 0, "b": "55:44:33:22:11" }"#,
+                "wrong group count (expected 6, found 5) at offset 14 (\"55:44:33:22:11\")",
+            ),
+        ] {
+            let err = serde_json::from_str::<Export>(json).unwrap_err();
+            assert!(err.to_string().starts_with(msg), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_split() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Device {
+            name: String,
+            #[serde(flatten, with = "super::split")]
+            addr: Address,
+            rssi: i16,
+        }
+
+        // `org.bluez.Device1` properties / mgmt Device Found event
+        let json = r#"[
+            { "name": "Keyboard", "address": "00:1A:7D:DA:71:13", "address_type": "public", "rssi": -48 },
+            { "name": "Tag", "address": "F5:44:33:22:11:00", "address_type": "random", "rssi": -71 },
+            { "name": "Phone", "address": "53:03:8C:BC:BD:82", "address_type": "random", "rssi": -60 },
+            { "name": "Headset", "address": "00:11:22:33:44:55", "address_type": "bredr", "rssi": -55 }
+        ]"#;
+        let devices = serde_json::from_str::<Vec<Device>>(json).unwrap();
+        assert_eq!(
+            vec![
+                Address::le_public_from_str("00:1a:7d:da:71:13").unwrap(),
+                Address::le_random_from_str("f5:44:33:22:11:00").unwrap(),
+                Address::le_random_from_str("53:03:8c:bc:bd:82").unwrap(),
+                Address::bredr_from_str("00:11:22:33:44:55").unwrap(),
+            ],
+            devices.iter().map(|d| d.addr).collect::<Vec<_>>()
+        );
+        assert!(matches!(
+            devices[1].addr,
+            Address::LeRandom(RandomDeviceAddress::Static(..))
+        ));
+        assert!(matches!(
+            devices[2].addr,
+            Address::LeRandom(RandomDeviceAddress::Resolvable(..))
+        ));
+        assert_eq!(
+            json!({ "name": "Tag", "address": "f5:44:33:22:11:00", "address_type": "random", "rssi": -71 }),
+            serde_json::to_value(&devices[1]).unwrap()
+        );
+        let v = serde_json::to_value(&devices).unwrap();
+        assert_eq!(devices, serde_json::from_value::<Vec<Device>>(v).unwrap());
+
+        let json = r#"{ "name": "x", "address": "B5:44:33:22:11:00", "address_type": "random", "rssi": 0 }"#;
+        let device = serde_json::from_str::<Device>(json).unwrap();
+        assert!(matches!(
+            device.addr,
+            Address::LeRandom(RandomDeviceAddress::Unknown(..))
+        ));
+        assert_eq!(
+            json!({ "name": "x", "address": "b5:44:33:22:11:00", "address_type": "random", "rssi": 0 }),
+            serde_json::to_value(&device).unwrap()
+        );
+
+        for (json, msg) in [
+            (
+                r#"{ "name": "x", "address": "00:1A:7D:DA:71:13", "address_type": "le-public", "rssi": 0 }"#,
+                "Unknown address type. (\"le-public\")",
+            ),
+            (
+                r#"{ "name": "x", "address": "00:1A:7D:DA:71", "address_type": "public", "rssi": 0 }"#,
+                "wrong group count (expected 6, found 5) at offset 14 (\"00:1A:7D:DA:71\")",
+            ),
+            (
+                r#"{ "name": "x", "address": "00:1A:7D:DA:71:13", "rssi": 0 }"#,
+                "missing field `address_type`",
+            ),
+        ] {
+            let err = serde_json::from_str::<Device>(json).unwrap_err();
+            assert!(err.to_string().starts_with(msg), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_impl() {
+        use crate::addr::{
+            NonResolvablePrivateAddress, PublicDeviceAddress, ResolvablePrivateAddress,
+            StaticDeviceAddress,
+        };
+
+        let addr = "55:44:33:22:11:00".parse::<BdAddr>().unwrap();
+        assert_eq!(
+            json!("55:44:33:22:11:00"),
+            serde_json::to_value(addr).unwrap()
+        );
+        assert_eq!(
+            addr,
+            serde_json::from_value(json!("55:44:33:22:11:00")).unwrap()
+        );
+        let b = bincode::serialize(&addr).unwrap();
+        assert_eq!(vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55], b);
+        assert_eq!(addr, bincode::deserialize::<BdAddr>(&b).unwrap());
+
+        let addr = StaticDeviceAddress::try_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]).unwrap();
+        let v = serde_json::to_value(addr).unwrap();
+        assert_eq!(json!("f5:44:33:22:11:00"), v);
+        assert_eq!(addr, serde_json::from_value(v).unwrap());
+        let b = bincode::serialize(&addr).unwrap();
+        assert_eq!(vec![0x00, 0x11, 0x22, 0x33, 0x44, 0xf5], b);
+        assert_eq!(addr, bincode::deserialize(&b).unwrap());
+
+        let addr = PublicDeviceAddress::from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        let v = serde_json::to_value(addr).unwrap();
+        assert_eq!(addr, serde_json::from_value(v).unwrap());
+        let addr =
+            ResolvablePrivateAddress::try_from([0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53]).unwrap();
+        let b = bincode::serialize(&addr).unwrap();
+        assert_eq!(addr, bincode::deserialize(&b).unwrap());
+        let addr = NonResolvablePrivateAddress::from_random_part(1).unwrap();
+        let v = serde_json::to_value(addr).unwrap();
+        assert_eq!(addr, serde_json::from_value(v).unwrap());
+
+        let err =
+            serde_json::from_value::<StaticDeviceAddress>(json!("55:44:33:22:11:00")).unwrap_err();
+        assert_eq!(
+            "Invalid bits for StaticDeviceAddress. (address: 55:44:33:22:11:00, expect: 0b11, but 0b01)",
+            &err.to_string()
+        );
+        let err =
+            bincode::deserialize::<ResolvablePrivateAddress>(&[0, 0, 0, 0, 0, 0xc0]).unwrap_err();
+        assert_eq!(
+            "Invalid bits for ResolvablePrivateAddress. (address: c0:00:00:00:00:00, expect: 0b01, but 0b11)",
+            &err.to_string()
+        );
+        let err = serde_json::from_value::<BdAddr>(json!("55:44:33:22:11")).unwrap_err();
+        assert_eq!(
+            "wrong group count (expected 6, found 5) at offset 14 (\"55:44:33:22:11\")",
+            &err.to_string()
+        );
+        assert!(bincode::deserialize::<BdAddr>(&[0, 0, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_impl_address() {
+        use crate::addr::RandomDeviceAddress;
+
+        for (addr, s, b) in [
+            (
+                Address::bredr_from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+                "bredr/55:44:33:22:11:00",
+                [0x00, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+            ),
+            (
+                Address::le_public_from([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+                "public/55:44:33:22:11:00",
+                [0x01, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+            ),
+            (
+                Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xf5]),
+                "random/f5:44:33:22:11:00",
+                [0x02, 0x00, 0x11, 0x22, 0x33, 0x44, 0xf5],
+            ),
+            // reserved tag bits
+            (
+                Address::le_random_from([0x00, 0x11, 0x22, 0x33, 0x44, 0xb5]),
+                "random/b5:44:33:22:11:00",
+                [0x02, 0x00, 0x11, 0x22, 0x33, 0x44, 0xb5],
+            ),
+        ] {
+            assert_eq!(json!(s), serde_json::to_value(addr).unwrap());
+            assert_eq!(addr, serde_json::from_value(json!(s)).unwrap());
+            assert_eq!(b.to_vec(), bincode::serialize(&addr).unwrap());
+            assert_eq!(addr, bincode::deserialize(&b).unwrap());
+        }
+
+        let addr = serde_json::from_value::<Address>(json!("random/53:03:8c:bc:bd:82")).unwrap();
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Resolvable(..))
+        ));
+        let addr =
+            bincode::deserialize::<Address>(&[0x02, 0x00, 0x11, 0x22, 0x33, 0x44, 0x35]).unwrap();
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::NonResolvable(..))
+        ));
+
+        let err = serde_json::from_value::<Address>(json!("le/55:44:33:22:11:00")).unwrap_err();
+        assert_eq!("Unknown address type. (\"le\")", &err.to_string());
+        let err = bincode::deserialize::<Address>(&[0x03, 0, 0, 0, 0, 0, 0]).unwrap_err();
+        assert_eq!("Unknown address type. (0x03)", &err.to_string());
+    }
+
+    #[test]
+    fn test_string_format() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Formats {
+            #[serde(with = "super::upper_colon")]
+            android: BdAddr,
+            #[serde(with = "super::lower_colonless")]
+            colonless: BdAddr,
+            #[serde(with = "super::dash_separated")]
+            dash: BdAddr,
+        }
+
+        let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+        let formats = Formats {
+            android: addr,
+            colonless: addr,
+            dash: addr,
+        };
+        let v = json!({
+            "android": "00:1A:7D:DA:71:13",
+            "colonless": "001a7dda7113",
+            "dash": "00-1a-7d-da-71-13",
+        });
+        assert_eq!(v, serde_json::to_value(&formats).unwrap());
+        assert_eq!(formats, serde_json::from_value(v).unwrap());
+
+        let v = json!({
+            "android": "00:1a:7d:da:71:13",
+            "colonless": "001A7DDA7113",
+            "dash": "00-1A-7d-DA-71-13",
+        });
+        assert_eq!(formats, serde_json::from_value(v).unwrap());
+
+        for (android, colonless, dash) in [
+            ("00-1A-7D-DA-71-13", "001a7dda7113", "00-1a-7d-da-71-13"),
+            ("001A7DDA7113", "001a7dda7113", "00-1a-7d-da-71-13"),
+            (
+                "00:1A:7D:DA:71:13",
+                "00:1a:7d:da:71:13",
+                "00-1a-7d-da-71-13",
+            ),
+            ("00:1A:7D:DA:71:13", "001a7dda711", "00-1a-7d-da-71-13"),
+            ("00:1A:7D:DA:71:13", "001a7dda7113", "00:1a:7d:da:71:13"),
+            ("00:1A:7D:DA:71:13", "001a7dda711g", "00-1a-7d-da-71-13"),
+            ("00:1A:7D:DA:71:13", "001a7dda7113", "00-1a-7d-da-71-1+"),
+            (
+                "00:1A:7D:DA:71:1\u{e9}",
+                "001a7dda7113",
+                "00-1a-7d-da-71-13",
+            ),
+        ] {
+            let v = json!({ "android": android, "colonless": colonless, "dash": dash });
+            assert!(serde_json::from_value::<Formats>(v).is_err());
+        }
+
+        for (v, message) in [
+            (
+                json!({ "android": "00-1A-7D-DA-71-13", "colonless": "001a7dda7113", "dash": "00-1a-7d-da-71-13" }),
+                "failed to parse address (\"00-1A-7D-DA-71-13\")",
+            ),
+            (
+                json!({ "android": "00:1A:7D:DA:71:13", "colonless": "001a7dda711", "dash": "00-1a-7d-da-71-13" }),
+                "group 0 too short at offset 11 (\"001a7dda711\")",
+            ),
+            (
+                json!({ "android": "00:1A:7D:DA:71:13", "colonless": "001a7dda7113", "dash": "00-1a-7d-da-71-1+" }),
+                "invalid hex digit in group 5 at offset 16 (\"00-1a-7d-da-71-1+\")",
+            ),
+        ] {
+            let err = serde_json::from_value::<Formats>(v).unwrap_err();
+            assert_eq!(message, err.to_string());
+        }
+    }
+}
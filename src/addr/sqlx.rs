@@ -0,0 +1,254 @@
+//! `sqlx` column representation, for any database supports TEXT and BLOB. (e.g. Postgres, SQLite)
+//!
+//! [`BdAddr`] is encoded as lowercase TEXT. (e.g. `00:1a:7d:da:71:13`)
+//! BLOB (`bytea`) of 6 bytes in the same (wire) order as [`BdAddr`] is also accepted on decode.
+//!
+//! [`Address`](super::Address) is stored as two columns, address and [`AddressType`]
+//! (TEXT `bredr`, `public` or `random`).
+//!
+//! ```ignore
+//! // CREATE TABLE device (addr TEXT NOT NULL, addr_type TEXT NOT NULL, PRIMARY KEY (addr, addr_type))
+//! sqlx::query("INSERT INTO device VALUES ($1, $2)")
+//!     .bind(addr.clone().into_bd_addr())
+//!     .bind(addr.address_type())
+//!     .execute(&pool)
+//!     .await?;
+//!
+//! let (addr, ty): (BdAddr, AddressType) = sqlx::query_as("SELECT addr, addr_type FROM device")
+//!     .fetch_one(&pool)
+//!     .await?;
+//! let addr = addr.to_addr(ty);
+//! ```
+
+use std::convert::TryFrom;
+
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Decode, Encode, Type, ValueRef};
+
+use super::{AddressType, BdAddr};
+
+impl BdAddr {
+    fn from_column_text(s: &str) -> Result<Self, BoxDynError> {
+        s.parse().map_err(|e| format!("{} ({:?})", e, s).into())
+    }
+
+    fn from_column_bytes(b: &[u8]) -> Result<Self, BoxDynError> {
+        Ok(Self::try_from(b)?)
+    }
+}
+
+impl<DB: Database> Type<DB> for BdAddr
+where
+    str: Type<DB>,
+    [u8]: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(ty) || <[u8] as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for BdAddr
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.to_string().encode(buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for BdAddr
+where
+    &'r str: Decode<'r, DB>,
+    str: Type<DB>,
+    Vec<u8>: Decode<'r, DB>,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        // text first, as `[u8]` of SQLite is compatible with TEXT too
+        if <str as Type<DB>>::compatible(&value.type_info()) {
+            Self::from_column_text(<&str as Decode<DB>>::decode(value)?)
+        } else {
+            Self::from_column_bytes(&<Vec<u8> as Decode<DB>>::decode(value)?)
+        }
+    }
+}
+
+impl<DB: Database> Type<DB> for AddressType
+where
+    str: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for AddressType
+where
+    &'static str: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.as_str().encode(buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for AddressType
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let s = <&str as Decode<DB>>::decode(value)?;
+        Ok(s.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, Postgres};
+    use sqlx::sqlite::{Sqlite, SqliteArgumentValue};
+
+    #[test]
+    fn test_type() {
+        assert_eq!(
+            PgTypeInfo::with_name("TEXT"),
+            <BdAddr as Type<Postgres>>::type_info()
+        );
+        for ty in [
+            PgTypeInfo::with_name("TEXT"),
+            PgTypeInfo::with_name("VARCHAR"),
+            PgTypeInfo::with_name("BYTEA"),
+        ] {
+            assert!(<BdAddr as Type<Postgres>>::compatible(&ty), "{:?}", ty);
+        }
+        assert!(!<BdAddr as Type<Postgres>>::compatible(
+            &PgTypeInfo::with_name("INT8")
+        ));
+        assert!(!<AddressType as Type<Postgres>>::compatible(
+            &PgTypeInfo::with_name("BYTEA")
+        ));
+
+        assert_eq!(
+            <str as Type<Sqlite>>::type_info(),
+            <BdAddr as Type<Sqlite>>::type_info()
+        );
+        assert!(<BdAddr as Type<Sqlite>>::compatible(&<[u8] as Type<
+            Sqlite,
+        >>::type_info(
+        )));
+    }
+
+    #[test]
+    fn test_encode_postgres() {
+        let addr = "00:1A:7D:DA:71:13".parse::<BdAddr>().unwrap();
+        let mut buf = PgArgumentBuffer::default();
+        let _ = <BdAddr as Encode<Postgres>>::encode_by_ref(&addr, &mut buf).unwrap();
+        assert_eq!(b"00:1a:7d:da:71:13", &buf[..]);
+
+        let mut buf = PgArgumentBuffer::default();
+        let _ = <AddressType as Encode<Postgres>>::encode_by_ref(&AddressType::LeRandom, &mut buf)
+            .unwrap();
+        assert_eq!(b"random", &buf[..]);
+    }
+
+    #[test]
+    fn test_encode_sqlite() {
+        let addr = "00:1A:7D:DA:71:13".parse::<BdAddr>().unwrap();
+        let mut buf = Vec::new();
+        let _ = <BdAddr as Encode<Sqlite>>::encode_by_ref(&addr, &mut buf).unwrap();
+        assert!(matches!(
+            &buf[..],
+            [SqliteArgumentValue::Text(s)] if s == "00:1a:7d:da:71:13"
+        ));
+    }
+
+    #[test]
+    fn test_decode() {
+        let addr = BdAddr::from_column_text("00:1a:7d:da:71:13").unwrap();
+        assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+
+        let addr = BdAddr::from_column_bytes(&[0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]).unwrap();
+        assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+
+        let err = BdAddr::from_column_text("00:1a:7d:da:71").unwrap_err();
+        assert_eq!(
+            "wrong group count (expected 6, found 5) at offset 14 (\"00:1a:7d:da:71\")",
+            &format!("{}", err)
+        );
+
+        let err = BdAddr::from_column_bytes(&[0x13, 0x71, 0xda, 0x7d, 0x1a]).unwrap_err();
+        assert_eq!(
+            "Invalid address length. (expect: 6, but 5)",
+            &format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_round_trip_sqlite() {
+        use sqlx::{Connection, SqliteConnection};
+
+        futures_executor::block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            sqlx::query("CREATE TABLE device (text TEXT, blob BLOB, ty TEXT)")
+                .execute(&mut conn)
+                .await
+                .unwrap();
+
+            let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+            sqlx::query("INSERT INTO device VALUES ($1, $2, $3)")
+                .bind(addr)
+                .bind(<[u8; 6]>::from(addr).to_vec())
+                .bind(AddressType::LePublic)
+                .execute(&mut conn)
+                .await
+                .unwrap();
+            let (text, blob, ty): (BdAddr, BdAddr, AddressType) =
+                sqlx::query_as("SELECT text, blob, ty FROM device")
+                    .fetch_one(&mut conn)
+                    .await
+                    .unwrap();
+            assert_eq!(addr, text);
+            assert_eq!(addr, blob);
+            assert_eq!(AddressType::LePublic, ty);
+
+            let (text,): (String,) = sqlx::query_as("SELECT text FROM device")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!("00:1a:7d:da:71:13", text);
+
+            let (upper,): (BdAddr,) = sqlx::query_as("SELECT '00:1A:7D:DA:71:13'")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(addr, upper);
+
+            let err = sqlx::query_as::<_, (BdAddr,)>("SELECT X'1371da7d1a'")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("Invalid address length. (expect: 6, but 5)"),
+                "{}",
+                err
+            );
+            let err = sqlx::query_as::<_, (AddressType,)>("SELECT 'le'")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap_err();
+            assert!(
+                err.to_string().contains("Unknown address type. (\"le\")"),
+                "{}",
+                err
+            );
+        });
+    }
+}
@@ -0,0 +1,127 @@
+//! `trouble_host::prelude::BdAddr` holds the octets in the same (wire) order as [`BdAddr`].
+
+use core::convert::TryFrom;
+
+use trouble_host::prelude::{AddrKind, BdAddr as TroubleBdAddr};
+
+use super::{Address, AddressType, BdAddr, UnknownAddressType, UnsupportedAddressType};
+
+impl From<BdAddr> for TroubleBdAddr {
+    #[inline]
+    fn from(v: BdAddr) -> Self {
+        Self::new(v.0)
+    }
+}
+
+impl From<TroubleBdAddr> for BdAddr {
+    #[inline]
+    fn from(v: TroubleBdAddr) -> Self {
+        Self(v.into_inner())
+    }
+}
+
+/// `AddrKind::RANDOM` and `AddrKind::RESOLVABLE_PRIVATE_OR_RANDOM` are classified by the tag bits.
+impl TryFrom<trouble_host::Address> for Address {
+    type Error = UnknownAddressType;
+
+    fn try_from(v: trouble_host::Address) -> Result<Self, Self::Error> {
+        let addr = BdAddr::from(v.addr);
+        match v.kind {
+            AddrKind::PUBLIC | AddrKind::RESOLVABLE_PRIVATE_OR_PUBLIC => {
+                Ok(addr.to_le_public_addr())
+            }
+            AddrKind::RANDOM | AddrKind::RESOLVABLE_PRIVATE_OR_RANDOM => {
+                Ok(addr.to_le_random_addr())
+            }
+            x => Err(UnknownAddressType(x.as_raw())),
+        }
+    }
+}
+
+impl TryFrom<Address> for trouble_host::Address {
+    type Error = UnsupportedAddressType;
+
+    fn try_from(v: Address) -> Result<Self, Self::Error> {
+        let kind = match v.address_type() {
+            AddressType::LePublic => AddrKind::PUBLIC,
+            AddressType::LeRandom => AddrKind::RANDOM,
+            ty @ AddressType::BrEdr => return Err(UnsupportedAddressType(ty)),
+        };
+        Ok(Self::new(kind, v.into_bd_addr().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RandomDeviceAddress;
+
+    #[test]
+    fn test_bd_addr() {
+        let b = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55];
+        let addr = TroubleBdAddr::from(BdAddr::from(b));
+        assert_eq!(b, addr.into_inner());
+        assert_eq!(b, <[u8; 6]>::from(BdAddr::from(addr)));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for s in [
+            "55:44:33:22:11:00",
+            "35:44:33:22:11:00",
+            "75:44:33:22:11:00",
+            "F5:44:33:22:11:00",
+            "B5:44:33:22:11:00",
+        ] {
+            for addr in [
+                Address::le_public_from_str(s).unwrap(),
+                Address::le_random_from_str(s).unwrap(),
+            ] {
+                let taddr = trouble_host::Address::try_from(addr).unwrap();
+                assert_eq!(addr.to_string(), taddr.to_string().to_lowercase());
+                assert_eq!(addr, Address::try_from(taddr).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_trouble_host() {
+        let b = [0x00, 0x11, 0x22, 0x33, 0x44, 0xf5];
+        let taddr = TroubleBdAddr::new(b);
+
+        let addr = Address::try_from(trouble_host::Address::new(AddrKind::PUBLIC, taddr)).unwrap();
+        assert_eq!(Address::le_public_from(b), addr);
+
+        let addr = Address::try_from(trouble_host::Address::new(AddrKind::RANDOM, taddr)).unwrap();
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Static(..))
+        ));
+
+        let addr = Address::try_from(trouble_host::Address::new(
+            AddrKind::RESOLVABLE_PRIVATE_OR_PUBLIC,
+            taddr,
+        ))
+        .unwrap();
+        assert_eq!(Address::le_public_from(b), addr);
+
+        let addr = Address::try_from(trouble_host::Address::new(
+            AddrKind::RESOLVABLE_PRIVATE_OR_RANDOM,
+            taddr,
+        ))
+        .unwrap();
+        assert_eq!(Address::le_random_from(b), addr);
+
+        let err = Address::try_from(trouble_host::Address::new(AddrKind::ANONYMOUS_ADV, taddr))
+            .unwrap_err();
+        assert_eq!("Unknown address type. (0xff)", &format!("{}", err));
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let addr = Address::bredr_from_str("55:44:33:22:11:00").unwrap();
+        let err = trouble_host::Address::try_from(addr).unwrap_err();
+        assert_eq!("UnsupportedAddressType(BrEdr)", &format!("{:?}", err));
+        assert_eq!("Unsupported address type. (BrEdr)", &format!("{}", err));
+    }
+}
@@ -0,0 +1,185 @@
+//! Kotlin / Swift bindings with UniFFI.
+//!
+//! ```sh
+//! cargo rustc --lib --release --features uniffi --crate-type cdylib
+//! cargo run --features uniffi-bindgen --bin uniffi-bindgen -- \
+//!     generate --library target/release/libbdaddr.so --language kotlin --out-dir out
+//! ```
+//!
+//! `BdAddr` is a string (`00:1a:7d:da:71:13`) in foreign languages, and [`Address`] is a record
+//! of `addressType` and `bdAddr`. Errors are thrown as `BdAddrException` (Kotlin) / `BdAddrError` (Swift).
+//! `scripts/uniffi-smoke.sh` generates both bindings and checks the exported interface.
+//!
+//! ```kotlin
+//! val addr = parseAddress("random/53:03:8c:bc:bd:82")
+//! check(addressKind(addr) == RandomDeviceAddressKind.RESOLVABLE)
+//! check(rpaMatches(addr.bdAddr, irk))
+//! ```
+
+use std::fmt;
+
+use super::matches::{irk_from_slice, InvalidIrkLength};
+use super::{
+    Address, AddressFromStrError, AddressType, BdAddr, RandomDeviceAddress, RandomDeviceAddressKind,
+};
+
+uniffi::custom_type!(BdAddr, String, {
+    try_lift: |s| Ok(s.parse()?),
+    lower: |v| v.to_string(),
+});
+
+/// Error thrown to foreign languages.
+#[derive(Debug, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum BdAddrError {
+    /// Failed to parse address, with the message of [`super::AddressParseError`] and the input.
+    AddressParse(String),
+
+    /// Unknown address type, with the message of [`super::VariantParseError`].
+    UnknownAddressType(String),
+
+    /// IRK is not 16 bytes.
+    InvalidIrkLength(u64),
+}
+
+impl fmt::Display for BdAddrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddressParse(s) => f.write_str(s),
+            Self::UnknownAddressType(s) => f.write_str(s),
+            Self::InvalidIrkLength(n) => InvalidIrkLength(*n as usize).fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for BdAddrError {}
+
+/// [`Address`] as record.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, uniffi::Record)]
+#[uniffi(name = "Address")]
+pub struct AddressRecord {
+    pub address_type: AddressType,
+    pub bd_addr: BdAddr,
+}
+
+impl From<Address> for AddressRecord {
+    fn from(v: Address) -> Self {
+        let (address_type, bd_addr) = v.into_parts();
+        Self {
+            address_type,
+            bd_addr,
+        }
+    }
+}
+
+impl From<AddressRecord> for Address {
+    fn from(v: AddressRecord) -> Self {
+        v.bd_addr.to_addr(v.address_type)
+    }
+}
+
+/// Parse address. (e.g. `00:1a:7d:da:71:13`)
+#[uniffi::export]
+pub fn parse_bd_addr(s: &str) -> Result<BdAddr, BdAddrError> {
+    s.parse()
+        .map_err(|e| BdAddrError::AddressParse(format!("{} ({:?})", e, s)))
+}
+
+/// Parse `<address type>/<address>`. (`bredr`, `public` or `random`, e.g. `public/00:1a:7d:da:71:13`)
+#[uniffi::export]
+pub fn parse_address(s: &str) -> Result<AddressRecord, BdAddrError> {
+    s.parse::<Address>().map(Into::into).map_err(|e| match e {
+        AddressFromStrError::UnknownAddressType(e) => {
+            BdAddrError::UnknownAddressType(e.to_string())
+        }
+        e => BdAddrError::AddressParse(format!("{} ({:?})", e, s)),
+    })
+}
+
+/// Kind of LE Random Device Address, `null` / `nil` for others.
+#[uniffi::export]
+pub fn address_kind(address: AddressRecord) -> Option<RandomDeviceAddressKind> {
+    match Address::from(address) {
+        Address::LeRandom(addr) => Some(addr.kind()),
+        _ => None,
+    }
+}
+
+/// Test address is a Resolvable Private Address generated from Identity Resolving Key. (16 bytes)
+///
+/// The IRK is in the same order as [`super::ResolvablePrivateAddress::matches`].
+#[uniffi::export]
+pub fn rpa_matches(addr: BdAddr, irk: &[u8]) -> Result<bool, BdAddrError> {
+    let irk = irk_from_slice(irk).map_err(|e| BdAddrError::InvalidIrkLength(e.0 as u64))?;
+    match RandomDeviceAddress::new(addr) {
+        RandomDeviceAddress::Resolvable(addr) => Ok(addr.matches(irk)),
+        _ => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uniffi::{Lift, Lower};
+
+    const IRK: [u8; 16] = [
+        0x19, 0x78, 0xa2, 0xaf, 0xdd, 0x75, 0x7b, 0xed, 0xfc, 0x9d, 0xc6, 0x9e, 0x95, 0xd7, 0x33,
+        0xb3,
+    ];
+
+    #[test]
+    fn test_parse() {
+        let addr = parse_bd_addr("00:1A:7D:DA:71:13").unwrap();
+        assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+        let err = parse_bd_addr("00:1a:7d").unwrap_err();
+        assert_eq!(
+            "wrong group count (expected 6, found 3) at offset 8 (\"00:1a:7d\")",
+            err.to_string()
+        );
+
+        let addr = parse_address("random/53:03:8c:bc:bd:82").unwrap();
+        assert_eq!(AddressType::LeRandom, addr.address_type);
+        assert_eq!(
+            Some(RandomDeviceAddressKind::Resolvable),
+            address_kind(addr.clone())
+        );
+        let addr = parse_address("public/00:1a:7d:da:71:13").unwrap();
+        assert_eq!(None, address_kind(addr));
+
+        let err = parse_address("le/00:1a:7d:da:71:13").unwrap_err();
+        assert_eq!("Unknown address type. (\"le\")", err.to_string());
+        let err = parse_address("00:1a:7d:da:71:13").unwrap_err();
+        assert!(matches!(err, BdAddrError::AddressParse(_)));
+        assert_eq!(
+            "expect <address type>/<address>. (\"00:1a:7d:da:71:13\")",
+            err.to_string()
+        );
+        let err = parse_address("public/00:1a:7d:da:71:1g").unwrap_err();
+        assert_eq!(
+            "invalid hex digit in group 5 at offset 23 (\"public/00:1a:7d:da:71:1g\")",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_rpa_matches() {
+        let addr = parse_bd_addr("53:03:8c:bc:bd:82").unwrap();
+        assert!(rpa_matches(addr, &IRK).unwrap());
+        let err = rpa_matches(addr, &IRK[..15]).unwrap_err();
+        assert_eq!("Invalid IRK length. (expect: 16, but 15)", err.to_string());
+
+        let addr = parse_bd_addr("00:1a:7d:da:71:13").unwrap();
+        assert!(!rpa_matches(addr, &IRK).unwrap());
+    }
+
+    #[test]
+    fn test_custom_type() {
+        let addr: BdAddr = "00:1a:7d:da:71:13".parse().unwrap();
+        let lowered = <BdAddr as Lower<crate::UniFfiTag>>::lower(addr);
+        let lifted = <BdAddr as Lift<crate::UniFfiTag>>::try_lift(lowered).unwrap();
+        assert_eq!(addr, lifted);
+
+        let lowered = <String as Lower<crate::UniFfiTag>>::lower("00:1a".to_string());
+        assert!(<BdAddr as Lift<crate::UniFfiTag>>::try_lift(lowered).is_err());
+    }
+}
@@ -0,0 +1,60 @@
+//! `ux::u48` is the address as integer in display order, the same as Windows `BLUETOOTH_ADDRESS` `u64` form,
+//! so `55:44:33:22:11:00` is `0x5544_3322_1100`.
+//!
+//! Unlike `u64`, every `u48` is an address, and the conversion never fails.
+
+use ux::u48;
+
+use super::BdAddr;
+
+impl From<BdAddr> for u48 {
+    fn from(v: BdAddr) -> Self {
+        let b = v.0;
+        u48::new(u64::from_le_bytes([
+            b[0], b[1], b[2], b[3], b[4], b[5], 0, 0,
+        ]))
+    }
+}
+
+impl From<u48> for BdAddr {
+    fn from(v: u48) -> Self {
+        let b = u64::from(v).to_le_bytes();
+        Self([b[0], b[1], b[2], b[3], b[4], b[5]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        for (s, v) in [
+            ("55:44:33:22:11:00", 0x5544_3322_1100),
+            ("00:00:00:00:00:00", 0),
+            ("00:00:00:00:00:01", 1),
+            ("ff:ff:ff:ff:ff:ff", 0xffff_ffff_ffff),
+        ] {
+            let addr = s.parse::<BdAddr>().unwrap();
+            let n = u48::from(addr);
+            assert_eq!(u48::new(v), n);
+            assert_eq!(addr, BdAddr::from(n));
+            assert_eq!(s, BdAddr::from(u48::new(v)).to_string());
+        }
+        assert_eq!(u48::MAX, u48::from(BdAddr::from([0xff; 6])));
+    }
+
+    #[test]
+    fn test_display_order() {
+        // consistent with the display order value of `offset_from`
+        let zero = BdAddr::from([0; 6]);
+        for b in [
+            [0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00],
+            [0x82, 0xbd, 0xbc, 0x8c, 0x03, 0x53],
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0x80],
+        ] {
+            let addr = BdAddr::from(b);
+            assert_eq!(addr.offset_from(&zero) as u64, u64::from(u48::from(addr)));
+        }
+    }
+}
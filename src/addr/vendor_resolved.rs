@@ -0,0 +1,110 @@
+use core::fmt;
+
+use super::BdAddr;
+
+/// Corporate suffixes dropped from vendor name. (compared case-insensitively)
+const SUFFIXES: &[&str] = &[
+    "ag",
+    "co",
+    "company",
+    "corp",
+    "corporation",
+    "gmbh",
+    "inc",
+    "incorporated",
+    "limited",
+    "llc",
+    "ltd",
+    "sa",
+];
+
+/// Max length of shortened vendor name, as the legacy Wireshark `manuf` file.
+const MAX_LEN: usize = 8;
+
+/// [`fmt::Display`] adapter of [`BdAddr::display_vendor_resolved`]
+#[derive(Debug, Clone)]
+pub struct VendorResolved<'a> {
+    addr: &'a BdAddr,
+    vendor: Option<&'a str>,
+}
+
+fn words(vendor: &str) -> impl Iterator<Item = &str> {
+    vendor
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .filter(|w| !SUFFIXES.iter().any(|s| s.eq_ignore_ascii_case(w)))
+}
+
+impl fmt::Display for VendorResolved<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let b = &self.addr.0;
+        let vendor = match self.vendor {
+            Some(v) if words(v).next().is_some() => v,
+            _ => return self.addr.fmt(f),
+        };
+        for c in words(vendor).flat_map(str::chars).take(MAX_LEN) {
+            fmt::Write::write_char(f, c)?;
+        }
+        write!(f, "_{:02x}:{:02x}:{:02x}", b[2], b[1], b[0])
+    }
+}
+
+impl BdAddr {
+    /// Display with the OUI substituted by vendor short name, as Wireshark. (e.g. `Apple_33:22:11`)
+    ///
+    /// `vendor` is the organization name of the OUI (e.g. looked up from IEEE registry), and shortened as below.
+    ///
+    /// 1. Split into words by non ASCII alphanumeric characters. (punctuation, spaces and non ASCII characters are stripped)
+    /// 2. Drop corporate suffix words. (`Inc`, `Ltd`, `Corp`, `GmbH`, ..)
+    /// 3. Concatenate and truncate to 8 characters.
+    ///
+    /// Falls back to the plain form if `vendor` is `None` or nothing is left.
+    ///
+    /// ```
+    /// use bdaddr::BdAddr;
+    ///
+    /// let addr = "00:1c:b3:33:22:11".parse::<BdAddr>().unwrap();
+    /// assert_eq!("Apple_33:22:11", addr.display_vendor_resolved(Some("Apple, Inc.")).to_string());
+    /// assert_eq!("00:1c:b3:33:22:11", addr.display_vendor_resolved(None).to_string());
+    /// ```
+    pub fn display_vendor_resolved<'a>(&'a self, vendor: Option<&'a str>) -> VendorResolved<'a> {
+        VendorResolved { addr: self, vendor }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_vendor_resolved() {
+        let addr = "b8:27:eb:12:34:56".parse::<BdAddr>().unwrap();
+        for (vendor, expect) in [
+            (Some("Apple, Inc."), "Apple_12:34:56"),
+            (Some("Raspberry Pi Foundation"), "Raspberr_12:34:56"),
+            (Some("Texas Instruments"), "TexasIns_12:34:56"),
+            (
+                Some("ESPRESSIF SYSTEMS (SHANGHAI) CO.,LTD."),
+                "ESPRESSI_12:34:56",
+            ),
+            (
+                Some("Telink Semiconductor (Taipei) Co. Ltd."),
+                "TelinkSe_12:34:56",
+            ),
+            (Some("Sony Corp."), "Sony_12:34:56"),
+            (Some("Nokia"), "Nokia_12:34:56"),
+            (Some("Société Générale"), "SocitGnr_12:34:56"),
+            (Some(""), "b8:27:eb:12:34:56"),
+            (Some(" ,. Inc. "), "b8:27:eb:12:34:56"),
+            (Some("\u{30bd}\u{30cb}\u{30fc}"), "b8:27:eb:12:34:56"),
+            (None, "b8:27:eb:12:34:56"),
+        ] {
+            assert_eq!(
+                expect,
+                addr.display_vendor_resolved(vendor).to_string(),
+                "{:?}",
+                vendor
+            );
+        }
+    }
+}
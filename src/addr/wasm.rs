@@ -0,0 +1,126 @@
+//! JavaScript bindings.
+//!
+//! Build with `wasm-pack` (`--features wasm`).
+//!
+//! ```js
+//! import { JsBdAddr } from "bdaddr";
+//!
+//! const addr = new JsBdAddr("53:03:8c:bc:bd:82");
+//! console.assert(addr.kind() === "resolvable");
+//! console.assert(addr.matches(Uint8Array.from([
+//!     0x19, 0x78, 0xa2, 0xaf, 0xdd, 0x75, 0x7b, 0xed,
+//!     0xfc, 0x9d, 0xc6, 0x9e, 0x95, 0xd7, 0x33, 0xb3,
+//! ])));
+//! ```
+
+use std::convert::TryFrom;
+
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+use super::matches::irk_from_slice;
+use super::{BdAddr, RandomDeviceAddress};
+
+/// Bluetooth Device Address.
+///
+/// Construct from string (`00:1a:7d:da:71:13`) or `Uint8Array` of 6 bytes in little-endian (wire) order.
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsBdAddr(BdAddr);
+
+#[wasm_bindgen]
+impl JsBdAddr {
+    #[wasm_bindgen(constructor)]
+    pub fn new(value: &JsValue) -> Result<JsBdAddr, JsError> {
+        if let Some(s) = value.as_string() {
+            return s
+                .parse()
+                .map(Self)
+                .map_err(|e| JsError::new(&format!("{} ({:?})", e, s)));
+        }
+        if let Some(b) = value.dyn_ref::<Uint8Array>() {
+            let b = b.to_vec();
+            return BdAddr::try_from(b.as_slice())
+                .map(Self)
+                .map_err(|e| JsError::new(&e.to_string()));
+        }
+        Err(JsError::new("expect string or Uint8Array"))
+    }
+
+    /// 6 bytes in little-endian (wire) order.
+    pub fn bytes(&self) -> Uint8Array {
+        Uint8Array::from(&self.0 .0[..])
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Kind of address, as if LE Random Device Address.
+    /// (`static`, `resolvable`, `non-resolvable` or `unknown`)
+    ///
+    /// Web Bluetooth does not expose address type, so a public address may be classified arbitrary.
+    pub fn kind(&self) -> String {
+        RandomDeviceAddress::new(self.0).kind().to_string()
+    }
+
+    /// Test Resolvable Private Address matches Identity Resolving Key. (16 bytes)
+    ///
+    /// Always `false` for other addresses.
+    pub fn matches(&self, irk: &[u8]) -> Result<bool, JsError> {
+        let irk = irk_from_slice(irk).map_err(|e| JsError::new(&e.to_string()))?;
+        match RandomDeviceAddress::new(self.0) {
+            RandomDeviceAddress::Resolvable(addr) => Ok(addr.matches(irk)),
+            _ => Ok(false),
+        }
+    }
+
+    pub fn equals(&self, other: &JsBdAddr) -> bool {
+        self == other
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_parse() {
+        let addr = JsBdAddr::new(&JsValue::from_str("00:1A:7D:DA:71:13")).unwrap();
+        assert_eq!(addr.to_js_string(), "00:1a:7d:da:71:13");
+        assert_eq!(
+            addr.bytes().to_vec(),
+            vec![0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00]
+        );
+        let bytes = JsValue::from(addr.bytes());
+        assert_eq!(JsBdAddr::new(&bytes).unwrap(), addr);
+
+        assert!(JsBdAddr::new(&JsValue::from_str("00:1a:7d:da:71")).is_err());
+        assert!(JsBdAddr::new(&JsValue::from(Uint8Array::new_with_length(5))).is_err());
+        assert!(JsBdAddr::new(&JsValue::from_f64(1.0)).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_kind() {
+        let t = |s: &str| JsBdAddr::new(&JsValue::from_str(s)).unwrap().kind();
+        assert_eq!(t("f5:44:33:22:11:00"), "static");
+        assert_eq!(t("53:03:8c:bc:bd:82"), "resolvable");
+        assert_eq!(t("35:44:33:22:11:00"), "non-resolvable");
+        assert_eq!(t("b5:44:33:22:11:00"), "unknown");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_matches() {
+        let irk = [
+            0x19, 0x78, 0xa2, 0xaf, 0xdd, 0x75, 0x7b, 0xed, 0xfc, 0x9d, 0xc6, 0x9e, 0x95, 0xd7,
+            0x33, 0xb3,
+        ];
+        let addr = JsBdAddr::new(&JsValue::from_str("53:03:8c:bc:bd:82")).unwrap();
+        assert!(addr.matches(&irk).unwrap());
+        let addr = JsBdAddr::new(&JsValue::from_str("53:03:8c:bc:bd:83")).unwrap();
+        assert!(!addr.matches(&irk).unwrap());
+        assert!(addr.matches(&irk[..15]).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+//! Windows `BLUETOOTH_ADDRESS` is a union of `ULONGLONG ullLong` and `BYTE rgBytes[6]`.
+//! `rgBytes` holds the octets in the same (wire) order as [`BdAddr`],
+//! so `55:44:33:22:11:00` is `0x0000_5544_3322_1100` as `u64`.
+//!
+//! WinRT `BluetoothLEAdvertisementReceivedEventArgs::BluetoothAddress` uses the same `u64` form.
+//!
+//! Conversions take the raw `(u64, i32)` pair. (see [platform integrations](crate#platform-integrations))
+//!
+//! ```ignore
+//! use bdaddr::Address;
+//!
+//! let args: BluetoothLEAdvertisementReceivedEventArgs = ...;
+//! let addr = Address::from_winrt(args.BluetoothAddress()?, args.BluetoothAddressType()?.0)?;
+//! ```
+
+use core::fmt;
+
+use super::{Address, BdAddr, UnsupportedAddressType};
+
+const BLUETOOTH_ADDRESS_TYPE_PUBLIC: i32 = 0;
+const BLUETOOTH_ADDRESS_TYPE_RANDOM: i32 = 1;
+
+/// Unknown WinRT `BluetoothAddressType` for [`Address::from_winrt`]
+///
+/// Holds the whole `i32`, unlike [`UnknownAddressType`](super::UnknownAddressType) for `u8` codes.
+#[derive(Debug)]
+pub struct UnknownWinrtAddressType(i32);
+
+impl fmt::Display for UnknownWinrtAddressType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown address type. ({})", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownWinrtAddressType {}
+
+impl BdAddr {
+    /// Construct from Windows `BLUETOOTH_ADDRESS` `u64` form.
+    ///
+    /// The upper 16 bits are ignored.
+    pub fn from_windows_u64(v: u64) -> Self {
+        let b = v.to_le_bytes();
+        Self([b[0], b[1], b[2], b[3], b[4], b[5]])
+    }
+
+    /// Convert to Windows `BLUETOOTH_ADDRESS` `u64` form.
+    pub fn to_windows_u64(&self) -> u64 {
+        let b = self.0;
+        u64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], 0, 0])
+    }
+}
+
+impl Address {
+    /// Construct LE Address from WinRT `BluetoothAddress` and `BluetoothAddressType`.
+    ///
+    /// | `BluetoothAddressType` | Address |
+    /// |------------------------|---------|
+    /// | 0 `Public` | LE Public Device Address |
+    /// | 1 `Random` | LE Random Device Address |
+    /// | 2 `Unspecified` | [`UnknownWinrtAddressType`] error |
+    pub fn from_winrt(addr: u64, ty: i32) -> Result<Self, UnknownWinrtAddressType> {
+        let addr = BdAddr::from_windows_u64(addr);
+        match ty {
+            BLUETOOTH_ADDRESS_TYPE_PUBLIC => Ok(addr.to_le_public_addr()),
+            BLUETOOTH_ADDRESS_TYPE_RANDOM => Ok(addr.to_le_random_addr()),
+            x => Err(UnknownWinrtAddressType(x)),
+        }
+    }
+
+    /// Convert to WinRT `BluetoothAddress` and `BluetoothAddressType`.
+    ///
+    /// BR/EDR Address is not supported.
+    pub fn to_winrt(&self) -> Result<(u64, i32), UnsupportedAddressType> {
+        let ty = match self {
+            Self::LePublic(..) => BLUETOOTH_ADDRESS_TYPE_PUBLIC,
+            Self::LeRandom(..) => BLUETOOTH_ADDRESS_TYPE_RANDOM,
+            Self::BrEdr(..) => return Err(UnsupportedAddressType(self.address_type())),
+        };
+        Ok((self.into_bd_addr().to_windows_u64(), ty))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RandomDeviceAddress;
+
+    #[test]
+    fn test_u64() {
+        // BluetoothAddress 269673264451840 (0xF54433221100)
+        let addr = BdAddr::from_windows_u64(269673264451840);
+        assert_eq!("f5:44:33:22:11:00", addr.to_string());
+        assert_eq!(269673264451840, addr.to_windows_u64());
+
+        let addr = BdAddr::from_windows_u64(0x0000_001a_7dda_7113);
+        assert_eq!("00:1a:7d:da:71:13", addr.to_string());
+        assert_eq!([0x13, 0x71, 0xda, 0x7d, 0x1a, 0x00], <[u8; 6]>::from(addr));
+
+        let addr = BdAddr::from_windows_u64(0xffff_5544_3322_1100);
+        assert_eq!("55:44:33:22:11:00", addr.to_string());
+        assert_eq!(0x0000_5544_3322_1100, addr.to_windows_u64());
+    }
+
+    #[test]
+    fn test_winrt() {
+        let addr = Address::from_winrt(0xf544_3322_1100, 0).unwrap();
+        assert_eq!(
+            Address::le_public_from_str("f5:44:33:22:11:00").unwrap(),
+            addr
+        );
+        assert_eq!((0xf544_3322_1100, 0), addr.to_winrt().unwrap());
+
+        let addr = Address::from_winrt(0xf544_3322_1100, 1).unwrap();
+        assert!(matches!(
+            addr,
+            Address::LeRandom(RandomDeviceAddress::Static(..))
+        ));
+        assert_eq!((0xf544_3322_1100, 1), addr.to_winrt().unwrap());
+
+        let err = Address::from_winrt(0xf544_3322_1100, 2).unwrap_err();
+        assert_eq!("Unknown address type. (2)", &format!("{}", err));
+        let err = Address::from_winrt(0xf544_3322_1100, 256).unwrap_err();
+        assert_eq!("Unknown address type. (256)", &format!("{}", err));
+        let err = Address::from_winrt(0xf544_3322_1100, -1).unwrap_err();
+        assert_eq!("Unknown address type. (-1)", &format!("{}", err));
+
+        let err = Address::bredr_from_str("f5:44:33:22:11:00")
+            .unwrap()
+            .to_winrt()
+            .unwrap_err();
+        assert_eq!("Unsupported address type. (BrEdr)", &format!("{}", err));
+    }
+}
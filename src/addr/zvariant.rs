@@ -0,0 +1,115 @@
+//! D-Bus (BlueZ) represents Bluetooth Device Address as uppercase string. (e.g. `00:1A:7D:DA:71:13`)
+//!
+//! ```ignore
+//! #[zbus::proxy(interface = "org.bluez.Device1", default_service = "org.bluez")]
+//! trait Device1 {
+//!     #[zbus(property)]
+//!     fn address(&self) -> zbus::Result<bdaddr::BdAddr>;
+//!     #[zbus(property)]
+//!     fn address_type(&self) -> zbus::Result<String>;
+//! }
+//! ```
+
+use std::convert::TryFrom;
+
+use zvariant::{Error, OwnedValue, Signature, Type, Value};
+
+use super::{Address, BdAddr};
+
+impl Type for BdAddr {
+    const SIGNATURE: &'static Signature = &Signature::Str;
+}
+
+impl From<BdAddr> for Value<'_> {
+    fn from(v: BdAddr) -> Self {
+        Value::from(v.format_with(Some(':'), true).to_string())
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for BdAddr {
+    type Error = Error;
+
+    fn try_from(v: Value<'a>) -> Result<Self, Self::Error> {
+        match v {
+            Value::Str(s) => s
+                .as_str()
+                .parse()
+                .map_err(|e| Error::Message(format!("{}", e))),
+            _ => Err(Error::IncorrectType),
+        }
+    }
+}
+
+impl TryFrom<OwnedValue> for BdAddr {
+    type Error = Error;
+
+    fn try_from(v: OwnedValue) -> Result<Self, Self::Error> {
+        Self::try_from(Value::from(v))
+    }
+}
+
+impl Address {
+    /// Construct from `Address` and `AddressType` properties of `org.bluez.Device1`.
+    ///
+    /// `AddressType` is mapped as [`Address::from_bluez_device`].
+    pub fn from_zbus_props(addr: BdAddr, ty: &str) -> Result<Self, Error> {
+        Self::from_bluez_address_type(addr, ty).map_err(|e| Error::Message(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zvariant::serialized::Context;
+    use zvariant::LE;
+
+    #[test]
+    fn test_value() {
+        let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+        let value = Value::from(addr);
+        assert_eq!(Value::from("00:1A:7D:DA:71:13"), value);
+        assert_eq!(addr, BdAddr::try_from(value.try_clone().unwrap()).unwrap());
+        assert_eq!(
+            addr,
+            BdAddr::try_from(OwnedValue::try_from(value).unwrap()).unwrap()
+        );
+        assert_eq!(&Signature::Str, BdAddr::SIGNATURE);
+    }
+
+    #[test]
+    fn test_serialize() {
+        let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+        let ctxt = Context::new_dbus(LE, 0);
+        let data = zvariant::to_bytes(ctxt, &Value::from(addr)).unwrap();
+        let (value, _) = data.deserialize::<Value>().unwrap();
+        assert_eq!(addr, BdAddr::try_from(value).unwrap());
+    }
+
+    #[test]
+    fn test_value_err() {
+        let err = BdAddr::try_from(Value::from(1u32)).unwrap_err();
+        assert_eq!(Error::IncorrectType, err);
+
+        let err = BdAddr::try_from(Value::from("00:1A:7D:DA:71")).unwrap_err();
+        assert_eq!(
+            "wrong group count (expected 6, found 5) at offset 14",
+            &format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_props() {
+        let addr = "00:1a:7d:da:71:13".parse::<BdAddr>().unwrap();
+        assert_eq!(
+            addr.to_le_public_addr(),
+            Address::from_zbus_props(addr, "public").unwrap()
+        );
+        assert_eq!(
+            addr.to_le_random_addr(),
+            Address::from_zbus_props(addr, "random").unwrap()
+        );
+
+        let err = Address::from_zbus_props(addr, "br/edr").unwrap_err();
+        assert_eq!("Unknown address type. (\"br/edr\")", &format!("{}", err));
+    }
+}
@@ -0,0 +1,5 @@
+//! Kotlin / Swift bindings generator for `uniffi` feature.
+
+fn main() {
+    uniffi_crate::uniffi_bindgen_main()
+}
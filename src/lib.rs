@@ -1,8 +1,57 @@
 #![doc(html_root_url = "https://docs.rs/bdaddr/0.2.0-alpha.4")]
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+// renamed to have `uniffi` feature implying others
+#[cfg(feature = "uniffi")]
+extern crate uniffi_crate as uniffi;
+
 pub use addr::{
-    Address, AddressParseError, AddressType, BdAddr, NonResolvablePrivateAddress,
-    PublicDeviceAddress, RandomDeviceAddress, ResolvablePrivateAddress, StaticDeviceAddress,
+    Address, AddressFromSliceError, AddressFromStrError, AddressKind, AddressParseError,
+    AddressParseErrorKind, AddressType, BdAddr, BluezAddressError, BufferTooSmall, FormatWith,
+    InvalidAddressLength, InvalidBitsForAddressType, InvalidLap, InvalidRandomPart, Lap,
+    NonResolvablePrivateAddress, PublicDeviceAddress, RandomDeviceAddress, RandomDeviceAddressKind,
+    ResolvablePrivateAddress, StaticDeviceAddress, TryIntoAddress, TryIntoBdAddr,
+    UnknownAddressType, UnsupportedAddressType, VariantParseError, VendorResolved,
 };
 
+pub use addr::{ad, find_addresses, hci, ndef};
+
+#[cfg(feature = "esp")]
+pub use addr::ble_addr_t;
+#[cfg(feature = "bson")]
+pub use addr::bson;
+#[cfg(feature = "capi")]
+pub use addr::capi;
+#[cfg(feature = "napi")]
+pub use addr::napi;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub use addr::serde;
+#[cfg(any(feature = "matches", feature = "matches-openssl"))]
+pub use addr::AesBackendError;
+#[cfg(feature = "io-bluetooth")]
+pub use addr::BluetoothDeviceAddress;
+#[cfg(feature = "dbus")]
+pub use addr::DbusAddressError;
+#[cfg(feature = "wasm")]
+pub use addr::JsBdAddr;
+#[cfg(feature = "nrf")]
+pub use addr::NrfAddressError;
+#[cfg(feature = "alloc")]
+pub use addr::OuiSet;
+#[cfg(feature = "windows")]
+pub use addr::UnknownWinrtAddressType;
+#[cfg(all(feature = "libc", target_os = "linux"))]
+pub use addr::{bdaddr_t, sockaddr_l2, sockaddr_rc};
+#[cfg(feature = "python")]
+pub use addr::{py_module, PyAddress, PyBdAddr};
+#[cfg(all(feature = "testing", feature = "rand"))]
+pub use addr::{CorpusGenerator, CorpusMix};
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!("bdaddr");
+
 mod addr;